@@ -0,0 +1,58 @@
+use std::fs;
+use std::io::{Cursor, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use walkdir::WalkDir;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::error::AddonError;
+use crate::error::AddonError::{IOError, ZipError};
+
+/// Zips every file below `path`, the entries are named relative to `path`
+/// so the manifest ends up at the root of the archive.
+pub fn zip_dir(path: impl AsRef<Path>) -> Result<Vec<u8>, AddonError> {
+    let path = path.as_ref();
+
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    for entry in WalkDir::new(path).sort_by_file_name() {
+        let entry = entry.map_err(|e| {
+            let entry_path = e.path().unwrap_or(path).to_path_buf();
+            IOError(Arc::new(e.into()), entry_path)
+        })?;
+
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let entry_path = entry.path();
+
+        // Zip entries always use `/` as separator
+        let name = entry_path
+            .strip_prefix(path)
+            .unwrap_or(entry_path)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        let data =
+            fs::read(entry_path).map_err(|e| IOError(Arc::new(e), entry_path.to_path_buf()))?;
+
+        writer
+            .start_file(name, options)
+            .map_err(|e| ZipError(Arc::new(e), entry_path.to_path_buf()))?;
+        writer
+            .write_all(&data)
+            .map_err(|e| IOError(Arc::new(e), entry_path.to_path_buf()))?;
+    }
+
+    let archive = writer
+        .finish()
+        .map_err(|e| ZipError(Arc::new(e), path.to_path_buf()))?;
+
+    Ok(archive.into_inner())
+}
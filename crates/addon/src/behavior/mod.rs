@@ -20,7 +20,11 @@ pub mod menu_category;
 
 #[derive(Debug, Clone)]
 pub struct BehaviorPack {
+    /// The directory the pack was imported from.
+    pub path: PathBuf,
     pub manifest: AddonManifest,
+    /// The key the pack is encrypted with, `None` if the pack isn't encrypted.
+    pub content_key: Option<String>,
     pub languages: Languages,
     pub blocks: HashMap<PathBuf, AddonBlock>,
     pub items: HashMap<PathBuf, AddonItem>,
@@ -33,6 +37,15 @@ impl Addon for BehaviorPack {
         &self.manifest
     }
 
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn set_encrypted(&mut self, path: PathBuf, content_key: String) {
+        self.path = path;
+        self.content_key = Some(content_key);
+    }
+
     fn import(path: impl AsRef<Path>) -> Result<Self, AddonError>
     where
         Self: Sized,
@@ -168,7 +181,9 @@ impl Addon for BehaviorPack {
         }
 
         Ok(Self {
+            path,
             manifest,
+            content_key: None,
             languages,
             blocks,
             items,
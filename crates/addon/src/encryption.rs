@@ -0,0 +1,417 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use aes::cipher::{AsyncStreamCipher, KeyIvInit};
+use aes::Aes256;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use walkdir::WalkDir;
+
+use crate::error::AddonError;
+use crate::error::AddonError::{FormatError, IOError, JsonError};
+
+/// The length of a pack key and of every per file key.
+pub const KEY_LENGTH: usize = 32;
+
+/// The magic number at the start of every encrypted `contents.json`.
+const CONTENTS_MAGIC: u32 = 0x9BCFB9FC;
+/// The size of the unencrypted header in front of the encrypted `contents.json`.
+const CONTENTS_HEADER_SIZE: usize = 0x100;
+/// Files the client needs to read before the pack's key is known, they stay unencrypted.
+const UNENCRYPTED_FILES: &[&str] = &["manifest.json", "pack_icon.png", "bug_pack_icon.png"];
+
+type Aes256Cfb8Enc = cfb8::Encryptor<Aes256>;
+type Aes256Cfb8Dec = cfb8::Decryptor<Aes256>;
+
+/// The index of an encrypted pack, it holds the key of every file in the pack.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PackContents {
+    pub content: Vec<PackContentsEntry>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PackContentsEntry {
+    /// The path of the file relative to the pack's root, separated by `/`.
+    pub path: String,
+    /// The key the file is encrypted with, `None` if the file isn't encrypted.
+    pub key: Option<String>,
+}
+
+/// Generates a random key as used for packs and their files.
+pub fn generate_key() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(KEY_LENGTH)
+        .map(char::from)
+        .collect()
+}
+
+/// Encrypts the pack at `src` into `dst` with `key` as pack key, which has to be [`KEY_LENGTH`] bytes long.
+///
+/// Every file is encrypted with its own random key using AES-256-CFB8, the keys are stored
+/// in a `contents.json` encrypted with the pack key. Sub packs get their own `contents.json`
+/// as the client reads them separately.
+pub fn encrypt_pack(
+    src: impl AsRef<Path>,
+    dst: impl AsRef<Path>,
+    content_id: &str,
+    key: &str,
+) -> Result<(), AddonError> {
+    let (src, dst) = (src.as_ref(), dst.as_ref());
+
+    // Fail before anything is written
+    key_iv(key)?;
+
+    for (root, files) in pack_roots(src)? {
+        let mut contents = PackContents { content: vec![] };
+
+        for file in files {
+            let relative = relative_path(&root, &file);
+            let data = fs::read(&file).map_err(|e| IOError(Arc::new(e), file.clone()))?;
+
+            let (data, file_key) = if UNENCRYPTED_FILES.contains(&relative.as_str()) {
+                (data, None)
+            } else {
+                let file_key = generate_key();
+                (encrypt(data, &file_key)?, Some(file_key))
+            };
+
+            write_file(&dst.join(file.strip_prefix(src).unwrap_or(&file)), &data)?;
+
+            contents.content.push(PackContentsEntry {
+                path: relative,
+                key: file_key,
+            });
+        }
+
+        let contents_path = dst
+            .join(root.strip_prefix(src).unwrap_or(&root))
+            .join("contents.json");
+        let contents_json = serde_json::to_vec(&contents)
+            .map_err(|e| JsonError(Arc::new(e), contents_path.clone()))?;
+
+        let mut data = Vec::with_capacity(CONTENTS_HEADER_SIZE + contents_json.len());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&CONTENTS_MAGIC.to_le_bytes());
+        data.resize(0x10, 0);
+        data.push(content_id.len().try_into().map_err(|_| FormatError {
+            message: String::from("Content id must not be longer than 255 bytes"),
+            path: contents_path.clone(),
+            line: None,
+            column: None,
+        })?);
+        data.extend_from_slice(content_id.as_bytes());
+        data.resize(CONTENTS_HEADER_SIZE, 0);
+        data.extend(encrypt(contents_json, key)?);
+
+        write_file(&contents_path, &data)?;
+    }
+
+    Ok(())
+}
+
+/// Decrypts the pack at `src` into `dst` using the pack's `key`, the inverse of [`encrypt_pack`].
+///
+/// Files not listed in a `contents.json` are copied as they are.
+pub fn decrypt_pack(
+    src: impl AsRef<Path>,
+    dst: impl AsRef<Path>,
+    key: &str,
+) -> Result<(), AddonError> {
+    let (src, dst) = (src.as_ref(), dst.as_ref());
+
+    key_iv(key)?;
+
+    for (root, files) in pack_roots(src)? {
+        let contents_path = root.join("contents.json");
+        let contents = read_contents(&contents_path, key)?;
+
+        for file in files {
+            let relative = relative_path(&root, &file);
+            let data = fs::read(&file).map_err(|e| IOError(Arc::new(e), file.clone()))?;
+
+            let file_key = contents
+                .content
+                .iter()
+                .find(|entry| entry.path == relative)
+                .and_then(|entry| entry.key.as_deref());
+
+            let data = match file_key {
+                Some(file_key) => decrypt(data, file_key)?,
+                None => data,
+            };
+
+            write_file(&dst.join(file.strip_prefix(src).unwrap_or(&file)), &data)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads and decrypts the `contents.json` at `path` with the pack's `key`.
+pub fn read_contents(path: impl AsRef<Path>, key: &str) -> Result<PackContents, AddonError> {
+    let path = path.as_ref();
+    let data = fs::read(path).map_err(|e| IOError(Arc::new(e), path.to_path_buf()))?;
+
+    let format_error = |message: &str| FormatError {
+        message: String::from(message),
+        path: path.to_path_buf(),
+        line: None,
+        column: None,
+    };
+
+    if data.len() < CONTENTS_HEADER_SIZE {
+        return Err(format_error("Encrypted contents.json is too short"));
+    }
+    if data[4..8] != CONTENTS_MAGIC.to_le_bytes() {
+        return Err(format_error(
+            "Encrypted contents.json has an invalid magic number",
+        ));
+    }
+
+    let contents = decrypt(data[CONTENTS_HEADER_SIZE..].to_vec(), key)?;
+
+    // A wrong key results in garbage, which fails to parse
+    serde_json::from_slice(&contents).map_err(|e| JsonError(Arc::new(e), path.to_path_buf()))
+}
+
+/// Encrypts `data` with AES-256-CFB8, the first 16 bytes of `key` are used as IV.
+pub fn encrypt(mut data: Vec<u8>, key: &str) -> Result<Vec<u8>, AddonError> {
+    let (key, iv) = key_iv(key)?;
+    Aes256Cfb8Enc::new(&key.into(), &iv.into()).encrypt(&mut data);
+    Ok(data)
+}
+
+/// Decrypts `data` with AES-256-CFB8, the first 16 bytes of `key` are used as IV.
+pub fn decrypt(mut data: Vec<u8>, key: &str) -> Result<Vec<u8>, AddonError> {
+    let (key, iv) = key_iv(key)?;
+    Aes256Cfb8Dec::new(&key.into(), &iv.into()).decrypt(&mut data);
+    Ok(data)
+}
+
+fn key_iv(key: &str) -> Result<([u8; KEY_LENGTH], [u8; 16]), AddonError> {
+    let key_bytes: [u8; KEY_LENGTH] = key
+        .as_bytes()
+        .try_into()
+        .map_err(|_| AddonError::InvalidKeyLength(key.len()))?;
+
+    let mut iv = [0; 16];
+    iv.copy_from_slice(&key_bytes[..16]);
+
+    Ok((key_bytes, iv))
+}
+
+/// Groups all files of the pack by the root they belong to, the pack itself or one of its sub packs.
+fn pack_roots(src: &Path) -> Result<Vec<(PathBuf, Vec<PathBuf>)>, AddonError> {
+    let subpacks_path = src.join("subpacks");
+
+    let mut roots = vec![(src.to_path_buf(), vec![])];
+
+    if subpacks_path.is_dir() {
+        for entry in
+            fs::read_dir(&subpacks_path).map_err(|e| IOError(Arc::new(e), subpacks_path.clone()))?
+        {
+            let entry = entry.map_err(|e| IOError(Arc::new(e), subpacks_path.clone()))?;
+
+            if entry.path().is_dir() {
+                roots.push((entry.path(), vec![]));
+            }
+        }
+    }
+
+    for entry in WalkDir::new(src).sort_by_file_name() {
+        let entry = entry.map_err(|e| {
+            let entry_path = e.path().unwrap_or(src).to_path_buf();
+            IOError(Arc::new(e.into()), entry_path)
+        })?;
+
+        if !entry.file_type().is_file() || entry.file_name() == "contents.json" {
+            continue;
+        }
+
+        // The most specific root the file is in
+        let root = roots
+            .iter_mut()
+            .rev()
+            .find(|(root, _)| entry.path().starts_with(root));
+
+        if let Some((_, files)) = root {
+            files.push(entry.path().to_path_buf());
+        }
+    }
+
+    Ok(roots)
+}
+
+fn relative_path(root: &Path, file: &Path) -> String {
+    file.strip_prefix(root)
+        .unwrap_or(file)
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn write_file(path: &Path, data: &[u8]) -> Result<(), AddonError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| IOError(Arc::new(e), parent.to_path_buf()))?;
+    }
+
+    fs::write(path, data).map_err(|e| IOError(Arc::new(e), path.to_path_buf()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::resource::ResourcePack;
+    use crate::Addon;
+
+    /// A fresh directory for a test, removed again once the test is done.
+    struct TestDir(PathBuf);
+
+    impl TestDir {
+        fn new(name: &str) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("bedrockrs_addon_{name}_{}", std::process::id()));
+            let _ = fs::remove_dir_all(&path);
+            fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn write(path: PathBuf, data: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, data).unwrap();
+    }
+
+    #[test]
+    fn data_round_trip() {
+        let key = generate_key();
+        let data = b"{\"format_version\": 2}".to_vec();
+
+        let encrypted = encrypt(data.clone(), &key).unwrap();
+        assert_ne!(encrypted, data);
+        assert_eq!(decrypt(encrypted, &key).unwrap(), data);
+    }
+
+    #[test]
+    fn keys_of_the_wrong_length_are_rejected() {
+        for key in ["", "too short", &"x".repeat(KEY_LENGTH + 1)] {
+            assert!(matches!(
+                encrypt(vec![1, 2, 3], key),
+                Err(AddonError::InvalidKeyLength(len)) if len == key.len()
+            ));
+        }
+
+        let dir = TestDir::new("wrong_key");
+        write(dir.0.join("src/manifest.json"), "{}");
+
+        assert!(encrypt_pack(dir.0.join("src"), dir.0.join("dst"), "id", "short").is_err());
+        assert!(!dir.0.join("dst").exists());
+    }
+
+    #[test]
+    fn pack_round_trip() {
+        let dir = TestDir::new("pack");
+        let (src, encrypted, decrypted) = (
+            dir.0.join("src"),
+            dir.0.join("encrypted"),
+            dir.0.join("decrypted"),
+        );
+
+        let files = [
+            ("manifest.json", "{\"header\": {}}"),
+            ("textures/terrain.json", "{\"texture_data\": {}}"),
+            ("subpacks/high/textures/blocks/stone.json", "high"),
+            ("subpacks/low/textures/blocks/stone.json", "low"),
+        ];
+        for (path, data) in files {
+            write(src.join(path), data);
+        }
+
+        let key = generate_key();
+        encrypt_pack(&src, &encrypted, "content_id", &key).unwrap();
+
+        // The manifest is needed before the key is known
+        assert_eq!(
+            fs::read_to_string(encrypted.join("manifest.json")).unwrap(),
+            files[0].1
+        );
+        assert_ne!(
+            fs::read(encrypted.join("textures/terrain.json")).unwrap(),
+            files[1].1.as_bytes()
+        );
+
+        // Every sub pack has its own index holding only its own files
+        let contents = read_contents(encrypted.join("subpacks/low/contents.json"), &key).unwrap();
+        assert_eq!(contents.content.len(), 1);
+        assert_eq!(contents.content[0].path, "textures/blocks/stone.json");
+
+        let contents = read_contents(encrypted.join("contents.json"), &key).unwrap();
+        assert_eq!(contents.content.len(), 2);
+
+        assert!(read_contents(encrypted.join("contents.json"), &generate_key()).is_err());
+
+        decrypt_pack(&encrypted, &decrypted, &key).unwrap();
+        for (path, data) in files {
+            assert_eq!(fs::read_to_string(decrypted.join(path)).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn addons_are_encrypted_and_imported() {
+        let dir = TestDir::new("addon");
+        let (src, encrypted, decrypted) = (
+            dir.0.join("src"),
+            dir.0.join("encrypted"),
+            dir.0.join("decrypted"),
+        );
+
+        write(
+            src.join("manifest.json"),
+            r#"{
+                "format_version": 2,
+                "header": {
+                    "name": "Test",
+                    "version": [1, 0, 0],
+                    "uuid": "5b6d3a0e-4b4e-4c4f-9d34-2f7f6c1e8a11"
+                },
+                "modules": [],
+                "dependencies": [],
+                "metadata": {}
+            }"#,
+        );
+        write(src.join("textures/terrain.json"), "{}");
+
+        let key = generate_key();
+        let pack = ResourcePack::import(&src)
+            .unwrap()
+            .encrypt(&encrypted, &key)
+            .unwrap();
+
+        assert_eq!(pack.path, encrypted);
+        assert_eq!(pack.content_key.as_deref(), Some(key.as_str()));
+        assert!(read_contents(encrypted.join("contents.json"), &key).is_ok());
+
+        let imported = ResourcePack::import_encrypted(&encrypted, &decrypted, &key).unwrap();
+
+        assert_eq!(imported.path, encrypted);
+        assert_eq!(imported.content_key.as_deref(), Some(key.as_str()));
+        assert_eq!(imported.manifest.header.name, "Test");
+        assert_eq!(
+            fs::read_to_string(decrypted.join("textures/terrain.json")).unwrap(),
+            "{}"
+        );
+    }
+}
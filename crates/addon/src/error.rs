@@ -10,6 +10,13 @@ pub enum AddonError {
     IOError(Arc<io::Error>, PathBuf),
     #[error("JsonError at {0}: {1}")]
     JsonError(Arc<serde_json::Error>, PathBuf),
+    #[error("ZipError at {1}: {0}")]
+    ZipError(Arc<zip::result::ZipError>, PathBuf),
+    #[error(
+        "InvalidKeyLength: keys must be {} bytes long, got {0} bytes",
+        crate::encryption::KEY_LENGTH
+    )]
+    InvalidKeyLength(usize),
     #[error("FormatError at {line:?}:{column:?} {path}: {message}")]
     FormatError {
         message: String,
@@ -1,9 +1,12 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
+use crate::encryption::{decrypt_pack, encrypt_pack};
 use crate::error::AddonError;
 use crate::manifest::AddonManifest;
 
+pub mod archive;
 pub mod behavior;
+pub mod encryption;
 pub mod error;
 pub mod identifier;
 pub mod language;
@@ -15,6 +18,55 @@ pub trait Addon {
     /// Returns the manifest of a given addon
     fn manifest(&self) -> &AddonManifest;
 
+    /// Returns the directory the addon was imported from
+    fn path(&self) -> &Path;
+
+    /// Points the addon to its encrypted files at `path` and sets the key they are encrypted with
+    fn set_encrypted(&mut self, path: PathBuf, content_key: String);
+
+    /// Encrypts the addon into `dst` with `key` as pack key, the returned addon points to the
+    /// encrypted files and carries the key, so it can be handed to the client
+    fn encrypt(&self, dst: impl AsRef<Path>, key: &str) -> Result<Self, AddonError>
+    where
+        Self: Sized + Clone,
+    {
+        let dst = dst.as_ref().to_path_buf();
+
+        encrypt_pack(
+            self.path(),
+            &dst,
+            &self.manifest().header.uuid.to_string(),
+            key,
+        )?;
+
+        let mut addon = self.clone();
+        addon.set_encrypted(dst, key.to_string());
+        Ok(addon)
+    }
+
+    /// Imports an encrypted addon by decrypting it into `decrypted` first, the returned addon
+    /// still points to the encrypted files at `path` and carries the key
+    fn import_encrypted(
+        path: impl AsRef<Path>,
+        decrypted: impl AsRef<Path>,
+        key: &str,
+    ) -> Result<Self, AddonError>
+    where
+        Self: Sized,
+    {
+        decrypt_pack(&path, &decrypted, key)?;
+
+        let mut addon = Self::import(decrypted)?;
+        addon.set_encrypted(path.as_ref().to_path_buf(), key.to_string());
+        Ok(addon)
+    }
+
+    /// Zips the directory of the addon, the result is what the client expects as a
+    /// `.mcpack` when downloading the addon from a server
+    fn zip(&self) -> Result<Vec<u8>, AddonError> {
+        archive::zip_dir(self.path())
+    }
+
     /// Imports an addon from a given path
     fn import(path: impl AsRef<Path>) -> Result<Self, AddonError>
     where
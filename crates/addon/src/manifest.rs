@@ -20,6 +20,8 @@ pub struct AddonManifest {
     pub capabilities: Option<Vec<String>>,
     /// Section containing the metadata about the file such as authors and licensing information.
     pub metadata: AddonManifestMetadata,
+    /// Section containing the sub packs of the pack, the user can choose between them in the pack settings. (resource pack manifest JSON only)
+    pub subpacks: Option<Vec<AddonManifestSubpack>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -87,3 +89,14 @@ pub struct AddonManifestMetadata {
     /// The home website of your pack.
     pub url: Option<String>,
 }
+
+/// Section containing a sub pack of the pack, sub packs are stored in the `subpacks` directory of the pack.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AddonManifestSubpack {
+    /// The name of the sub pack's directory inside of the `subpacks` directory.
+    pub folder_name: String,
+    /// The name of the sub pack as it appears within Minecraft.
+    pub name: String,
+    /// The memory tier a device needs to have in order to use the sub pack, each tier stands for 0.25GB of RAM.
+    pub memory_tier: Option<u32>,
+}
@@ -1,5 +1,5 @@
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::error::AddonError;
@@ -10,7 +10,11 @@ use crate::Addon;
 
 #[derive(Debug, Clone)]
 pub struct ResourcePack {
+    /// The directory the pack was imported from.
+    pub path: PathBuf,
     pub manifest: AddonManifest,
+    /// The key the pack is encrypted with, `None` if the pack isn't encrypted.
+    pub content_key: Option<String>,
     pub languages: Languages,
 }
 
@@ -19,6 +23,15 @@ impl Addon for ResourcePack {
         &self.manifest
     }
 
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn set_encrypted(&mut self, path: PathBuf, content_key: String) {
+        self.path = path;
+        self.content_key = Some(content_key);
+    }
+
     fn import(path: impl AsRef<Path>) -> Result<Self, AddonError>
     where
         Self: Sized,
@@ -36,7 +49,9 @@ impl Addon for ResourcePack {
         let languages = Languages::import(path.join("texts"))?;
 
         Ok(Self {
+            path,
             manifest,
+            content_key: None,
             languages,
         })
     }
@@ -1,4 +1,4 @@
-use std::fmt::{Debug, Formatter};
+use std::fmt::{Debug, Display, Formatter};
 
 use bedrockrs_core::Vec3;
 use serde::{Deserialize, Serialize};
@@ -15,7 +15,16 @@ impl Debug for AddonSemanticVersion {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             AddonSemanticVersion::Vector(v) => f.debug_list().entries([v.x, v.y, v.z]).finish(),
-            AddonSemanticVersion::SemVer(v) => v.fmt(f),
+            AddonSemanticVersion::SemVer(v) => Debug::fmt(v, f),
+        }
+    }
+}
+
+impl Display for AddonSemanticVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AddonSemanticVersion::Vector(v) => write!(f, "{}.{}.{}", v.x, v.y, v.z),
+            AddonSemanticVersion::SemVer(v) => write!(f, "{v}"),
         }
     }
 }
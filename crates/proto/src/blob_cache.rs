@@ -0,0 +1,387 @@
+//! The client blob cache, which lets clients keep sub chunks and biomes they received before.
+//!
+//! Chunks are sent with the ids of their blobs instead of the blobs themselves, the client
+//! answers with a `ClientCacheBlobStatusPacket` listing the blobs it has and the ones it misses,
+//! and the server sends the missing blobs with a `ClientCacheMissResponsePacket`. The blob cache
+//! may only be used if the client sent `cache_supported` in its `ClientCacheStatusPacket`.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use bedrockrs_core::int::{LE, VAR};
+
+use crate::packets::client_cache_blob_status_packet::ClientCacheBlobStatusPacket;
+use crate::packets::client_cache_miss_response_packet::ClientCacheMissResponsePacket;
+use crate::packets::level_chunk::LevelChunkPacket;
+use crate::types::cache_blob::CacheBlob;
+use crate::types::chunk_pos::ChunkPos;
+
+/// The id of a blob, the xxHash64 of its payload.
+pub fn blob_id(payload: &[u8]) -> u64 {
+    xxhash_rust::xxh64::xxh64(payload, 0)
+}
+
+/// The blobs of all connections, the least recently used blobs are dropped
+/// once the payloads take more than the given number of bytes.
+#[derive(Debug, Clone)]
+pub struct BlobStore {
+    max_size: usize,
+    size: usize,
+    blobs: HashMap<u64, (Vec<u8>, u64)>,
+    /// The blob ids by the time they were last used
+    usage: BTreeMap<u64, u64>,
+    time: u64,
+}
+
+impl BlobStore {
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            max_size,
+            size: 0,
+            blobs: HashMap::new(),
+            usage: BTreeMap::new(),
+            time: 0,
+        }
+    }
+
+    /// Stores a blob and returns its id.
+    pub fn insert(&mut self, payload: Vec<u8>) -> u64 {
+        let id = blob_id(&payload);
+
+        if self.blobs.contains_key(&id) {
+            self.touch(id);
+            return id;
+        }
+
+        self.time += 1;
+        self.size += payload.len();
+        self.blobs.insert(id, (payload, self.time));
+        self.usage.insert(self.time, id);
+
+        while self.size > self.max_size && self.blobs.len() > 1 {
+            let Some((_, oldest)) = self.usage.pop_first() else {
+                break;
+            };
+            if let Some((payload, _)) = self.blobs.remove(&oldest) {
+                self.size -= payload.len();
+            }
+        }
+
+        id
+    }
+
+    pub fn get(&mut self, id: u64) -> Option<&[u8]> {
+        if !self.blobs.contains_key(&id) {
+            return None;
+        }
+        self.touch(id);
+
+        self.blobs.get(&id).map(|(payload, _)| payload.as_slice())
+    }
+
+    pub fn contains(&self, id: u64) -> bool {
+        self.blobs.contains_key(&id)
+    }
+
+    /// The size of all stored payloads in bytes.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn len(&self) -> usize {
+        self.blobs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blobs.is_empty()
+    }
+
+    fn touch(&mut self, id: u64) {
+        if let Some((_, last_used)) = self.blobs.get_mut(&id) {
+            self.usage.remove(last_used);
+            self.time += 1;
+            *last_used = self.time;
+            self.usage.insert(self.time, id);
+        }
+    }
+
+    /// Builds a level chunk sending the sub chunks and biomes as blobs, the blobs are stored
+    /// and only the border blocks and block entities are sent within the packet.
+    pub fn level_chunk(
+        &mut self,
+        chunk_position: ChunkPos,
+        dimension_id: i32,
+        sub_chunks: Vec<Vec<u8>>,
+        biomes: Vec<u8>,
+        extra_data: Vec<u8>,
+    ) -> LevelChunkPacket {
+        let sub_chunk_count = sub_chunks.len() as u32;
+
+        let mut blob_ids: Vec<LE<u64>> = sub_chunks
+            .into_iter()
+            .map(|sub_chunk| LE::new(self.insert(sub_chunk)))
+            .collect();
+        blob_ids.push(LE::new(self.insert(biomes)));
+
+        LevelChunkPacket {
+            chunk_position,
+            dimension_id: VAR::new(dimension_id),
+            sub_chunk_count: VAR::new(sub_chunk_count),
+            cache_enabled: true,
+            blob_ids,
+            serialized_chunk_data: extra_data,
+            client_needs_to_request_subchunks: false,
+            client_request_subchunk_limit: VAR::new(-1),
+        }
+    }
+}
+
+/// The blobs a single client was sent ids of and the blobs it acknowledged having.
+#[derive(Debug, Clone, Default)]
+pub struct ClientBlobCache {
+    /// Blobs the client was sent ids of but didn't answer for yet
+    pending: HashSet<u64>,
+    /// Blobs the client reported having
+    held: HashSet<u64>,
+}
+
+impl ClientBlobCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the blobs of a level chunk sent to the client.
+    pub fn track(&mut self, packet: &LevelChunkPacket) {
+        if !packet.cache_enabled {
+            return;
+        }
+
+        for blob_id in &packet.blob_ids {
+            let blob_id = blob_id.into_inner();
+            if !self.held.contains(&blob_id) {
+                self.pending.insert(blob_id);
+            }
+        }
+    }
+
+    /// If the client reported having the blob.
+    pub fn holds(&self, blob_id: u64) -> bool {
+        self.held.contains(&blob_id)
+    }
+
+    pub fn is_pending(&self, blob_id: u64) -> bool {
+        self.pending.contains(&blob_id)
+    }
+
+    /// Handles the answer of the client, acknowledged blobs are recorded as held and the missing
+    /// blobs are sent from the store. Blobs the client wasn't sent ids of are ignored.
+    ///
+    /// Blobs the store dropped are forgotten so the cache doesn't outgrow the store, blobs the
+    /// client didn't answer for yet are reported as evicted since they can't be sent anymore.
+    pub fn handle_status(
+        &mut self,
+        status: &ClientCacheBlobStatusPacket,
+        store: &mut BlobStore,
+    ) -> BlobStatusResponse {
+        for blob_id in &status.acknowledged_blob_ids {
+            let blob_id = blob_id.into_inner();
+            if self.pending.remove(&blob_id) {
+                self.held.insert(blob_id);
+            }
+        }
+
+        let mut blobs = vec![];
+        let mut evicted_blob_ids = vec![];
+        for blob_id in &status.missing_blob_ids {
+            let blob_id = blob_id.into_inner();
+
+            // The client may have dropped blobs it reported before
+            if !self.pending.remove(&blob_id) && !self.held.remove(&blob_id) {
+                continue;
+            }
+
+            match store.get(blob_id) {
+                Some(payload) => {
+                    blobs.push(CacheBlob {
+                        id: LE::new(blob_id),
+                        payload: payload.to_vec(),
+                    });
+                    self.held.insert(blob_id);
+                }
+                None => evicted_blob_ids.push(blob_id),
+            }
+        }
+
+        self.held.retain(|blob_id| store.contains(*blob_id));
+        self.pending.retain(|blob_id| {
+            let stored = store.contains(*blob_id);
+            if !stored {
+                evicted_blob_ids.push(*blob_id);
+            }
+            stored
+        });
+
+        BlobStatusResponse {
+            miss_response: match blobs.is_empty() {
+                true => None,
+                false => Some(ClientCacheMissResponsePacket { blobs }),
+            },
+            evicted_blob_ids,
+        }
+    }
+}
+
+/// The answer to a [`ClientCacheBlobStatusPacket`].
+#[derive(Debug, Clone)]
+pub struct BlobStatusResponse {
+    /// The missing blobs that are still in the store, `None` if there are none
+    pub miss_response: Option<ClientCacheMissResponsePacket>,
+    /// Missing or unanswered blobs that were already dropped from the store, the client waits
+    /// for them forever, so the chunks using them have to be sent again without the blob cache
+    pub evicted_blob_ids: Vec<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(missing: &[u64], acknowledged: &[u64]) -> ClientCacheBlobStatusPacket {
+        ClientCacheBlobStatusPacket {
+            missing_blob_ids: missing.iter().map(|id| LE::new(*id)).collect(),
+            acknowledged_blob_ids: acknowledged.iter().map(|id| LE::new(*id)).collect(),
+        }
+    }
+
+    #[test]
+    fn least_recently_used_blobs_are_evicted() {
+        let mut store = BlobStore::new(8);
+
+        let first = store.insert(vec![1; 4]);
+        let second = store.insert(vec![2; 4]);
+        store.get(first);
+        let third = store.insert(vec![3; 4]);
+
+        assert!(store.contains(first));
+        assert!(!store.contains(second));
+        assert!(store.contains(third));
+        assert_eq!(store.size(), 8);
+    }
+
+    #[test]
+    fn missing_blobs_are_sent() {
+        let mut store = BlobStore::new(1024);
+        let mut cache = ClientBlobCache::new();
+
+        let chunk = store.level_chunk(
+            ChunkPos::new(0, 0),
+            0,
+            vec![vec![1; 4]],
+            vec![2; 4],
+            vec![0],
+        );
+        cache.track(&chunk);
+
+        let (sub_chunk, biomes) = (
+            chunk.blob_ids[0].into_inner(),
+            chunk.blob_ids[1].into_inner(),
+        );
+        assert!(cache.is_pending(sub_chunk) && cache.is_pending(biomes));
+
+        let response = cache.handle_status(&status(&[sub_chunk, 42], &[biomes]), &mut store);
+
+        let blobs = response.miss_response.unwrap().blobs;
+        assert_eq!(blobs.len(), 1);
+        assert_eq!(blobs[0].id.into_inner(), sub_chunk);
+        assert_eq!(blobs[0].payload, vec![1; 4]);
+        assert!(response.evicted_blob_ids.is_empty());
+
+        assert!(cache.holds(sub_chunk) && cache.holds(biomes));
+    }
+
+    #[test]
+    fn evicted_blobs_are_reported() {
+        let mut store = BlobStore::new(8);
+        let mut cache = ClientBlobCache::new();
+
+        let chunk = store.level_chunk(
+            ChunkPos::new(0, 0),
+            0,
+            vec![vec![1; 4]],
+            vec![2; 4],
+            vec![0],
+        );
+        cache.track(&chunk);
+        let (sub_chunk, biomes) = (
+            chunk.blob_ids[0].into_inner(),
+            chunk.blob_ids[1].into_inner(),
+        );
+
+        // Another chunk pushes the blobs of the first one out of the store
+        store.level_chunk(
+            ChunkPos::new(1, 0),
+            0,
+            vec![vec![3; 4]],
+            vec![4; 4],
+            vec![0],
+        );
+        assert!(!store.contains(sub_chunk));
+
+        let response = cache.handle_status(&status(&[sub_chunk], &[biomes]), &mut store);
+
+        assert!(response.miss_response.is_none());
+        assert_eq!(response.evicted_blob_ids, vec![sub_chunk]);
+        assert!(!cache.is_pending(sub_chunk) && !cache.holds(sub_chunk));
+        assert!(!cache.holds(biomes));
+    }
+
+    #[test]
+    fn blobs_dropped_from_the_store_are_forgotten() {
+        let mut store = BlobStore::new(8);
+        let mut cache = ClientBlobCache::new();
+
+        let first = store.level_chunk(
+            ChunkPos::new(0, 0),
+            0,
+            vec![vec![1; 4]],
+            vec![2; 4],
+            vec![0],
+        );
+        cache.track(&first);
+        let (sub_chunk, biomes) = (
+            first.blob_ids[0].into_inner(),
+            first.blob_ids[1].into_inner(),
+        );
+        cache.handle_status(&status(&[], &[sub_chunk, biomes]), &mut store);
+
+        let second = store.level_chunk(
+            ChunkPos::new(1, 0),
+            0,
+            vec![vec![3; 4]],
+            vec![4; 4],
+            vec![0],
+        );
+        cache.track(&second);
+        let third = store.level_chunk(
+            ChunkPos::new(2, 0),
+            0,
+            vec![vec![5; 4]],
+            vec![6; 4],
+            vec![0],
+        );
+        cache.track(&third);
+
+        // The client answers for the second chunk, the blobs of the first chunk and the pending
+        // blobs of the second chunk were pushed out of the store by the third chunk
+        let response = cache.handle_status(&status(&[], &[]), &mut store);
+
+        assert!(!cache.holds(sub_chunk) && !cache.holds(biomes));
+        let mut evicted = response.evicted_blob_ids;
+        evicted.sort_unstable();
+        let mut expected: Vec<u64> = second.blob_ids.iter().map(|id| id.into_inner()).collect();
+        expected.sort_unstable();
+        assert_eq!(evicted, expected);
+
+        for blob_id in &third.blob_ids {
+            assert!(cache.is_pending(blob_id.into_inner()));
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Batching of block changes, see [`BlockUpdateBatch`].
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::gamepacket::GamePacket;
+use crate::packets::update_sub_chunk_blocks_packet::UpdateSubChunkBlocksPacket;
+use crate::types::block_change_entry::BlockChangeEntry;
+use crate::types::block_layer::BlockLayer;
+use crate::types::block_update_flags::BlockUpdateFlags;
+use crate::types::network_block_pos::NetworkBlockPos;
+use crate::types::sub_chunk_pos::SubChunkPos;
+
+/// Collects block changes and sends them with one `UpdateSubChunkBlocksPacket` per sub chunk
+/// instead of one `UpdateBlockPacket` per block. Changing a block twice only sends the last change.
+#[derive(Debug, Clone)]
+pub struct BlockUpdateBatch {
+    flags: BlockUpdateFlags,
+    /// The block runtime ids by block position and layer, grouped by sub chunk
+    changes: BTreeMap<(i32, i32, i32), HashMap<(i32, i32, i32, BlockLayer), u32>>,
+}
+
+impl Default for BlockUpdateBatch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BlockUpdateBatch {
+    /// Creates an empty batch with the flags the vanilla server usually uses.
+    pub fn new() -> Self {
+        Self {
+            flags: BlockUpdateFlags::NETWORK,
+            changes: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the flags used for all changes of the batch.
+    pub fn with_flags(mut self, flags: BlockUpdateFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Changes a block on the standard layer.
+    pub fn set_block(&mut self, x: i32, y: i32, z: i32, block_runtime_id: u32) {
+        self.set_block_on_layer(x, y, z, BlockLayer::Standard, block_runtime_id);
+    }
+
+    pub fn set_block_on_layer(
+        &mut self,
+        x: i32,
+        y: i32,
+        z: i32,
+        layer: BlockLayer,
+        block_runtime_id: u32,
+    ) {
+        self.changes
+            .entry((x >> 4, y >> 4, z >> 4))
+            .or_default()
+            .insert((x, y, z, layer), block_runtime_id);
+    }
+
+    /// The number of changed blocks.
+    pub fn len(&self) -> usize {
+        self.changes.values().map(HashMap::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The packets sending all changes, the batch is empty afterwards.
+    pub fn take_packets(&mut self) -> Vec<GamePacket> {
+        let changes = std::mem::take(&mut self.changes);
+
+        changes
+            .into_iter()
+            .map(|((sub_x, sub_y, sub_z), blocks)| {
+                let mut standard_blocks = vec![];
+                let mut extra_blocks = vec![];
+
+                for ((x, y, z, layer), block_runtime_id) in blocks {
+                    let entry = BlockChangeEntry::new(
+                        NetworkBlockPos::new(x, y, z),
+                        block_runtime_id,
+                        self.flags,
+                    );
+
+                    match layer {
+                        BlockLayer::Standard => standard_blocks.push(entry),
+                        BlockLayer::Liquid => extra_blocks.push(entry),
+                    }
+                }
+
+                GamePacket::UpdateSubChunkBlocksPacket(UpdateSubChunkBlocksPacket {
+                    sub_chunk_position: SubChunkPos::new(sub_x, sub_y, sub_z),
+                    standard_blocks,
+                    extra_blocks,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,406 @@
+//! Boss bars that aren't tied to a real boss.
+//!
+//! The client only shows a boss bar while it knows the actor the bar belongs to, so every
+//! [`BossBar`] spawns an invisible fake actor for its viewers and attaches the bar to it.
+
+use std::collections::HashSet;
+
+use bedrockrs_core::int::LE;
+use bedrockrs_core::{Vec2, Vec3};
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+
+use crate::gamepacket::GamePacket;
+use crate::packets::add_actor_packet::AddActorPacket;
+use crate::packets::boss_event_packet::BossEventPacket;
+use crate::packets::move_entity_packet::MoveEntityPacket;
+use crate::packets::remove_actor_packet::RemoveEntityPacket;
+use crate::types::actor_flags::ActorFlags;
+use crate::types::boss_bar_color::{BossBarColor, BossBarOverlay};
+use crate::types::boss_event::BossEvent;
+use crate::types::dataitem::ActorDataBuilder;
+use crate::types::property_sync_data::{FloatEntriesList, IntEntriesList, PropertySyncData};
+
+/// The type of the fake actor, it is made invisible and shrunk to nothing.
+const FAKE_ACTOR_TYPE: &str = "minecraft:slime";
+
+/// A boss bar shown to a set of players.
+///
+/// The setters return the packet to send to every viewer, or `None` if nothing changed.
+#[derive(Debug, Clone)]
+pub struct BossBar {
+    unique_id: ActorUniqueID,
+    runtime_id: ActorRuntimeID,
+    title: String,
+    progress: f32,
+    color: BossBarColor,
+    overlay: BossBarOverlay,
+    darken_screen: bool,
+    viewers: HashSet<ActorUniqueID>,
+}
+
+impl BossBar {
+    /// Creates a full boss bar, the ids are used for the fake actor and must not be used by other actors.
+    pub fn new(
+        unique_id: ActorUniqueID,
+        runtime_id: ActorRuntimeID,
+        title: impl Into<String>,
+    ) -> Self {
+        Self {
+            unique_id,
+            runtime_id,
+            title: title.into(),
+            progress: 1.0,
+            color: BossBarColor::Purple,
+            overlay: BossBarOverlay::Progress,
+            darken_screen: false,
+            viewers: HashSet::new(),
+        }
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// The filled part of the bar from 0 to 1.
+    pub fn progress(&self) -> f32 {
+        self.progress
+    }
+
+    pub fn color(&self) -> BossBarColor {
+        self.color
+    }
+
+    pub fn overlay(&self) -> BossBarOverlay {
+        self.overlay
+    }
+
+    pub fn darken_screen(&self) -> bool {
+        self.darken_screen
+    }
+
+    /// The unique id of the fake actor the bar belongs to.
+    pub fn unique_id(&self) -> &ActorUniqueID {
+        &self.unique_id
+    }
+
+    pub fn viewers(&self) -> impl Iterator<Item = &ActorUniqueID> {
+        self.viewers.iter()
+    }
+
+    pub fn is_viewer(&self, player: &ActorUniqueID) -> bool {
+        self.viewers.contains(player)
+    }
+
+    /// Shows the bar to a player, the fake actor is spawned at the given position
+    /// which should be close to the player. Returns nothing if the player already sees the bar.
+    pub fn add_viewer(&mut self, player: ActorUniqueID, position: Vec3<f32>) -> Vec<GamePacket> {
+        if !self.viewers.insert(player) {
+            return vec![];
+        }
+
+        vec![self.spawn_packet(position), self.event(self.add_event())]
+    }
+
+    /// Hides the bar from a player and removes the fake actor again.
+    pub fn remove_viewer(&mut self, player: &ActorUniqueID) -> Vec<GamePacket> {
+        if !self.viewers.remove(player) {
+            return vec![];
+        }
+
+        vec![
+            self.event(BossEvent::Remove),
+            GamePacket::RemoveEntity(RemoveEntityPacket {
+                target_actor_id: self.unique_id.clone(),
+            }),
+        ]
+    }
+
+    /// Hides the bar from all players, returns the former viewers and the packets each of them gets.
+    pub fn clear_viewers(&mut self) -> (Vec<ActorUniqueID>, Vec<GamePacket>) {
+        if self.viewers.is_empty() {
+            return (vec![], vec![]);
+        }
+        let viewers = self.viewers.drain().collect();
+
+        (
+            viewers,
+            vec![
+                self.event(BossEvent::Remove),
+                GamePacket::RemoveEntity(RemoveEntityPacket {
+                    target_actor_id: self.unique_id.clone(),
+                }),
+            ],
+        )
+    }
+
+    pub fn set_title(&mut self, title: impl Into<String>) -> Option<GamePacket> {
+        let title = title.into();
+        if self.title == title {
+            return None;
+        }
+        self.title = title;
+
+        Some(self.event(BossEvent::UpdateName {
+            name: self.title.clone(),
+        }))
+    }
+
+    /// Sets the filled part of the bar, the progress is clamped between 0 and 1.
+    pub fn set_progress(&mut self, progress: f32) -> Option<GamePacket> {
+        let progress = progress.clamp(0.0, 1.0);
+        if self.progress == progress {
+            return None;
+        }
+        self.progress = progress;
+
+        Some(self.event(BossEvent::UpdatePercent {
+            health_percent: LE::new(self.progress),
+        }))
+    }
+
+    pub fn set_style(
+        &mut self,
+        color: BossBarColor,
+        overlay: BossBarOverlay,
+    ) -> Option<GamePacket> {
+        if self.color == color && self.overlay == overlay {
+            return None;
+        }
+        self.color = color;
+        self.overlay = overlay;
+
+        Some(self.event(BossEvent::UpdateStyle { color, overlay }))
+    }
+
+    /// Darkens the sky like while fighting the wither.
+    pub fn set_darken_screen(&mut self, darken_screen: bool) -> Option<GamePacket> {
+        if self.darken_screen == darken_screen {
+            return None;
+        }
+        self.darken_screen = darken_screen;
+
+        Some(self.event(BossEvent::UpdateProperties {
+            darken_screen: LE::new(darken_screen as u16),
+            color: self.color,
+            overlay: self.overlay,
+        }))
+    }
+
+    /// Moves the fake actor of a viewer, the client hides the bar once the actor
+    /// is too far away, so it should follow the player.
+    pub fn move_packet(&self, position: Vec3<f32>) -> GamePacket {
+        GamePacket::MoveEntity(MoveEntityPacket {
+            target_runtime_id: self.runtime_id.clone(),
+            on_ground: false,
+            teleport: true,
+            force_move: false,
+            position: Vec3 {
+                x: LE::new(position.x),
+                y: LE::new(position.y),
+                z: LE::new(position.z),
+            },
+            pitch: 0.0,
+            yaw: 0.0,
+            head_yaw: 0.0,
+        })
+    }
+
+    /// Answers events sent by clients, a query is answered by showing the bar again.
+    pub fn handle_event(&self, player: &ActorUniqueID, event: &BossEvent) -> Option<GamePacket> {
+        match event {
+            BossEvent::Query { .. } if self.viewers.contains(player) => {
+                Some(self.event(self.add_event()))
+            }
+            _ => None,
+        }
+    }
+
+    fn add_event(&self) -> BossEvent {
+        BossEvent::Add {
+            name: self.title.clone(),
+            health_percent: LE::new(self.progress),
+            darken_screen: LE::new(self.darken_screen as u16),
+            color: self.color,
+            overlay: self.overlay,
+        }
+    }
+
+    fn event(&self, event: BossEvent) -> GamePacket {
+        GamePacket::BossEvent(BossEventPacket {
+            boss_actor_unique_id: self.unique_id.clone(),
+            event,
+        })
+    }
+
+    fn spawn_packet(&self, position: Vec3<f32>) -> GamePacket {
+        let actor_data = ActorDataBuilder::new()
+            .flags(ActorFlags::INVISIBLE | ActorFlags::SILENT | ActorFlags::NO_AI)
+            .name_tag(self.title.clone())
+            .scale(0.0)
+            .bounding_box(0.0, 0.0)
+            .build();
+
+        GamePacket::AddEntity(AddActorPacket {
+            target_actor_id: self.unique_id.clone(),
+            target_runtime_id: self.runtime_id.clone(),
+            actor_type: String::from(FAKE_ACTOR_TYPE),
+            position: Vec3 {
+                x: LE::new(position.x),
+                y: LE::new(position.y),
+                z: LE::new(position.z),
+            },
+            velocity: Vec3 {
+                x: LE::new(0.0),
+                y: LE::new(0.0),
+                z: LE::new(0.0),
+            },
+            rotation: Vec2 {
+                x: LE::new(0.0),
+                y: LE::new(0.0),
+            },
+            y_head_rotation: LE::new(0.0),
+            y_body_rotation: LE::new(0.0),
+            attributes: vec![],
+            actor_data,
+            synched_properties: PropertySyncData {
+                int: IntEntriesList { entries: vec![] },
+                float: FloatEntriesList { entries: vec![] },
+            },
+            actor_links: vec![],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn boss_bar() -> BossBar {
+        BossBar::new(ActorUniqueID(-7), ActorRuntimeID(7), "Boss")
+    }
+
+    fn position() -> Vec3<f32> {
+        Vec3 {
+            x: 0.0,
+            y: 64.0,
+            z: 0.0,
+        }
+    }
+
+    fn event(packet: Option<GamePacket>) -> BossEvent {
+        match packet {
+            Some(GamePacket::BossEvent(packet)) => {
+                assert_eq!(packet.boss_actor_unique_id, ActorUniqueID(-7));
+                packet.event
+            }
+            other => panic!("expected a boss event, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn viewers_are_added_once() {
+        let mut bar = boss_bar();
+
+        let packets = bar.add_viewer(ActorUniqueID(1), position());
+        assert!(matches!(
+            packets[..],
+            [
+                GamePacket::AddEntity(_),
+                GamePacket::BossEvent(BossEventPacket {
+                    event: BossEvent::Add { .. },
+                    ..
+                })
+            ]
+        ));
+        assert!(bar.add_viewer(ActorUniqueID(1), position()).is_empty());
+        assert!(bar.is_viewer(&ActorUniqueID(1)));
+    }
+
+    #[test]
+    fn removed_viewers_lose_the_bar() {
+        let mut bar = boss_bar();
+        bar.add_viewer(ActorUniqueID(1), position());
+
+        assert!(bar.remove_viewer(&ActorUniqueID(2)).is_empty());
+        let packets = bar.remove_viewer(&ActorUniqueID(1));
+        assert!(matches!(
+            packets[..],
+            [
+                GamePacket::BossEvent(BossEventPacket {
+                    event: BossEvent::Remove,
+                    ..
+                }),
+                GamePacket::RemoveEntity(_)
+            ]
+        ));
+        assert!(!bar.is_viewer(&ActorUniqueID(1)));
+    }
+
+    #[test]
+    fn clearing_returns_the_former_viewers() {
+        let mut bar = boss_bar();
+        bar.add_viewer(ActorUniqueID(1), position());
+        bar.add_viewer(ActorUniqueID(2), position());
+
+        let (mut viewers, packets) = bar.clear_viewers();
+        viewers.sort_by_key(|viewer| viewer.0);
+        assert_eq!(viewers, vec![ActorUniqueID(1), ActorUniqueID(2)]);
+        assert_eq!(packets.len(), 2);
+        assert_eq!(bar.viewers().count(), 0);
+
+        let (viewers, packets) = bar.clear_viewers();
+        assert!(viewers.is_empty() && packets.is_empty());
+    }
+
+    #[test]
+    fn updates_are_only_sent_on_changes() {
+        let mut bar = boss_bar();
+
+        assert!(bar.set_title("Boss").is_none());
+        assert!(
+            matches!(event(bar.set_title("Wither")), BossEvent::UpdateName { name } if name == "Wither")
+        );
+
+        assert!(bar.set_progress(1.5).is_none());
+        assert!(matches!(
+            event(bar.set_progress(-1.0)),
+            BossEvent::UpdatePercent { health_percent } if health_percent.into_inner() == 0.0
+        ));
+
+        assert!(matches!(
+            event(bar.set_style(BossBarColor::Red, BossBarOverlay::Progress)),
+            BossEvent::UpdateStyle {
+                color: BossBarColor::Red,
+                ..
+            }
+        ));
+        assert!(bar
+            .set_style(BossBarColor::Red, BossBarOverlay::Progress)
+            .is_none());
+
+        assert!(matches!(
+            event(bar.set_darken_screen(true)),
+            BossEvent::UpdateProperties {
+                color: BossBarColor::Red,
+                ..
+            }
+        ));
+        assert!(bar.set_darken_screen(true).is_none());
+    }
+
+    #[test]
+    fn queries_of_viewers_show_the_bar_again() {
+        let mut bar = boss_bar();
+        bar.add_viewer(ActorUniqueID(1), position());
+
+        let query = BossEvent::Query {
+            player_unique_id: ActorUniqueID(1),
+        };
+        assert!(matches!(
+            event(bar.handle_event(&ActorUniqueID(1), &query)),
+            BossEvent::Add { .. }
+        ));
+        assert!(bar.handle_event(&ActorUniqueID(2), &query).is_none());
+    }
+}
@@ -0,0 +1,190 @@
+use bedrockrs_core::Vec3;
+
+use crate::command::selector::{SelectorEntity, SelectorSource, TargetSelector};
+
+/// A parsed argument of a command.
+#[derive(Debug, Clone)]
+pub enum CommandArg {
+    Int(i32),
+    Float(f32),
+    /// A `*` for wildcard integers
+    Wildcard,
+    String(String),
+    /// The value of an enum or soft enum, as typed by the player
+    Enum(String),
+    Target(CommandTarget),
+    Position(CommandPosition),
+    Json(serde_json::Value),
+}
+
+/// A target given as player name or target selector.
+#[derive(Debug, Clone)]
+pub enum CommandTarget {
+    Name(String),
+    /// A target selector like `@a[r=10]`
+    Selector(TargetSelector),
+    /// A `*` for wildcard targets
+    Wildcard,
+}
+
+impl CommandTarget {
+    /// Resolves the target, names select the players with that name
+    /// and wildcards select all given actors.
+    pub fn select<'a, E: SelectorEntity>(
+        &self,
+        source: &SelectorSource<'a, E>,
+        entities: impl IntoIterator<Item = &'a E>,
+    ) -> Vec<&'a E> {
+        match self {
+            CommandTarget::Name(name) => entities
+                .into_iter()
+                .filter(|entity| entity.is_player() && entity.name() == name)
+                .collect(),
+            CommandTarget::Selector(selector) => selector.select(source, entities),
+            CommandTarget::Wildcard => entities.into_iter().collect(),
+        }
+    }
+}
+
+/// A position with coordinates that may be relative to the origin of the command.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CommandPosition {
+    pub x: Coordinate,
+    pub y: Coordinate,
+    pub z: Coordinate,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Coordinate {
+    Absolute(f32),
+    /// Relative to the position of the origin, written as `~`
+    Relative(f32),
+    /// Relative to the position and rotation of the origin, written as `^`
+    Local(f32),
+}
+
+impl CommandPosition {
+    /// Resolves the position against the position and rotation (in degrees) of the origin.
+    pub fn resolve(&self, origin: Vec3<f32>, pitch: f32, yaw: f32) -> Vec3<f32> {
+        if let (Coordinate::Local(left), Coordinate::Local(up), Coordinate::Local(forwards)) =
+            (self.x, self.y, self.z)
+        {
+            let yaw = (yaw + 90.0).to_radians();
+            let pitch_forward = (-pitch).to_radians();
+            let pitch_up = (-pitch + 90.0).to_radians();
+
+            let forward = Vec3 {
+                x: yaw.cos() * pitch_forward.cos(),
+                y: pitch_forward.sin(),
+                z: yaw.sin() * pitch_forward.cos(),
+            };
+            let upward = Vec3 {
+                x: yaw.cos() * pitch_up.cos(),
+                y: pitch_up.sin(),
+                z: yaw.sin() * pitch_up.cos(),
+            };
+            // forward x upward, negated
+            let leftward = Vec3 {
+                x: -(forward.y * upward.z - forward.z * upward.y),
+                y: -(forward.z * upward.x - forward.x * upward.z),
+                z: -(forward.x * upward.y - forward.y * upward.x),
+            };
+
+            return Vec3 {
+                x: origin.x + forward.x * forwards + upward.x * up + leftward.x * left,
+                y: origin.y + forward.y * forwards + upward.y * up + leftward.y * left,
+                z: origin.z + forward.z * forwards + upward.z * up + leftward.z * left,
+            };
+        }
+
+        Vec3 {
+            x: self.x.resolve(origin.x),
+            y: self.y.resolve(origin.y),
+            z: self.z.resolve(origin.z),
+        }
+    }
+}
+
+impl Coordinate {
+    /// Resolves a world coordinate, local coordinates can only be resolved
+    /// as a whole by [`CommandPosition::resolve`].
+    pub fn resolve(&self, origin: f32) -> f32 {
+        match self {
+            Coordinate::Absolute(value) => *value,
+            Coordinate::Relative(offset) | Coordinate::Local(offset) => origin + offset,
+        }
+    }
+}
+
+/// The arguments of a command by the name of their parameter.
+#[derive(Debug, Clone, Default)]
+pub struct CommandArgs {
+    args: Vec<(String, CommandArg)>,
+}
+
+impl CommandArgs {
+    pub(crate) fn push(&mut self, name: String, arg: CommandArg) {
+        self.args.push((name, arg));
+    }
+
+    pub fn get(&self, name: &str) -> Option<&CommandArg> {
+        self.args
+            .iter()
+            .find(|(arg_name, _)| arg_name == name)
+            .map(|(_, arg)| arg)
+    }
+
+    /// If the argument was given, optional arguments may be missing.
+    pub fn has(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    pub fn int(&self, name: &str) -> Option<i32> {
+        match self.get(name)? {
+            CommandArg::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The argument as float, integers are converted.
+    pub fn float(&self, name: &str) -> Option<f32> {
+        match self.get(name)? {
+            CommandArg::Float(value) => Some(*value),
+            CommandArg::Int(value) => Some(*value as f32),
+            _ => None,
+        }
+    }
+
+    /// The argument as text, works for strings and enum values.
+    pub fn string(&self, name: &str) -> Option<&str> {
+        match self.get(name)? {
+            CommandArg::String(value) | CommandArg::Enum(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn target(&self, name: &str) -> Option<&CommandTarget> {
+        match self.get(name)? {
+            CommandArg::Target(target) => Some(target),
+            _ => None,
+        }
+    }
+
+    pub fn position(&self, name: &str) -> Option<&CommandPosition> {
+        match self.get(name)? {
+            CommandArg::Position(position) => Some(position),
+            _ => None,
+        }
+    }
+
+    pub fn json(&self, name: &str) -> Option<&serde_json::Value> {
+        match self.get(name)? {
+            CommandArg::Json(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &CommandArg)> {
+        self.args.iter().map(|(name, arg)| (name.as_str(), arg))
+    }
+}
@@ -0,0 +1,622 @@
+use std::collections::HashMap;
+
+use bedrockrs_core::int::LE;
+
+use crate::error::AvailableCommandsError;
+use crate::packets::available_commands_packet::AvailableCommandsPacket;
+use crate::types::command_data::{
+    CommandArgType, CommandData, CommandOverload, CommandParameter, CommandPermissionLevel,
+};
+use crate::types::command_enum::{
+    ChainedSubcommand, ChainedSubcommandValue, CommandConstraint, CommandEnum,
+    CommandEnumConstraint, CommandSoftEnum,
+};
+
+/// Builds an [`AvailableCommandsPacket`], enum values, suffixes and enums are collected
+/// into the index tables of the packet and shared between commands.
+///
+/// ```ignore
+/// let packet = AvailableCommandsBuilder::new()
+///     .command(
+///         CommandBuilder::new("warp", "Teleports you to a warp")
+///             .alias("w")
+///             .overload([CommandParam::soft_enum("warp", "Warps")])
+///             .overload([CommandParam::enumeration("action", "WarpAction", ["list"])]),
+///     )
+///     .soft_enum("Warps", ["spawn", "shop"])
+///     .build()?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AvailableCommandsBuilder {
+    commands: Vec<CommandBuilder>,
+    soft_enums: Vec<CommandSoftEnum>,
+    enum_constraints: Vec<(String, String, Vec<CommandConstraint>)>,
+}
+
+impl AvailableCommandsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn command(mut self, command: CommandBuilder) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    /// Adds a soft enum, its values can be changed later without resending all commands.
+    pub fn soft_enum(
+        mut self,
+        name: impl Into<String>,
+        values: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.soft_enums.push(CommandSoftEnum {
+            name: name.into(),
+            values: values.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// Restricts when a value of an enum used by a command may be used.
+    pub fn enum_constraint(
+        mut self,
+        enum_name: impl Into<String>,
+        value: impl Into<String>,
+        constraints: Vec<CommandConstraint>,
+    ) -> Self {
+        self.enum_constraints
+            .push((enum_name.into(), value.into(), constraints));
+        self
+    }
+
+    /// Fails if enums with the same name have different values or
+    /// an enum constraint refers to a value no command uses.
+    pub fn build(self) -> Result<AvailableCommandsPacket, AvailableCommandsError> {
+        let mut tables = Tables::default();
+
+        for soft_enum in self.soft_enums {
+            tables
+                .soft_enum_indices
+                .insert(soft_enum.name.clone(), tables.soft_enums.len() as u32);
+            tables.soft_enums.push(soft_enum);
+        }
+
+        let commands = self
+            .commands
+            .iter()
+            .map(|command| command.to_data(&mut tables))
+            .collect::<Result<_, _>>()?;
+
+        let enum_constraints = self
+            .enum_constraints
+            .into_iter()
+            .map(|(enum_name, value, constraints)| {
+                let indices = tables.enum_indices.get(&enum_name).and_then(|enum_index| {
+                    let value_index = *tables.value_indices.get(&value)?;
+
+                    tables.enums[*enum_index as usize]
+                        .value_indices
+                        .contains(&value_index)
+                        .then_some((*enum_index, value_index))
+                });
+
+                let Some((enum_index, value_index)) = indices else {
+                    return Err(AvailableCommandsError::UnknownEnumConstraint { enum_name, value });
+                };
+
+                Ok(CommandEnumConstraint {
+                    enum_value_index: LE::new(value_index),
+                    enum_index: LE::new(enum_index),
+                    constraints,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(AvailableCommandsPacket {
+            enum_values: tables.enum_values,
+            chained_subcommand_values: tables.chained_subcommand_values,
+            suffixes: tables.suffixes,
+            enums: tables.enums,
+            chained_subcommands: tables.chained_subcommands,
+            commands,
+            soft_enums: tables.soft_enums,
+            enum_constraints,
+        })
+    }
+}
+
+/// The index tables of the packet while it is being built.
+#[derive(Default)]
+struct Tables {
+    enum_values: Vec<String>,
+    value_indices: HashMap<String, u32>,
+    enums: Vec<CommandEnum>,
+    enum_indices: HashMap<String, u32>,
+    suffixes: Vec<String>,
+    suffix_indices: HashMap<String, u32>,
+    chained_subcommand_values: Vec<String>,
+    chained_subcommand_value_indices: HashMap<String, u32>,
+    chained_subcommands: Vec<ChainedSubcommand>,
+    soft_enums: Vec<CommandSoftEnum>,
+    soft_enum_indices: HashMap<String, u32>,
+}
+
+impl Tables {
+    fn enum_value(&mut self, value: &str) -> u32 {
+        index_of(&mut self.enum_values, &mut self.value_indices, value)
+    }
+
+    fn suffix(&mut self, suffix: &str) -> u32 {
+        index_of(&mut self.suffixes, &mut self.suffix_indices, suffix)
+    }
+
+    fn chained_subcommand_value(&mut self, value: &str) -> u32 {
+        index_of(
+            &mut self.chained_subcommand_values,
+            &mut self.chained_subcommand_value_indices,
+            value,
+        )
+    }
+
+    /// The index of the soft enum, soft enums that weren't added to the builder are added empty.
+    fn soft_enum(&mut self, name: &str) -> u32 {
+        if let Some(index) = self.soft_enum_indices.get(name) {
+            return *index;
+        }
+
+        let index = self.soft_enums.len() as u32;
+        self.soft_enums.push(CommandSoftEnum {
+            name: name.to_string(),
+            values: vec![],
+        });
+        self.soft_enum_indices.insert(name.to_string(), index);
+
+        index
+    }
+
+    /// The index of the enum, enums with the same name are only added once
+    /// and have to be used with the same values every time.
+    fn command_enum(
+        &mut self,
+        name: &str,
+        values: &[String],
+    ) -> Result<u32, AvailableCommandsError> {
+        let value_indices: Vec<u32> = values.iter().map(|value| self.enum_value(value)).collect();
+
+        if let Some(index) = self.enum_indices.get(name) {
+            if self.enums[*index as usize].value_indices != value_indices {
+                return Err(AvailableCommandsError::ConflictingEnum(name.to_string()));
+            }
+
+            return Ok(*index);
+        }
+
+        let index = self.enums.len() as u32;
+        self.enums.push(CommandEnum {
+            name: name.to_string(),
+            value_indices,
+        });
+        self.enum_indices.insert(name.to_string(), index);
+
+        Ok(index)
+    }
+}
+
+fn index_of(list: &mut Vec<String>, indices: &mut HashMap<String, u32>, value: &str) -> u32 {
+    *indices.entry(value.to_string()).or_insert_with(|| {
+        list.push(value.to_string());
+        (list.len() - 1) as u32
+    })
+}
+
+/// A single command with all its overloads.
+#[derive(Debug, Clone)]
+pub struct CommandBuilder {
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) flags: u16,
+    pub(crate) permission_level: CommandPermissionLevel,
+    pub(crate) aliases: Vec<String>,
+    pub(crate) overloads: Vec<(bool, Vec<CommandParam>)>,
+    pub(crate) chained_subcommands: Vec<(String, Vec<(String, CommandArgType)>)>,
+}
+
+impl CommandBuilder {
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            flags: 0,
+            permission_level: CommandPermissionLevel::Any,
+            aliases: vec![],
+            overloads: vec![],
+            chained_subcommands: vec![],
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The permission level needed to see the command.
+    pub fn permission(mut self, permission_level: CommandPermissionLevel) -> Self {
+        self.permission_level = permission_level;
+        self
+    }
+
+    /// A combination of the `CommandData::FLAG_*` constants.
+    pub fn flags(mut self, flags: u16) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    pub fn alias(mut self, alias: impl Into<String>) -> Self {
+        self.aliases.push(alias.into());
+        self
+    }
+
+    /// Adds a list of parameters the command accepts, a command without
+    /// overloads gets one without parameters.
+    pub fn overload(mut self, params: impl IntoIterator<Item = CommandParam>) -> Self {
+        self.overloads.push((false, params.into_iter().collect()));
+        self
+    }
+
+    /// Adds an overload chaining into the chained subcommands.
+    pub fn chained_overload(mut self, params: impl IntoIterator<Item = CommandParam>) -> Self {
+        self.overloads.push((true, params.into_iter().collect()));
+        self
+    }
+
+    /// Adds a chained subcommand like the `as` in `/execute as`.
+    pub fn chained_subcommand(
+        mut self,
+        name: impl Into<String>,
+        values: impl IntoIterator<Item = (impl Into<String>, CommandArgType)>,
+    ) -> Self {
+        self.chained_subcommands.push((
+            name.into(),
+            values
+                .into_iter()
+                .map(|(value, arg_type)| (value.into(), arg_type))
+                .collect(),
+        ));
+        self
+    }
+
+    fn to_data(&self, tables: &mut Tables) -> Result<CommandData, AvailableCommandsError> {
+        let aliases_enum_index = if self.aliases.is_empty() {
+            -1
+        } else {
+            // The aliases enum contains the command's name as well
+            let mut values = vec![self.name.clone()];
+            values.extend(self.aliases.iter().cloned());
+
+            tables.command_enum(&format!("{}Aliases", self.name), &values)? as i32
+        };
+
+        let chained_subcommand_indices = self
+            .chained_subcommands
+            .iter()
+            .map(|(name, values)| {
+                let values = values
+                    .iter()
+                    .map(|(value, arg_type)| ChainedSubcommandValue {
+                        index: LE::new(tables.chained_subcommand_value(value) as u16),
+                        value: LE::new(arg_type.id() as u16),
+                    })
+                    .collect();
+
+                tables.chained_subcommands.push(ChainedSubcommand {
+                    name: name.clone(),
+                    values,
+                });
+
+                LE::new((tables.chained_subcommands.len() - 1) as u16)
+            })
+            .collect();
+
+        let mut overloads = self
+            .overloads
+            .iter()
+            .map(|(chaining, params)| {
+                Ok(CommandOverload {
+                    chaining: *chaining,
+                    parameters: params
+                        .iter()
+                        .map(|param| param.to_parameter(tables))
+                        .collect::<Result<_, _>>()?,
+                })
+            })
+            .collect::<Result<Vec<_>, AvailableCommandsError>>()?;
+
+        if overloads.is_empty() {
+            overloads.push(CommandOverload {
+                chaining: false,
+                parameters: vec![],
+            });
+        }
+
+        Ok(CommandData {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            flags: LE::new(self.flags),
+            permission_level: self.permission_level,
+            aliases_enum_index: LE::new(aliases_enum_index),
+            chained_subcommand_indices,
+            overloads,
+        })
+    }
+}
+
+/// A parameter of a command overload.
+#[derive(Debug, Clone)]
+pub struct CommandParam {
+    pub(crate) name: String,
+    pub(crate) kind: CommandParamKind,
+    pub(crate) optional: bool,
+    pub(crate) options: u8,
+}
+
+#[derive(Debug, Clone)]
+pub enum CommandParamKind {
+    Basic(CommandArgType),
+    /// One of the values of an enum, subcommands are usually enums with a single value
+    Enum {
+        name: String,
+        values: Vec<String>,
+    },
+    /// One of the values of a soft enum added to the [`AvailableCommandsBuilder`]
+    SoftEnum(String),
+    /// An integer followed by a suffix like `10L`
+    Suffixed(String),
+}
+
+impl CommandParam {
+    pub fn new(name: impl Into<String>, arg_type: CommandArgType) -> Self {
+        Self::with_kind(name, CommandParamKind::Basic(arg_type))
+    }
+
+    pub fn enumeration(
+        name: impl Into<String>,
+        enum_name: impl Into<String>,
+        values: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self::with_kind(
+            name,
+            CommandParamKind::Enum {
+                name: enum_name.into(),
+                values: values.into_iter().map(Into::into).collect(),
+            },
+        )
+    }
+
+    /// A literal subcommand like the `add` in `/tag @s add`.
+    pub fn subcommand(name: impl Into<String>) -> Self {
+        let name = name.into();
+        Self::enumeration(name.clone(), name.clone(), [name]).collapse_enum()
+    }
+
+    pub fn soft_enum(name: impl Into<String>, enum_name: impl Into<String>) -> Self {
+        Self::with_kind(name, CommandParamKind::SoftEnum(enum_name.into()))
+    }
+
+    pub fn suffixed(name: impl Into<String>, suffix: impl Into<String>) -> Self {
+        Self::with_kind(name, CommandParamKind::Suffixed(suffix.into()))
+    }
+
+    fn with_kind(name: impl Into<String>, kind: CommandParamKind) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            optional: false,
+            options: 0,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> &CommandParamKind {
+        &self.kind
+    }
+
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+
+    pub fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    /// Shows an enum as a single parameter instead of listing its values.
+    pub fn collapse_enum(mut self) -> Self {
+        self.options |= CommandParameter::OPTION_COLLAPSE_ENUM;
+        self
+    }
+
+    fn to_parameter(
+        &self,
+        tables: &mut Tables,
+    ) -> Result<CommandParameter, AvailableCommandsError> {
+        let param_type = match &self.kind {
+            CommandParamKind::Basic(arg_type) => CommandParameter::FLAG_VALID | arg_type.id(),
+            CommandParamKind::Enum { name, values } => {
+                CommandParameter::FLAG_VALID
+                    | CommandParameter::FLAG_ENUM
+                    | tables.command_enum(name, values)?
+            }
+            CommandParamKind::SoftEnum(name) => {
+                CommandParameter::FLAG_VALID
+                    | CommandParameter::FLAG_SOFT_ENUM
+                    | tables.soft_enum(name)
+            }
+            CommandParamKind::Suffixed(suffix) => {
+                CommandParameter::FLAG_SUFFIXED | tables.suffix(suffix)
+            }
+        };
+
+        Ok(CommandParameter {
+            name: self.name.clone(),
+            param_type: LE::new(param_type),
+            optional: self.optional,
+            options: self.options,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use bedrockrs_proto_core::ProtoCodec;
+
+    use super::*;
+
+    fn game_mode(name: &str) -> CommandParam {
+        CommandParam::enumeration(name, "GameMode", ["survival", "creative"])
+    }
+
+    fn round_trip(packet: &AvailableCommandsPacket) -> AvailableCommandsPacket {
+        let mut stream = vec![];
+        packet.proto_serialize(&mut stream).unwrap();
+
+        let mut cursor = Cursor::new(stream.as_slice());
+        let decoded = AvailableCommandsPacket::proto_deserialize(&mut cursor).unwrap();
+        assert_eq!(cursor.position() as usize, stream.len());
+
+        decoded
+    }
+
+    fn enum_values<'a>(packet: &'a AvailableCommandsPacket, name: &str) -> Vec<&'a str> {
+        let command_enum = packet
+            .enums
+            .iter()
+            .find(|command_enum| command_enum.name == name)
+            .unwrap();
+
+        command_enum
+            .value_indices
+            .iter()
+            .map(|index| packet.enum_values[*index as usize].as_str())
+            .collect()
+    }
+
+    fn param_type(packet: &AvailableCommandsPacket, command: &str) -> u32 {
+        let command = packet
+            .commands
+            .iter()
+            .find(|data| data.name == command)
+            .unwrap();
+
+        command.overloads[0].parameters[0].param_type.into_inner()
+    }
+
+    #[test]
+    fn shared_enums_and_suffixes_round_trip() {
+        let packet = AvailableCommandsBuilder::new()
+            .command(
+                CommandBuilder::new("gamemode", "Sets a game mode")
+                    .alias("gm")
+                    .overload([
+                        game_mode("mode"),
+                        CommandParam::new("player", CommandArgType::Target),
+                    ]),
+            )
+            .command(
+                CommandBuilder::new("defaultgamemode", "Sets the default game mode")
+                    .overload([game_mode("mode")]),
+            )
+            .command(
+                CommandBuilder::new("xp", "Adds experience")
+                    .overload([CommandParam::suffixed("levels", "L")])
+                    .overload([CommandParam::new("amount", CommandArgType::Int)]),
+            )
+            .enum_constraint(
+                "GameMode",
+                "creative",
+                vec![CommandConstraint::CheatsEnabled],
+            )
+            .build()
+            .unwrap();
+
+        let decoded = round_trip(&packet);
+
+        // The enum and its values are only sent once
+        assert_eq!(
+            decoded
+                .enums
+                .iter()
+                .filter(|command_enum| command_enum.name == "GameMode")
+                .count(),
+            1
+        );
+        assert_eq!(decoded.enum_values.len(), 4);
+        assert_eq!(enum_values(&decoded, "GameMode"), ["survival", "creative"]);
+        assert_eq!(enum_values(&decoded, "gamemodeAliases"), ["gamemode", "gm"]);
+
+        let game_mode_index = decoded
+            .enums
+            .iter()
+            .position(|command_enum| command_enum.name == "GameMode")
+            .unwrap() as u32;
+        let enum_type =
+            CommandParameter::FLAG_VALID | CommandParameter::FLAG_ENUM | game_mode_index;
+        assert_eq!(param_type(&decoded, "gamemode"), enum_type);
+        assert_eq!(param_type(&decoded, "defaultgamemode"), enum_type);
+
+        assert_eq!(decoded.suffixes, ["L"]);
+        assert_eq!(param_type(&decoded, "xp"), CommandParameter::FLAG_SUFFIXED);
+
+        assert_eq!(decoded.enum_constraints.len(), 1);
+        let constraint = &decoded.enum_constraints[0];
+        assert_eq!(constraint.enum_index.into_inner(), game_mode_index);
+        assert_eq!(
+            decoded.enum_values[constraint.enum_value_index.into_inner() as usize],
+            "creative"
+        );
+        assert_eq!(constraint.constraints, [CommandConstraint::CheatsEnabled]);
+    }
+
+    #[test]
+    fn enums_with_different_values_are_rejected() {
+        let result = AvailableCommandsBuilder::new()
+            .command(CommandBuilder::new("gamemode", "").overload([game_mode("mode")]))
+            .command(
+                CommandBuilder::new("weather", "").overload([CommandParam::enumeration(
+                    "weather",
+                    "GameMode",
+                    ["clear", "rain"],
+                )]),
+            )
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(AvailableCommandsError::ConflictingEnum(name)) if name == "GameMode"
+        ));
+    }
+
+    #[test]
+    fn constraints_on_unknown_values_are_rejected() {
+        let builder = AvailableCommandsBuilder::new()
+            .command(CommandBuilder::new("gamemode", "").overload([game_mode("mode")]));
+
+        for (enum_name, value) in [("GameMode", "spectator"), ("Weather", "survival")] {
+            let result = builder
+                .clone()
+                .enum_constraint(
+                    enum_name,
+                    value,
+                    vec![CommandConstraint::OperatorPermissions],
+                )
+                .build();
+
+            assert!(matches!(
+                result,
+                Err(AvailableCommandsError::UnknownEnumConstraint { .. })
+            ));
+        }
+    }
+}
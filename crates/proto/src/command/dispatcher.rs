@@ -0,0 +1,240 @@
+use std::fmt::{Debug, Formatter};
+
+use bedrockrs_core::int::VAR;
+
+use crate::command::args::CommandArgs;
+use crate::command::builder::{AvailableCommandsBuilder, CommandBuilder};
+use crate::command::parser::{parse_overload, CommandReader};
+use crate::error::CommandError;
+use crate::packets::command_output_packet::CommandOutputPacket;
+use crate::packets::command_request_packet::CommandRequestPacket;
+use crate::types::command_data::CommandPermissionLevel;
+use crate::types::command_origin_data::CommandOriginData;
+use crate::types::command_output_message::{CommandOutputMessage, CommandOutputType};
+
+/// The message of a successful command or the error message of a failed one.
+pub type CommandResult = Result<String, String>;
+
+type CommandHandler<S> = Box<dyn Fn(&mut S, &CommandInvocation) -> CommandResult + Send + Sync>;
+
+/// A parsed command ready to be executed.
+#[derive(Debug, Clone)]
+pub struct CommandInvocation {
+    pub origin: CommandOriginData,
+    /// The name or alias the command was called with
+    pub label: String,
+    /// The index of the overload the arguments matched
+    pub overload: usize,
+    pub args: CommandArgs,
+}
+
+/// Parses `CommandRequest` packets against registered commands and runs their handlers.
+///
+/// `S` is the state handed to the handlers, for example the server or the player.
+/// The same definitions are advertised to the client with [`CommandDispatcher::available_commands`].
+///
+/// ```ignore
+/// let mut dispatcher = CommandDispatcher::<Server>::new();
+///
+/// dispatcher.register(
+///     CommandBuilder::new("heal", "Heals a player")
+///         .overload([CommandParam::new("amount", CommandArgType::Int).optional()]),
+///     |server, invocation| {
+///         let amount = invocation.args.int("amount").unwrap_or(20);
+///         server.heal(amount);
+///         Ok(format!("Healed by {amount}"))
+///     },
+/// );
+///
+/// let output = dispatcher.handle(&mut server, &request, CommandPermissionLevel::Any);
+/// ```
+pub struct CommandDispatcher<S> {
+    commands: Vec<(CommandBuilder, CommandHandler<S>)>,
+}
+
+impl<S> CommandDispatcher<S> {
+    pub fn new() -> Self {
+        Self { commands: vec![] }
+    }
+
+    /// Registers a command, a command with the same name gets replaced.
+    pub fn register(
+        &mut self,
+        command: CommandBuilder,
+        handler: impl Fn(&mut S, &CommandInvocation) -> CommandResult + Send + Sync + 'static,
+    ) {
+        self.commands
+            .retain(|(registered, _)| !registered.name.eq_ignore_ascii_case(&command.name));
+        self.commands.push((command, Box::new(handler)));
+    }
+
+    /// A builder with all registered commands, soft enums and enum constraints can be added before building.
+    pub fn available_commands(&self) -> AvailableCommandsBuilder {
+        self.commands
+            .iter()
+            .fold(AvailableCommandsBuilder::new(), |builder, (command, _)| {
+                builder.command(command.clone())
+            })
+    }
+
+    /// Parses a command, with or without the leading `/`, against the commands usable with the permission level.
+    pub fn parse(
+        &self,
+        command: &str,
+        origin: CommandOriginData,
+        permission_level: CommandPermissionLevel,
+    ) -> Result<CommandInvocation, CommandError> {
+        let trimmed = command.trim_start();
+        let mut start = command.len() - trimmed.len();
+        if trimmed.starts_with('/') {
+            start += 1;
+        }
+
+        let mut reader = CommandReader::new(command, start);
+
+        let label = reader
+            .next_token()
+            .map(|token| token.text)
+            .unwrap_or_default();
+
+        // Commands without the permission are treated as unknown, like in vanilla
+        let (definition, _) = self
+            .find(&label)
+            .filter(|(definition, _)| definition.permission_level <= permission_level)
+            .ok_or_else(|| CommandError::UnknownCommand(label.clone()))?;
+
+        let overloads: Vec<_> = if definition.overloads.is_empty() {
+            vec![vec![]]
+        } else {
+            definition
+                .overloads
+                .iter()
+                .map(|(_, params)| params.clone())
+                .collect()
+        };
+
+        let mut furthest_error = None;
+
+        for (overload, params) in overloads.iter().enumerate() {
+            match parse_overload(&mut reader.clone(), params) {
+                Ok(args) => {
+                    return Ok(CommandInvocation {
+                        origin,
+                        label,
+                        overload,
+                        args,
+                    })
+                }
+                Err(error) => {
+                    // Report the error of the overload that matched the most
+                    let position = |e: &CommandError| match e {
+                        CommandError::SyntaxError { position, .. } => *position,
+                        CommandError::UnknownCommand(_) => 0,
+                    };
+
+                    if furthest_error
+                        .as_ref()
+                        .map_or(true, |furthest| position(&error) > position(furthest))
+                    {
+                        furthest_error = Some(error);
+                    }
+                }
+            }
+        }
+
+        Err(furthest_error.unwrap_or_else(|| CommandError::UnknownCommand(label)))
+    }
+
+    /// Parses and executes the command of a request, the returned packet should be sent back to the client.
+    pub fn handle(
+        &self,
+        state: &mut S,
+        request: &CommandRequestPacket,
+        permission_level: CommandPermissionLevel,
+    ) -> CommandOutputPacket {
+        let origin = request.command_origin.clone();
+
+        let message = match self.parse(&request.command, origin.clone(), permission_level) {
+            Ok(invocation) => {
+                let (_, handler) = self
+                    .find(&invocation.label)
+                    .expect("Parsed commands are always registered");
+
+                match handler(state, &invocation) {
+                    Ok(message) => output_message(true, message, vec![]),
+                    Err(message) => output_message(false, message, vec![]),
+                }
+            }
+            Err(CommandError::UnknownCommand(label)) => {
+                output_message(false, String::from("commands.generic.unknown"), vec![label])
+            }
+            Err(CommandError::SyntaxError {
+                position, length, ..
+            }) => {
+                let command = &request.command;
+                let end = (position + length).min(command.len());
+
+                output_message(
+                    false,
+                    String::from("commands.generic.syntax"),
+                    vec![
+                        command[..position].to_string(),
+                        command[position..end].to_string(),
+                        command[end..].to_string(),
+                    ],
+                )
+            }
+        };
+
+        CommandOutputPacket {
+            command_origin: origin,
+            output_type: CommandOutputType::AllOutput,
+            success_count: VAR::new(message.is_success as u32),
+            output_messages: vec![message],
+            data_set: String::new(),
+        }
+    }
+
+    fn find(&self, label: &str) -> Option<&(CommandBuilder, CommandHandler<S>)> {
+        self.commands.iter().find(|(definition, _)| {
+            definition.name.eq_ignore_ascii_case(label)
+                || definition
+                    .aliases
+                    .iter()
+                    .any(|alias| alias.eq_ignore_ascii_case(label))
+        })
+    }
+}
+
+fn output_message(
+    is_success: bool,
+    message_id: String,
+    parameters: Vec<String>,
+) -> CommandOutputMessage {
+    CommandOutputMessage {
+        is_success,
+        message_id,
+        parameters,
+    }
+}
+
+impl<S> Default for CommandDispatcher<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Debug for CommandDispatcher<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CommandDispatcher")
+            .field(
+                "commands",
+                &self
+                    .commands
+                    .iter()
+                    .map(|(definition, _)| definition)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
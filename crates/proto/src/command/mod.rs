@@ -0,0 +1,10 @@
+pub use args::*;
+pub use builder::*;
+pub use dispatcher::*;
+pub use selector::*;
+
+pub mod args;
+pub mod builder;
+pub mod dispatcher;
+mod parser;
+pub mod selector;
@@ -0,0 +1,504 @@
+use crate::command::args::{CommandArg, CommandArgs, CommandPosition, CommandTarget, Coordinate};
+use crate::command::builder::{CommandParam, CommandParamKind};
+use crate::command::selector::TargetSelector;
+use crate::error::CommandError;
+use crate::types::command_data::CommandArgType;
+
+/// A single word of a command, quoted strings and selectors with
+/// arguments like `@a[name="A B"]` count as one word.
+#[derive(Debug, Clone)]
+pub(crate) struct Token {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+impl Token {
+    fn error(&self, message: impl Into<String>) -> CommandError {
+        CommandError::SyntaxError {
+            position: self.start,
+            length: self.end - self.start,
+            message: message.into(),
+        }
+    }
+}
+
+/// Reads the words of a command one by one.
+#[derive(Debug, Clone)]
+pub(crate) struct CommandReader<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> CommandReader<'a> {
+    pub fn new(input: &'a str, position: usize) -> Self {
+        Self { input, position }
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    fn remaining(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let remaining = self.remaining();
+        self.position += remaining.len() - remaining.trim_start().len();
+    }
+
+    pub fn is_empty(&mut self) -> bool {
+        self.skip_whitespace();
+        self.remaining().is_empty()
+    }
+
+    /// Consumes everything left, used for messages.
+    pub fn rest(&mut self) -> Option<Token> {
+        self.skip_whitespace();
+
+        let text = self.remaining().trim_end();
+        if text.is_empty() {
+            return None;
+        }
+
+        let token = Token {
+            start: self.position,
+            end: self.position + text.len(),
+            text: text.to_string(),
+        };
+        self.position = self.input.len();
+
+        Some(token)
+    }
+
+    pub fn next_token(&mut self) -> Option<Token> {
+        self.skip_whitespace();
+
+        let start = self.position;
+        let mut chars = self.remaining().char_indices().peekable();
+
+        let (_, first) = chars.peek().copied()?;
+
+        // A quoted string, the quotes aren't part of the text
+        if first == '"' {
+            chars.next();
+
+            let mut text = String::new();
+            let mut end = self.input.len();
+            let mut escaped = false;
+
+            for (index, c) in chars {
+                match c {
+                    _ if escaped => {
+                        text.push(c);
+                        escaped = false;
+                    }
+                    '\\' => escaped = true,
+                    '"' => {
+                        end = start + index + 1;
+                        break;
+                    }
+                    _ => text.push(c),
+                }
+            }
+
+            self.position = end;
+            return Some(Token { start, end, text });
+        }
+
+        let mut depth = 0u32;
+        let mut in_quotes = false;
+        let mut end = self.input.len();
+
+        for (index, c) in chars {
+            match c {
+                '"' if depth > 0 => in_quotes = !in_quotes,
+                '[' | '{' if !in_quotes => depth += 1,
+                ']' | '}' if !in_quotes => depth = depth.saturating_sub(1),
+                c if c.is_whitespace() && depth == 0 && !in_quotes => {
+                    end = start + index;
+                    break;
+                }
+                _ => {}
+            }
+        }
+
+        self.position = end;
+        Some(Token {
+            start,
+            end,
+            text: self.input[start..end].to_string(),
+        })
+    }
+}
+
+/// Parses the arguments of an overload, all input has to be consumed.
+pub(crate) fn parse_overload(
+    reader: &mut CommandReader,
+    params: &[CommandParam],
+) -> Result<CommandArgs, CommandError> {
+    let mut args = CommandArgs::default();
+
+    for param in params {
+        if reader.is_empty() {
+            if param.optional {
+                break;
+            }
+
+            return Err(CommandError::SyntaxError {
+                position: reader.position(),
+                length: 0,
+                message: format!("Missing argument {:?}", param.name),
+            });
+        }
+
+        args.push(param.name.clone(), parse_param(reader, param)?);
+    }
+
+    if !reader.is_empty() {
+        let position = reader.position();
+        let token = reader.next_token();
+
+        return Err(CommandError::SyntaxError {
+            position,
+            length: token.map(|token| token.end - token.start).unwrap_or(0),
+            message: String::from("Too many arguments"),
+        });
+    }
+
+    Ok(args)
+}
+
+fn parse_param(
+    reader: &mut CommandReader,
+    param: &CommandParam,
+) -> Result<CommandArg, CommandError> {
+    let arg_type = match &param.kind {
+        CommandParamKind::Basic(arg_type) => *arg_type,
+        CommandParamKind::Enum { values, .. } => {
+            let token = next_token(reader)?;
+
+            return values
+                .iter()
+                .find(|value| value.eq_ignore_ascii_case(&token.text))
+                .map(|value| CommandArg::Enum(value.clone()))
+                .ok_or_else(|| token.error(format!("Expected one of {values:?}")));
+        }
+        CommandParamKind::SoftEnum(_) => return Ok(CommandArg::Enum(next_token(reader)?.text)),
+        CommandParamKind::Suffixed(suffix) => {
+            let token = next_token(reader)?;
+            let text = &token.text;
+
+            let value = text
+                .len()
+                .checked_sub(suffix.len())
+                .filter(|split| text.is_char_boundary(*split))
+                .filter(|split| text[*split..].eq_ignore_ascii_case(suffix))
+                .and_then(|split| text[..split].parse().ok());
+
+            return value
+                .map(CommandArg::Int)
+                .ok_or_else(|| token.error(format!("Expected an integer followed by {suffix:?}")));
+        }
+    };
+
+    match arg_type {
+        CommandArgType::Int => {
+            let token = next_token(reader)?;
+            token
+                .text
+                .parse()
+                .map(CommandArg::Int)
+                .map_err(|_| token.error("Expected an integer"))
+        }
+        CommandArgType::WildcardInt => {
+            let token = next_token(reader)?;
+            if token.text == "*" {
+                return Ok(CommandArg::Wildcard);
+            }
+
+            token
+                .text
+                .parse()
+                .map(CommandArg::Int)
+                .map_err(|_| token.error("Expected an integer or *"))
+        }
+        CommandArgType::Float | CommandArgType::Value => {
+            let token = next_token(reader)?;
+            token
+                .text
+                .parse()
+                .map(CommandArg::Float)
+                .map_err(|_| token.error("Expected a number"))
+        }
+        CommandArgType::Target | CommandArgType::WildcardTarget => {
+            let token = next_token(reader)?;
+
+            Ok(CommandArg::Target(match token.text.as_str() {
+                "*" if arg_type == CommandArgType::WildcardTarget => CommandTarget::Wildcard,
+                text if text.starts_with('@') => {
+                    let selector = TargetSelector::parse(text).map_err(|e| match e {
+                        CommandError::SyntaxError {
+                            position,
+                            length,
+                            message,
+                        } => CommandError::SyntaxError {
+                            position: token.start + position,
+                            length,
+                            message,
+                        },
+                        other => other,
+                    })?;
+                    CommandTarget::Selector(selector)
+                }
+                _ => CommandTarget::Name(token.text),
+            }))
+        }
+        CommandArgType::Position | CommandArgType::BlockPosition => {
+            parse_position(reader).map(CommandArg::Position)
+        }
+        CommandArgType::Message | CommandArgType::RawText | CommandArgType::Command => {
+            Ok(CommandArg::String(next(reader.rest(), reader)?.text))
+        }
+        CommandArgType::Json => {
+            let token = next_token(reader)?;
+            serde_json::from_str(&token.text)
+                .map(CommandArg::Json)
+                .map_err(|e| token.error(format!("Invalid JSON: {e}")))
+        }
+        _ => Ok(CommandArg::String(next_token(reader)?.text)),
+    }
+}
+
+/// Parses three coordinates, which may be written without spaces between them like `~~1~`.
+fn parse_position(reader: &mut CommandReader) -> Result<CommandPosition, CommandError> {
+    let mut coordinates = vec![];
+
+    while coordinates.len() < 3 {
+        let token = next_token(reader)?;
+
+        // Every ~ and ^ starts a new coordinate
+        let mut pieces = vec![];
+        for (index, c) in token.text.char_indices() {
+            if index == 0 || c == '~' || c == '^' {
+                pieces.push(index);
+            }
+        }
+
+        for (i, start) in pieces.iter().enumerate() {
+            let end = pieces.get(i + 1).copied().unwrap_or(token.text.len());
+            let piece = &token.text[*start..end];
+
+            if coordinates.len() == 3 {
+                return Err(token.error("Too many coordinates"));
+            }
+
+            coordinates.push(
+                parse_coordinate(piece)
+                    .ok_or_else(|| token.error(format!("Invalid coordinate {piece:?}")))?,
+            );
+        }
+    }
+
+    let local = coordinates
+        .iter()
+        .filter(|c| matches!(c, Coordinate::Local(_)))
+        .count();
+
+    // Local coordinates can't be mixed with world coordinates
+    if local != 0 && local != 3 {
+        return Err(CommandError::SyntaxError {
+            position: reader.position(),
+            length: 0,
+            message: String::from("Cannot mix world and local coordinates"),
+        });
+    }
+
+    Ok(CommandPosition {
+        x: coordinates[0],
+        y: coordinates[1],
+        z: coordinates[2],
+    })
+}
+
+fn parse_coordinate(text: &str) -> Option<Coordinate> {
+    let offset = |value: &str| -> Option<f32> {
+        if value.is_empty() {
+            Some(0.0)
+        } else {
+            value.parse().ok()
+        }
+    };
+
+    if let Some(value) = text.strip_prefix('~') {
+        Some(Coordinate::Relative(offset(value)?))
+    } else if let Some(value) = text.strip_prefix('^') {
+        Some(Coordinate::Local(offset(value)?))
+    } else {
+        Some(Coordinate::Absolute(text.parse().ok()?))
+    }
+}
+
+fn next_token(reader: &mut CommandReader) -> Result<Token, CommandError> {
+    let token = reader.next_token();
+    next(token, reader)
+}
+
+fn next(token: Option<Token>, reader: &CommandReader) -> Result<Token, CommandError> {
+    token.ok_or_else(|| CommandError::SyntaxError {
+        position: reader.position(),
+        length: 0,
+        message: String::from("Unexpected end of command"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::selector::SelectorKind;
+
+    fn parse(input: &str, params: &[CommandParam]) -> Result<CommandArgs, CommandError> {
+        parse_overload(&mut CommandReader::new(input, 0), params)
+    }
+
+    fn error_span(result: Result<CommandArgs, CommandError>) -> (usize, usize) {
+        match result {
+            Err(CommandError::SyntaxError {
+                position, length, ..
+            }) => (position, length),
+            other => panic!("Expected a syntax error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn reads_quoted_strings_and_selectors_as_one_token() {
+        let mut reader = CommandReader::new(r#"say "a \"b\" c" @a[name="A B"] rest"#, 3);
+
+        let quoted = reader.next_token().unwrap();
+        assert_eq!(quoted.text, r#"a "b" c"#);
+        assert_eq!((quoted.start, quoted.end), (4, 15));
+
+        assert_eq!(reader.next_token().unwrap().text, r#"@a[name="A B"]"#);
+        assert_eq!(reader.next_token().unwrap().text, "rest");
+        assert!(reader.next_token().is_none());
+    }
+
+    #[test]
+    fn parses_basic_arguments() {
+        let params = [
+            CommandParam::new("amount", CommandArgType::Int),
+            CommandParam::new("name", CommandArgType::String),
+            CommandParam::new("message", CommandArgType::Message),
+        ];
+
+        let args = parse(r#"5 "Steve Jobs" hello  there "#, &params).unwrap();
+
+        assert_eq!(args.int("amount"), Some(5));
+        assert_eq!(args.string("name"), Some("Steve Jobs"));
+        assert_eq!(args.string("message"), Some("hello  there"));
+    }
+
+    #[test]
+    fn optional_arguments_may_be_missing() {
+        let params = [
+            CommandParam::new("a", CommandArgType::Int),
+            CommandParam::new("b", CommandArgType::Int).optional(),
+        ];
+
+        let args = parse("3", &params).unwrap();
+        assert_eq!(args.int("a"), Some(3));
+        assert!(!args.has("b"));
+
+        assert_eq!(error_span(parse("", &params)), (0, 0));
+    }
+
+    #[test]
+    fn errors_point_at_the_invalid_argument() {
+        let params = [
+            CommandParam::new("a", CommandArgType::Int),
+            CommandParam::new("b", CommandArgType::Int),
+        ];
+
+        assert_eq!(error_span(parse("1 x", &params)), (2, 1));
+        assert_eq!(error_span(parse("1 2 345", &params)), (4, 3));
+
+        let params = [CommandParam::new("target", CommandArgType::Target)];
+        assert_eq!(error_span(parse("  @e[r=far]", &params)), (5, 5));
+    }
+
+    #[test]
+    fn parses_enums_and_suffixed_integers() {
+        let params = [
+            CommandParam::enumeration("mode", "Mode", ["add", "remove"]),
+            CommandParam::suffixed("levels", "L"),
+        ];
+
+        let args = parse("ADD 10l", &params).unwrap();
+        assert_eq!(args.string("mode"), Some("add"));
+        assert_eq!(args.int("levels"), Some(10));
+
+        assert_eq!(error_span(parse("list 10L", &params)), (0, 4));
+        assert_eq!(error_span(parse("add 10", &params)), (4, 2));
+    }
+
+    #[test]
+    fn parses_targets() {
+        let params = [CommandParam::new("target", CommandArgType::WildcardTarget)];
+
+        let args = parse("*", &params).unwrap();
+        assert!(matches!(
+            args.target("target"),
+            Some(CommandTarget::Wildcard)
+        ));
+
+        let args = parse("Steve", &params).unwrap();
+        assert!(
+            matches!(args.target("target"), Some(CommandTarget::Name(name)) if name == "Steve")
+        );
+
+        let args = parse("@r[c=2]", &params).unwrap();
+        match args.target("target") {
+            Some(CommandTarget::Selector(selector)) => {
+                assert_eq!(selector.kind, SelectorKind::RandomPlayer);
+                assert_eq!(selector.count, Some(2));
+            }
+            other => panic!("Expected a selector, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_positions() {
+        let params = [CommandParam::new("position", CommandArgType::Position)];
+
+        let args = parse("~~1~", &params).unwrap();
+        assert_eq!(
+            args.position("position"),
+            Some(&CommandPosition {
+                x: Coordinate::Relative(0.0),
+                y: Coordinate::Relative(1.0),
+                z: Coordinate::Relative(0.0),
+            })
+        );
+
+        let args = parse("1 -2.5 ~3", &params).unwrap();
+        assert_eq!(
+            args.position("position"),
+            Some(&CommandPosition {
+                x: Coordinate::Absolute(1.0),
+                y: Coordinate::Absolute(-2.5),
+                z: Coordinate::Relative(3.0),
+            })
+        );
+
+        let args = parse("^ ^1 ^", &params).unwrap();
+        assert_eq!(
+            args.position("position").map(|position| position.y),
+            Some(Coordinate::Local(1.0))
+        );
+
+        assert!(parse("~ ^ 1", &params).is_err());
+        assert!(parse("~ ~", &params).is_err());
+        assert!(parse("~~~~", &params).is_err());
+    }
+}
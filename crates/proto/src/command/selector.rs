@@ -0,0 +1,734 @@
+use bedrockrs_core::Vec3;
+use bedrockrs_shared::world::gamemode::Gamemode;
+use rand::seq::SliceRandom;
+
+use crate::command::args::Coordinate;
+use crate::error::CommandError;
+use crate::types::actor_type::ActorType;
+
+/// The entities a target selector can choose from, implemented by the server for its actors.
+pub trait SelectorEntity {
+    fn actor_type(&self) -> ActorType;
+
+    fn position(&self) -> Vec3<f32>;
+
+    /// The name of the player or the name tag of the actor
+    fn name(&self) -> &str;
+
+    fn tags(&self) -> &[String];
+
+    /// The gamemode, `None` for actors that aren't players
+    fn gamemode(&self) -> Option<Gamemode>;
+
+    /// The experience level, `None` for actors that aren't players
+    fn level(&self) -> Option<i32>;
+
+    /// The score on the given objective, `None` if the actor has no score on it
+    fn score(&self, objective: &str) -> Option<i32>;
+
+    fn is_player(&self) -> bool {
+        matches!(self.actor_type(), ActorType::Player)
+    }
+}
+
+/// Where a target selector is evaluated from.
+#[derive(Debug)]
+pub struct SelectorSource<'a, E> {
+    pub position: Vec3<f32>,
+    /// The actor running the command, selected by `@s`
+    pub executor: Option<&'a E>,
+    /// The player interacting with the NPC running the command, selected by `@initiator`
+    pub initiator: Option<&'a E>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SelectorKind {
+    /// `@a`
+    AllPlayers,
+    /// `@p`
+    NearestPlayer,
+    /// `@r`
+    RandomPlayer,
+    /// `@e`
+    AllEntities,
+    /// `@s`
+    Executor,
+    /// `@initiator`
+    Initiator,
+}
+
+/// A filter value that may be negated with `!`, like `type=!cow`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Negatable<T> {
+    pub value: T,
+    pub negated: bool,
+}
+
+/// An inclusive range like `1..5`, `..5`, `1..` or a single `3`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct IntRange {
+    pub min: Option<i32>,
+    pub max: Option<i32>,
+}
+
+impl IntRange {
+    pub fn contains(&self, value: i32) -> bool {
+        self.min.map_or(true, |min| value >= min) && self.max.map_or(true, |max| value <= max)
+    }
+}
+
+/// A parsed target selector like `@e[type=zombie,r=10,c=2]`.
+#[derive(Debug, Clone)]
+pub struct TargetSelector {
+    pub kind: SelectorKind,
+    /// `type=`, identifiers without namespace are in the `minecraft` namespace
+    pub types: Vec<Negatable<String>>,
+    /// `name=`
+    pub names: Vec<Negatable<String>>,
+    /// `tag=`, an empty tag matches actors without any tags
+    pub tags: Vec<Negatable<String>>,
+    /// `r=`, the maximum distance
+    pub radius: Option<f32>,
+    /// `rm=`, the minimum distance
+    pub min_radius: Option<f32>,
+    /// `x=`, `y=` and `z=`, replace the position the selector is evaluated from
+    pub x: Option<Coordinate>,
+    pub y: Option<Coordinate>,
+    pub z: Option<Coordinate>,
+    /// `dx=`, `dy=` and `dz=`, select the actors in the box spanned from the position
+    pub dx: Option<f32>,
+    pub dy: Option<f32>,
+    pub dz: Option<f32>,
+    /// `c=`, the maximum number of actors, negative values select the furthest actors first
+    pub count: Option<i32>,
+    /// `m=`
+    pub gamemodes: Vec<Negatable<Gamemode>>,
+    /// `l=`, the maximum experience level
+    pub level: Option<i32>,
+    /// `lm=`, the minimum experience level
+    pub min_level: Option<i32>,
+    /// `scores={objective=range}`
+    pub scores: Vec<Negatable<(String, IntRange)>>,
+}
+
+impl TargetSelector {
+    pub fn new(kind: SelectorKind) -> Self {
+        Self {
+            kind,
+            types: vec![],
+            names: vec![],
+            tags: vec![],
+            radius: None,
+            min_radius: None,
+            x: None,
+            y: None,
+            z: None,
+            dx: None,
+            dy: None,
+            dz: None,
+            count: None,
+            gamemodes: vec![],
+            level: None,
+            min_level: None,
+            scores: vec![],
+        }
+    }
+
+    /// Parses a selector, positions in errors are byte offsets into `text`.
+    pub fn parse(text: &str) -> Result<Self, CommandError> {
+        let error = |position: usize, length: usize, message: String| CommandError::SyntaxError {
+            position,
+            length,
+            message,
+        };
+
+        let open = text.find('[');
+        let kind_text = &text[..open.unwrap_or(text.len())];
+
+        let kind = match kind_text {
+            "@a" => SelectorKind::AllPlayers,
+            "@p" => SelectorKind::NearestPlayer,
+            "@r" => SelectorKind::RandomPlayer,
+            "@e" => SelectorKind::AllEntities,
+            "@s" => SelectorKind::Executor,
+            "@initiator" => SelectorKind::Initiator,
+            _ => {
+                return Err(error(
+                    0,
+                    kind_text.len(),
+                    format!("Unknown selector {kind_text:?}"),
+                ))
+            }
+        };
+
+        let mut selector = Self::new(kind);
+
+        let Some(open) = open else {
+            return Ok(selector);
+        };
+
+        if !text.ends_with(']') {
+            return Err(error(
+                open,
+                text.len() - open,
+                String::from("Expected ] at the end of the selector"),
+            ));
+        }
+
+        let body_start = open + 1;
+        let body = &text[body_start..text.len() - 1];
+
+        if body.trim().is_empty() {
+            return Ok(selector);
+        }
+
+        for (offset, arg) in split_top_level(body) {
+            let position = body_start + offset;
+
+            let Some((key, value)) = arg.split_once('=') else {
+                return Err(error(
+                    position,
+                    arg.len(),
+                    String::from("Expected key=value"),
+                ));
+            };
+
+            selector
+                .parse_arg(key.trim(), value.trim())
+                .map_err(|message| error(position, arg.len(), message))?;
+        }
+
+        Ok(selector)
+    }
+
+    fn parse_arg(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "type" => {
+                let value = parse_negatable(value, |value| Ok(unquote(value).to_string()))?;
+                self.types.push(value);
+            }
+            "name" => {
+                let value = parse_negatable(value, |value| Ok(unquote(value).to_string()))?;
+                self.names.push(value);
+            }
+            "tag" => {
+                let value = parse_negatable(value, |value| Ok(unquote(value).to_string()))?;
+                self.tags.push(value);
+            }
+            "r" => self.radius = Some(parse_number(value)?),
+            "rm" => self.min_radius = Some(parse_number(value)?),
+            "x" => self.x = Some(parse_coordinate(value)?),
+            "y" => self.y = Some(parse_coordinate(value)?),
+            "z" => self.z = Some(parse_coordinate(value)?),
+            "dx" => self.dx = Some(parse_number(value)?),
+            "dy" => self.dy = Some(parse_number(value)?),
+            "dz" => self.dz = Some(parse_number(value)?),
+            "c" => self.count = Some(parse_number(value)?),
+            "m" => {
+                let value = parse_negatable(value, parse_gamemode)?;
+                self.gamemodes.push(value);
+            }
+            "l" => self.level = Some(parse_number(value)?),
+            "lm" => self.min_level = Some(parse_number(value)?),
+            "scores" => {
+                let inner = value
+                    .strip_prefix('{')
+                    .and_then(|value| value.strip_suffix('}'))
+                    .ok_or_else(|| String::from("Expected scores in {}"))?;
+
+                if inner.trim().is_empty() {
+                    return Ok(());
+                }
+
+                for (_, score) in split_top_level(inner) {
+                    let (objective, range) = score
+                        .split_once('=')
+                        .ok_or_else(|| String::from("Expected objective=range"))?;
+                    let objective = unquote(objective.trim()).to_string();
+
+                    let range = parse_negatable(range.trim(), parse_range)?;
+                    self.scores.push(Negatable {
+                        value: (objective, range.value),
+                        negated: range.negated,
+                    });
+                }
+            }
+            _ => return Err(format!("Unknown selector argument {key:?}")),
+        }
+
+        Ok(())
+    }
+
+    /// Selects the matching actors, sorted and limited like the vanilla selectors.
+    pub fn select<'a, E: SelectorEntity>(
+        &self,
+        source: &SelectorSource<'a, E>,
+        entities: impl IntoIterator<Item = &'a E>,
+    ) -> Vec<&'a E> {
+        let origin = Vec3 {
+            x: self
+                .x
+                .map_or(source.position.x, |x| x.resolve(source.position.x)),
+            y: self
+                .y
+                .map_or(source.position.y, |y| y.resolve(source.position.y)),
+            z: self
+                .z
+                .map_or(source.position.z, |z| z.resolve(source.position.z)),
+        };
+
+        let players_only = match self.kind {
+            SelectorKind::AllPlayers | SelectorKind::NearestPlayer => true,
+            // @r can select other actors if a type is given
+            SelectorKind::RandomPlayer => !self.types.iter().any(|filter| !filter.negated),
+            _ => false,
+        };
+
+        let candidates: Vec<&'a E> = match self.kind {
+            SelectorKind::Executor => source.executor.into_iter().collect(),
+            SelectorKind::Initiator => source.initiator.into_iter().collect(),
+            _ => entities.into_iter().collect(),
+        };
+
+        let mut selected: Vec<&'a E> = candidates
+            .into_iter()
+            .filter(|entity| !players_only || entity.is_player())
+            .filter(|entity| self.matches(*entity, origin))
+            .collect();
+
+        let distance = |entity: &E| squared_distance(entity.position(), origin);
+        let by_distance = |a: &&'a E, b: &&'a E| distance(a).total_cmp(&distance(b));
+
+        let count = match self.kind {
+            SelectorKind::NearestPlayer => {
+                selected.sort_by(by_distance);
+                Some(self.count.unwrap_or(1))
+            }
+            SelectorKind::RandomPlayer => {
+                selected.shuffle(&mut rand::thread_rng());
+                Some(self.count.unwrap_or(1).abs())
+            }
+            _ => {
+                if self.count.is_some() {
+                    selected.sort_by(by_distance);
+                }
+                self.count
+            }
+        };
+
+        if let Some(count) = count {
+            if count < 0 {
+                selected.reverse();
+            }
+            selected.truncate(count.unsigned_abs() as usize);
+        }
+
+        selected
+    }
+
+    /// If the actor matches all filters, ignoring the kind and count of the selector.
+    pub fn matches<E: SelectorEntity>(&self, entity: &E, origin: Vec3<f32>) -> bool {
+        let identifier = entity.actor_type().identifier();
+        let type_matches = |filter: &Negatable<String>| {
+            let matches = match filter.value.contains(':') {
+                true => identifier == Some(filter.value.as_str()),
+                false => identifier
+                    .and_then(|identifier| identifier.strip_prefix("minecraft:"))
+                    .map_or(false, |identifier| identifier == filter.value),
+            };
+            matches != filter.negated
+        };
+        if !self.types.iter().all(type_matches) {
+            return false;
+        }
+
+        let name = entity.name();
+        if !self
+            .names
+            .iter()
+            .all(|filter| (filter.value == name) != filter.negated)
+        {
+            return false;
+        }
+
+        let tags = entity.tags();
+        let tag_matches = |filter: &Negatable<String>| {
+            let matches = match filter.value.is_empty() {
+                true => tags.is_empty(),
+                false => tags.contains(&filter.value),
+            };
+            matches != filter.negated
+        };
+        if !self.tags.iter().all(tag_matches) {
+            return false;
+        }
+
+        let position = entity.position();
+        let distance = squared_distance(position, origin);
+        if self.radius.map_or(false, |r| distance > r * r) {
+            return false;
+        }
+        if self.min_radius.map_or(false, |rm| distance < rm * rm) {
+            return false;
+        }
+
+        if self.dx.is_some() || self.dy.is_some() || self.dz.is_some() {
+            let in_range = |value: f32, start: f32, delta: Option<f32>| {
+                let end = start + delta.unwrap_or(0.0);
+                value >= start.min(end) && value <= start.max(end) + 1.0
+            };
+
+            if !in_range(position.x, origin.x, self.dx)
+                || !in_range(position.y, origin.y, self.dy)
+                || !in_range(position.z, origin.z, self.dz)
+            {
+                return false;
+            }
+        }
+
+        if !self.gamemodes.is_empty() {
+            let Some(gamemode) = entity.gamemode() else {
+                return false;
+            };
+            if !self
+                .gamemodes
+                .iter()
+                .all(|filter| (filter.value == gamemode) != filter.negated)
+            {
+                return false;
+            }
+        }
+
+        if self.level.is_some() || self.min_level.is_some() {
+            let Some(level) = entity.level() else {
+                return false;
+            };
+            let range = IntRange {
+                min: self.min_level,
+                max: self.level,
+            };
+            if !range.contains(level) {
+                return false;
+            }
+        }
+
+        self.scores.iter().all(|filter| {
+            let (objective, range) = &filter.value;
+            entity
+                .score(objective)
+                .map_or(false, |score| range.contains(score) != filter.negated)
+        })
+    }
+}
+
+fn squared_distance(a: Vec3<f32>, b: Vec3<f32>) -> f32 {
+    (a.x - b.x).powi(2) + (a.y - b.y).powi(2) + (a.z - b.z).powi(2)
+}
+
+/// Splits at commas that aren't inside quotes or braces, with the byte offset of each part.
+fn split_top_level(text: &str) -> Vec<(usize, &str)> {
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut depth = 0;
+    let mut quoted = false;
+
+    for (i, c) in text.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '{' if !quoted => depth += 1,
+            '}' if !quoted => depth -= 1,
+            ',' if !quoted && depth == 0 => {
+                parts.push((start, &text[start..i]));
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push((start, &text[start..]));
+
+    parts
+}
+
+fn unquote(text: &str) -> &str {
+    text.strip_prefix('"')
+        .and_then(|text| text.strip_suffix('"'))
+        .unwrap_or(text)
+}
+
+fn parse_negatable<T>(
+    value: &str,
+    parse: impl FnOnce(&str) -> Result<T, String>,
+) -> Result<Negatable<T>, String> {
+    let (negated, value) = match value.strip_prefix('!') {
+        Some(value) => (true, value.trim_start()),
+        None => (false, value),
+    };
+
+    Ok(Negatable {
+        value: parse(value)?,
+        negated,
+    })
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Expected a number, got {value:?}"))
+}
+
+fn parse_coordinate(value: &str) -> Result<Coordinate, String> {
+    match value.strip_prefix('~') {
+        Some("") => Ok(Coordinate::Relative(0.0)),
+        Some(offset) => parse_number(offset).map(Coordinate::Relative),
+        None => parse_number(value).map(Coordinate::Absolute),
+    }
+}
+
+fn parse_gamemode(value: &str) -> Result<Gamemode, String> {
+    Ok(match value.to_ascii_lowercase().as_str() {
+        "0" | "s" | "survival" => Gamemode::Survival,
+        "1" | "c" | "creative" => Gamemode::Creative,
+        "2" | "a" | "adventure" => Gamemode::Adventure,
+        "5" | "d" | "default" => Gamemode::Default,
+        "6" | "spectator" => Gamemode::Spectator,
+        _ => return Err(format!("Unknown gamemode {value:?}")),
+    })
+}
+
+fn parse_range(value: &str) -> Result<IntRange, String> {
+    let bound = |value: &str| match value.is_empty() {
+        true => Ok(None),
+        false => parse_number(value).map(Some),
+    };
+
+    match value.split_once("..") {
+        Some((min, max)) => {
+            let range = IntRange {
+                min: bound(min)?,
+                max: bound(max)?,
+            };
+            match range.min.is_none() && range.max.is_none() {
+                true => Err(String::from("Expected a range with at least one bound")),
+                false => Ok(range),
+            }
+        }
+        None => {
+            let value = parse_number(value)?;
+            Ok(IntRange {
+                min: Some(value),
+                max: Some(value),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestEntity {
+        actor_type: ActorType,
+        position: Vec3<f32>,
+        name: String,
+        tags: Vec<String>,
+        gamemode: Option<Gamemode>,
+        scores: Vec<(String, i32)>,
+    }
+
+    impl TestEntity {
+        fn new(actor_type: ActorType, name: &str, x: f32) -> Self {
+            Self {
+                gamemode: match actor_type {
+                    ActorType::Player => Some(Gamemode::Survival),
+                    _ => None,
+                },
+                actor_type,
+                position: Vec3 { x, y: 0.0, z: 0.0 },
+                name: String::from(name),
+                tags: vec![],
+                scores: vec![],
+            }
+        }
+    }
+
+    impl SelectorEntity for TestEntity {
+        fn actor_type(&self) -> ActorType {
+            self.actor_type.clone()
+        }
+
+        fn position(&self) -> Vec3<f32> {
+            Vec3 {
+                x: self.position.x,
+                y: self.position.y,
+                z: self.position.z,
+            }
+        }
+
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn tags(&self) -> &[String] {
+            &self.tags
+        }
+
+        fn gamemode(&self) -> Option<Gamemode> {
+            self.gamemode
+        }
+
+        fn level(&self) -> Option<i32> {
+            self.gamemode.map(|_| 0)
+        }
+
+        fn score(&self, objective: &str) -> Option<i32> {
+            self.scores
+                .iter()
+                .find(|(name, _)| name == objective)
+                .map(|(_, score)| *score)
+        }
+    }
+
+    fn origin<'a>(executor: Option<&'a TestEntity>) -> SelectorSource<'a, TestEntity> {
+        SelectorSource {
+            position: Vec3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            executor,
+            initiator: None,
+        }
+    }
+
+    fn names(selected: Vec<&TestEntity>) -> Vec<&str> {
+        selected.iter().map(|entity| entity.name.as_str()).collect()
+    }
+
+    #[test]
+    fn parses_arguments() {
+        let selector = TargetSelector::parse(
+            r#"@e[type=!cow,name="A, B",r=10,c=-2,m=c,scores={kills=1..,deaths=!..3}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(selector.kind, SelectorKind::AllEntities);
+        assert_eq!(
+            selector.types,
+            [Negatable {
+                value: String::from("cow"),
+                negated: true
+            }]
+        );
+        assert_eq!(selector.names[0].value, "A, B");
+        assert_eq!(selector.radius, Some(10.0));
+        assert_eq!(selector.count, Some(-2));
+        assert_eq!(selector.gamemodes[0].value, Gamemode::Creative);
+        assert_eq!(
+            selector.scores,
+            [
+                Negatable {
+                    value: (
+                        String::from("kills"),
+                        IntRange {
+                            min: Some(1),
+                            max: None
+                        }
+                    ),
+                    negated: false
+                },
+                Negatable {
+                    value: (
+                        String::from("deaths"),
+                        IntRange {
+                            min: None,
+                            max: Some(3)
+                        }
+                    ),
+                    negated: true
+                },
+            ]
+        );
+
+        assert_eq!(
+            TargetSelector::parse("@initiator").unwrap().kind,
+            SelectorKind::Initiator
+        );
+        assert!(TargetSelector::parse("@p[]").unwrap().types.is_empty());
+    }
+
+    #[test]
+    fn errors_point_at_the_invalid_part() {
+        let position = |text: &str| match TargetSelector::parse(text) {
+            Err(CommandError::SyntaxError {
+                position, length, ..
+            }) => (position, length),
+            other => panic!("Expected a syntax error, got {other:?}"),
+        };
+
+        assert_eq!(position("@x"), (0, 2));
+        assert_eq!(position("@e[type=cow"), (2, 9));
+        assert_eq!(position("@e[type=cow,r=far]"), (12, 5));
+        assert_eq!(position("@e[r=1,unknown=1]"), (7, 9));
+        assert_eq!(position("@e[scores={kills=..}]"), (3, 17));
+    }
+
+    #[test]
+    fn selects_nearest_player() {
+        let entities = [
+            TestEntity::new(ActorType::Player, "far", 20.0),
+            TestEntity::new(ActorType::Zombie, "zombie", 1.0),
+            TestEntity::new(ActorType::Player, "near", 5.0),
+        ];
+
+        let selector = TargetSelector::parse("@p").unwrap();
+        assert_eq!(names(selector.select(&origin(None), &entities)), ["near"]);
+
+        let selector = TargetSelector::parse("@a[c=-1]").unwrap();
+        assert_eq!(names(selector.select(&origin(None), &entities)), ["far"]);
+    }
+
+    #[test]
+    fn filters_entities() {
+        let mut tagged = TestEntity::new(ActorType::Cow, "tagged", 3.0);
+        tagged.tags.push(String::from("marked"));
+        let mut scored = TestEntity::new(ActorType::Player, "scored", 8.0);
+        scored.scores.push((String::from("kills"), 5));
+
+        let entities = [
+            TestEntity::new(ActorType::Zombie, "zombie", 1.0),
+            tagged,
+            scored,
+        ];
+
+        let select = |text: &str| {
+            names(
+                TargetSelector::parse(text)
+                    .unwrap()
+                    .select(&origin(None), &entities),
+            )
+        };
+
+        assert_eq!(select("@e[type=zombie]"), ["zombie"]);
+        assert_eq!(select("@e[type=minecraft:cow]"), ["tagged"]);
+        assert_eq!(select("@e[type=!player,tag=!marked]"), ["zombie"]);
+        assert_eq!(select("@e[tag=]"), ["zombie", "scored"]);
+        assert_eq!(select("@e[rm=2,r=5]"), ["tagged"]);
+        assert_eq!(select("@e[scores={kills=3..}]"), ["scored"]);
+        assert_eq!(select("@e[m=survival]"), ["scored"]);
+        assert_eq!(select("@e[x=7,dx=1]"), ["scored"]);
+        assert_eq!(select("@e[c=2]"), ["zombie", "tagged"]);
+    }
+
+    #[test]
+    fn selects_the_executor() {
+        let executor = TestEntity::new(ActorType::Player, "executor", 0.0);
+        let entities = [TestEntity::new(ActorType::Player, "other", 1.0)];
+
+        let selector = TargetSelector::parse("@s").unwrap();
+        assert_eq!(
+            names(selector.select(&origin(Some(&executor)), &entities)),
+            ["executor"]
+        );
+        assert!(selector.select(&origin(None), &entities).is_empty());
+    }
+}
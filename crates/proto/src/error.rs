@@ -3,6 +3,7 @@ use std::error::Error;
 use std::io;
 use std::sync::Arc;
 
+use bedrockrs_addon::error::AddonError;
 use bedrockrs_proto_core::error::ProtoCodecError;
 use rak_rs::connection::queue::SendQueueError;
 use rak_rs::connection::RecvError;
@@ -10,6 +11,7 @@ use rak_rs::error::server::ServerError;
 use thiserror::Error;
 
 use crate::info::RAKNET_GAME_PACKET_ID;
+use crate::types::disconnect_reason::DisconnectReason;
 
 #[derive(Error, Debug)]
 pub enum ListenerError {
@@ -58,11 +60,50 @@ pub enum LoginError {
     #[error("Connection Error: {0}")]
     ConnectionError(#[from] ConnectionError),
     #[error("Login aborted, reason: {reason}")]
-    Abort { reason: String },
+    Abort {
+        reason: String,
+        disconnect_reason: DisconnectReason,
+    },
     #[error("Wrong protocol version (client: {client}, server: {server:?})")]
     WrongProtocolVersion { client: i32, server: Vec<i32> },
     #[error("Format Error: {0}")]
     FormatError(String),
+    #[error("Pack Error: {0}")]
+    PackError(#[from] AddonError),
+}
+
+#[cfg(feature = "cdn")]
+#[derive(Error, Debug, Clone)]
+pub enum CDNError {
+    #[error("IO Error: {0}")]
+    IOError(#[from] Arc<IOError>),
+    #[error("Pack Error: {0}")]
+    PackError(#[from] AddonError),
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum CommandError {
+    #[error("Unknown command: {0}")]
+    UnknownCommand(String),
+    /// The part of the command starting at `position` (a byte offset) couldn't be parsed.
+    #[error("Syntax error at {position}: {message}")]
+    SyntaxError {
+        position: usize,
+        length: usize,
+        message: String,
+    },
+}
+
+/// A command definition that can't be sent in an `AvailableCommandsPacket`.
+#[derive(Error, Debug, Clone)]
+pub enum AvailableCommandsError {
+    /// Enums are shared by name, so every parameter using it needs the same values.
+    #[error("Enum {0} is used with different values")]
+    ConflictingEnum(String),
+    #[error(
+        "Enum constraint for value {value} of enum {enum_name}, which isn't used by any command"
+    )]
+    UnknownEnumConstraint { enum_name: String, value: String },
 }
 
 #[derive(Error, Debug, Clone)]
@@ -4,48 +4,91 @@ use std::io::{Cursor, Write};
 use std::sync::Arc;
 
 use crate::packets::add_actor_packet::AddActorPacket;
+use crate::packets::add_item_entity_packet::AddItemEntityPacket;
 use crate::packets::add_painting_packet::AddPaintingPacket;
+use crate::packets::add_player_packet::AddPlayerPacket;
 use crate::packets::animate::AnimatePacket;
+use crate::packets::available_commands_packet::AvailableCommandsPacket;
+use crate::packets::block_entity_data_packet::BlockEntityDataPacket;
+use crate::packets::block_event_packet::BlockEventPacket;
+use crate::packets::boss_event_packet::BossEventPacket;
 use crate::packets::camera_packet::CameraPacket;
 use crate::packets::chunk_radius_updated::ChunkRadiusUpdatedPacket;
+use crate::packets::client_cache_blob_status_packet::ClientCacheBlobStatusPacket;
+use crate::packets::client_cache_miss_response_packet::ClientCacheMissResponsePacket;
 use crate::packets::client_cache_status::ClientCacheStatusPacket;
+use crate::packets::command_output_packet::CommandOutputPacket;
 use crate::packets::command_request_packet::CommandRequestPacket;
 use crate::packets::container_close_packet::ContainerClosePacket;
 use crate::packets::container_open_packet::ContainerOpenPacket;
 use crate::packets::correct_player_move_prediction_packet::CorrectPlayerMovePredictionPacket;
+use crate::packets::crafting_data_packet::CraftingDataPacket;
 use crate::packets::debug_info_packet::DebugInfoPacket;
 use crate::packets::disconnect::DisconnectPacket;
 use crate::packets::emote_list::EmoteListPacket;
+use crate::packets::entity_event_packet::EntityEventPacket;
 use crate::packets::handshake_server_to_client::HandshakeServerToClientPacket;
 use crate::packets::interact::InteractPacket;
 use crate::packets::inventory_content_packet::InventoryContentPacket;
+use crate::packets::inventory_slot_packet::InventorySlotPacket;
+use crate::packets::inventory_transaction_packet::InventoryTransactionPacket;
+use crate::packets::item_stack_request_packet::ItemStackRequestPacket;
+use crate::packets::item_stack_response_packet::ItemStackResponsePacket;
 use crate::packets::level_chunk::LevelChunkPacket;
+use crate::packets::level_event_generic_packet::LevelEventGenericPacket;
+use crate::packets::level_event_packet::LevelEventPacket;
+use crate::packets::level_sound_event_packet::LevelSoundEventPacket;
+use crate::packets::level_sound_event_v1_packet::LevelSoundEventV1Packet;
+use crate::packets::level_sound_event_v2_packet::LevelSoundEventV2Packet;
 use crate::packets::login::LoginPacket;
+use crate::packets::mob_armor_equipment_packet::MobArmorEquipmentPacket;
 use crate::packets::mob_equipment_packet::MobEquipmentPacket;
 use crate::packets::modal_form_request::ModalFormRequestPacket;
 use crate::packets::modal_form_response::ModalFormResponsePacket;
+use crate::packets::move_entity_delta_packet::MoveEntityDeltaPacket;
+use crate::packets::move_entity_packet::MoveEntityPacket;
+use crate::packets::network_chunk_publisher_update::NetworkChunkPublisherUpdatePacket;
 use crate::packets::network_settings::NetworkSettingsPacket;
 use crate::packets::network_settings_request::NetworkSettingsRequestPacket;
 use crate::packets::packet_violation_warning::PacketViolationWarningPacket;
+use crate::packets::play_sound_packet::PlaySoundPacket;
 use crate::packets::play_status::PlayStatusPacket;
 use crate::packets::player_action::PlayerActionPacket;
 use crate::packets::player_auth_input::PlayerAuthInputPacket;
 use crate::packets::player_hotbar_packet::PlayerHotbarPacket;
 use crate::packets::player_move::MovePlayerPacket;
 use crate::packets::remove_actor_packet::RemoveEntityPacket;
+use crate::packets::remove_objective_packet::RemoveObjectivePacket;
 use crate::packets::request_chunk_radius::RequestChunkRadiusPacket;
+use crate::packets::resource_pack_chunk_data::ResourcePackChunkDataPacket;
+use crate::packets::resource_pack_chunk_request::ResourcePackChunkRequestPacket;
+use crate::packets::resource_pack_data_info::ResourcePackDataInfoPacket;
 use crate::packets::resource_packs_info::ResourcePacksInfoPacket;
 use crate::packets::resource_packs_response::ResourcePacksResponsePacket;
 use crate::packets::resource_packs_stack::ResourcePacksStackPacket;
 use crate::packets::server_player_post_move_position_packet::ServerPlayerPostMovePositionPacket;
 use crate::packets::server_settings_request::ServerSettingsRequestPacket;
 use crate::packets::server_settings_response::ServerSettingsResponsePacket;
+use crate::packets::set_display_objective_packet::SetDisplayObjectivePacket;
+use crate::packets::set_entity_data_packet::SetEntityDataPacket;
+use crate::packets::set_entity_link_packet::SetEntityLinkPacket;
+use crate::packets::set_entity_motion_packet::SetEntityMotionPacket;
 use crate::packets::set_local_player_as_initialized::SetLocalPlayerAsInitializedPacket;
+use crate::packets::set_score_packet::SetScorePacket;
+use crate::packets::set_scoreboard_identity_packet::SetScoreboardIdentityPacket;
 use crate::packets::set_time_packet::SetTimePacket;
 use crate::packets::set_title_packet::SetTitlePacket;
+use crate::packets::spawn_particle_effect_packet::SpawnParticleEffectPacket;
 use crate::packets::start_game::StartGamePacket;
+use crate::packets::stop_sound_packet::StopSoundPacket;
+use crate::packets::sub_chunk_packet::SubChunkPacket;
+use crate::packets::sub_chunk_request_packet::SubChunkRequestPacket;
+use crate::packets::take_item_entity_packet::TakeItemEntityPacket;
 use crate::packets::text_message::TextMessagePacket;
 use crate::packets::toast_request_packet::ToastRequestPacket;
+use crate::packets::update_block_packet::UpdateBlockPacket;
+use crate::packets::update_block_synced_packet::UpdateBlockSyncedPacket;
+use crate::packets::update_sub_chunk_blocks_packet::UpdateSubChunkBlocksPacket;
 use bedrockrs_core::int::VAR;
 use bedrockrs_proto_core::error::ProtoCodecError;
 use bedrockrs_proto_core::ProtoCodec;
@@ -64,35 +107,35 @@ pub enum GamePacket {
     TextMessage(TextMessagePacket),
     SetTime(SetTimePacket),
     StartGame(StartGamePacket),
-    AddPlayer(),
+    AddPlayer(AddPlayerPacket),
     AddEntity(AddActorPacket),
     RemoveEntity(RemoveEntityPacket),
-    AddItemEntity(),
+    AddItemEntity(AddItemEntityPacket),
     ServerPlayerPostMovePositionPacket(ServerPlayerPostMovePositionPacket),
-    TakeItemEntity(),
-    MoveEntity(),
+    TakeItemEntity(TakeItemEntityPacket),
+    MoveEntity(MoveEntityPacket),
     MovePlayer(MovePlayerPacket),
     RiderJump(),
-    UpdateBlock(),
+    UpdateBlock(UpdateBlockPacket),
     AddPainting(AddPaintingPacket),
     TickSync(),
-    LevelSoundEventOld(),
-    LevelEvent(),
-    BlockEvent(),
-    EntityEvent(),
+    LevelSoundEventOld(LevelSoundEventV1Packet),
+    LevelEvent(LevelEventPacket),
+    BlockEvent(BlockEventPacket),
+    EntityEvent(EntityEventPacket),
     MobEffect(),
     UpdateAttributes(),
-    InventoryTransaction(),
+    InventoryTransaction(InventoryTransactionPacket),
     MobEquipment(MobEquipmentPacket),
-    MobArmorEquipment(),
+    MobArmorEquipment(MobArmorEquipmentPacket),
     Interact(InteractPacket),
     BlockPickRequest(),
     EntityPickRequest(),
     PlayerAction(PlayerActionPacket),
     HurtArmor(),
-    SetEntityData(),
-    SetEntityMotion(),
-    SetEntityLink(),
+    SetEntityData(SetEntityDataPacket),
+    SetEntityMotion(SetEntityMotionPacket),
+    SetEntityLink(SetEntityLinkPacket),
     SetHealth(),
     SetSpawnPosition(),
     Animate(AnimatePacket),
@@ -101,13 +144,13 @@ pub enum GamePacket {
     ContainerClose(ContainerClosePacket),
     PlayerHotbar(PlayerHotbarPacket),
     InventoryContent(InventoryContentPacket),
-    InventorySlot(),
+    InventorySlot(InventorySlotPacket),
     ContainerSetData(),
-    CraftingData(),
+    CraftingData(CraftingDataPacket),
     CraftingEvent(),
     GuiDataPickItem(),
     AdventureSettings(),
-    BlockEntityData(),
+    BlockEntityData(BlockEntityDataPacket),
     PlayerInput(),
     LevelChunk(LevelChunkPacket),
     SetCommandsEnabled(),
@@ -125,20 +168,20 @@ pub enum GamePacket {
     ItemFrameDropItem(),
     GameRulesChanged(),
     Camera(CameraPacket),
-    BossEvent(),
+    BossEvent(BossEventPacket),
     ShowCredits(),
-    AvailableCommands(),
+    AvailableCommands(AvailableCommandsPacket),
     CommandRequest(CommandRequestPacket),
     CommandBlockUpdate(),
-    CommandOutput(),
+    CommandOutput(CommandOutputPacket),
     UpdateTrade(),
     UpdateEquipment(),
-    ResourcePackDataInfo(),
-    ResourcePackChunkData(),
-    ResourcePackChunkRequest(),
+    ResourcePackDataInfo(ResourcePackDataInfoPacket),
+    ResourcePackChunkData(ResourcePackChunkDataPacket),
+    ResourcePackChunkRequest(ResourcePackChunkRequestPacket),
     Transfer(),
-    PlaySound(),
-    StopSound(),
+    PlaySound(PlaySoundPacket),
+    StopSound(StopSoundPacket),
     SetTitle(SetTitlePacket),
     AddBehaviorTree(),
     StructureBlockUpdate(),
@@ -157,24 +200,24 @@ pub enum GamePacket {
     ServerSettingsResponse(ServerSettingsResponsePacket),
     ShowProfile(),
     SetDefaultGameType(),
-    RemoveObjective(),
-    SetDisplayObjective(),
-    SetScore(),
+    RemoveObjective(RemoveObjectivePacket),
+    SetDisplayObjective(SetDisplayObjectivePacket),
+    SetScore(SetScorePacket),
     LabTable(),
-    UpdateBlockSynced(),
-    MoveEntityDelta(),
-    SetScoreboardIdentity(),
+    UpdateBlockSynced(UpdateBlockSyncedPacket),
+    MoveEntityDelta(MoveEntityDeltaPacket),
+    SetScoreboardIdentity(SetScoreboardIdentityPacket),
     SetLocalPlayerAsInitialized(SetLocalPlayerAsInitializedPacket),
     UpdateSoftEnum(),
     NetworkStackLatency(),
     ScriptCustomEvent(),
-    SpawnParticleEffect(),
+    SpawnParticleEffect(SpawnParticleEffectPacket),
     AvailableEntityIdentifiers(),
-    LevelSoundEventV2(),
-    NetworkChunkPublisherUpdate(),
+    LevelSoundEventV2(LevelSoundEventV2Packet),
+    NetworkChunkPublisherUpdate(NetworkChunkPublisherUpdatePacket),
     BiomeDefinitionList(),
-    LevelSoundEvent(),
-    LevelEventGeneric(),
+    LevelSoundEvent(LevelSoundEventPacket),
+    LevelEventGeneric(LevelEventGenericPacket),
     LecternUpdate(),
     VideoStreamConnect(),
     ClientCacheStatus(ClientCacheStatusPacket),
@@ -183,14 +226,14 @@ pub enum GamePacket {
     StructureTemplateDataExportRequest(),
     StructureTemplateDataExportResponse(),
     UpdateBlockProperties(),
-    ClientCacheBlobStatus(),
-    ClientCacheMissResponse(),
+    ClientCacheBlobStatus(ClientCacheBlobStatusPacket),
+    ClientCacheMissResponse(ClientCacheMissResponsePacket),
     NetworkSettings(NetworkSettingsPacket),
     PlayerAuthInput(PlayerAuthInputPacket),
     CreativeContent(),
     PlayerEnchantOptions(),
-    ItemStackRequest(),
-    ItemStackResponse(),
+    ItemStackRequest(ItemStackRequestPacket),
+    ItemStackResponse(ItemStackResponsePacket),
     UpdatePlayerGameType(),
     EmoteList(EmoteListPacket),
     DebugInfoPacket(DebugInfoPacket),
@@ -198,9 +241,9 @@ pub enum GamePacket {
     CorrectPlayerMovePredictionPacket(CorrectPlayerMovePredictionPacket),
     ItemComponent(),
     FilterTextPacket(),
-    UpdateSubChunkBlocksPacket(),
-    SubChunkPacket(),
-    SubChunkRequestPacket(),
+    UpdateSubChunkBlocksPacket(UpdateSubChunkBlocksPacket),
+    SubChunkPacket(SubChunkPacket),
+    SubChunkRequestPacket(SubChunkRequestPacket),
     DimensionData(),
     ToastRequestPacket(ToastRequestPacket),
     RequestNetworkSettings(NetworkSettingsRequestPacket),
@@ -451,8 +494,8 @@ impl GamePacket {
             GamePacket::StartGame(pk) => {
                 ser_packet!(stream, GamePacket::StartGameID, pk)
             }
-            GamePacket::AddPlayer() => {
-                unimplemented!()
+            GamePacket::AddPlayer(pk) => {
+                ser_packet!(stream, GamePacket::AddPlayerID, pk)
             }
             GamePacket::AddEntity(pk) => {
                 ser_packet!(stream, GamePacket::AddEntityID, pk)
@@ -460,17 +503,17 @@ impl GamePacket {
             GamePacket::RemoveEntity(pk) => {
                 ser_packet!(stream, GamePacket::RemoveEntityID, pk)
             }
-            GamePacket::AddItemEntity() => {
-                unimplemented!()
+            GamePacket::AddItemEntity(pk) => {
+                ser_packet!(stream, GamePacket::AddItemEntityID, pk)
             }
             GamePacket::ServerPlayerPostMovePositionPacket(pk) => {
                 ser_packet!(stream, GamePacket::ServerPlayerPostMovePositionPacketID, pk)
             }
-            GamePacket::TakeItemEntity() => {
-                unimplemented!()
+            GamePacket::TakeItemEntity(pk) => {
+                ser_packet!(stream, GamePacket::TakeItemEntityID, pk)
             }
-            GamePacket::MoveEntity() => {
-                unimplemented!()
+            GamePacket::MoveEntity(pk) => {
+                ser_packet!(stream, GamePacket::MoveEntityID, pk)
             }
             GamePacket::MovePlayer(pk) => {
                 ser_packet!(stream, GamePacket::MovePlayerID, pk)
@@ -478,8 +521,8 @@ impl GamePacket {
             GamePacket::RiderJump() => {
                 unimplemented!()
             }
-            GamePacket::UpdateBlock() => {
-                unimplemented!()
+            GamePacket::UpdateBlock(pk) => {
+                ser_packet!(stream, GamePacket::UpdateBlockID, pk)
             }
             GamePacket::AddPainting(pk) => {
                 ser_packet!(stream, GamePacket::AddPaintingID, pk)
@@ -487,17 +530,17 @@ impl GamePacket {
             GamePacket::TickSync() => {
                 unimplemented!()
             }
-            GamePacket::LevelSoundEventOld() => {
-                unimplemented!()
+            GamePacket::LevelSoundEventOld(pk) => {
+                ser_packet!(stream, GamePacket::LevelSoundEventOldID, pk)
             }
-            GamePacket::LevelEvent() => {
-                unimplemented!()
+            GamePacket::LevelEvent(pk) => {
+                ser_packet!(stream, GamePacket::LevelEventID, pk)
             }
-            GamePacket::BlockEvent() => {
-                unimplemented!()
+            GamePacket::BlockEvent(pk) => {
+                ser_packet!(stream, GamePacket::BlockEventID, pk)
             }
-            GamePacket::EntityEvent() => {
-                unimplemented!()
+            GamePacket::EntityEvent(pk) => {
+                ser_packet!(stream, GamePacket::EntityEventID, pk)
             }
             GamePacket::MobEffect() => {
                 unimplemented!()
@@ -505,14 +548,14 @@ impl GamePacket {
             GamePacket::UpdateAttributes() => {
                 unimplemented!()
             }
-            GamePacket::InventoryTransaction() => {
-                unimplemented!()
+            GamePacket::InventoryTransaction(pk) => {
+                ser_packet!(stream, GamePacket::InventoryTransactionID, pk)
             }
             GamePacket::MobEquipment(pk) => {
                 ser_packet!(stream, GamePacket::MobEquipmentID, pk)
             }
-            GamePacket::MobArmorEquipment() => {
-                unimplemented!()
+            GamePacket::MobArmorEquipment(pk) => {
+                ser_packet!(stream, GamePacket::MobArmorEquipmentID, pk)
             }
             GamePacket::Interact(pk) => {
                 ser_packet!(stream, GamePacket::InteractID, pk)
@@ -529,14 +572,14 @@ impl GamePacket {
             GamePacket::HurtArmor() => {
                 unimplemented!()
             }
-            GamePacket::SetEntityData() => {
-                unimplemented!()
+            GamePacket::SetEntityData(pk) => {
+                ser_packet!(stream, GamePacket::SetEntityDataID, pk)
             }
-            GamePacket::SetEntityMotion() => {
-                unimplemented!()
+            GamePacket::SetEntityMotion(pk) => {
+                ser_packet!(stream, GamePacket::SetEntityMotionID, pk)
             }
-            GamePacket::SetEntityLink() => {
-                unimplemented!()
+            GamePacket::SetEntityLink(pk) => {
+                ser_packet!(stream, GamePacket::SetEntityLinkID, pk)
             }
             GamePacket::SetHealth() => {
                 unimplemented!()
@@ -562,14 +605,14 @@ impl GamePacket {
             GamePacket::InventoryContent(pk) => {
                 ser_packet!(stream, GamePacket::InventoryContentID, pk)
             }
-            GamePacket::InventorySlot() => {
-                unimplemented!()
+            GamePacket::InventorySlot(pk) => {
+                ser_packet!(stream, GamePacket::InventorySlotID, pk)
             }
             GamePacket::ContainerSetData() => {
                 unimplemented!()
             }
-            GamePacket::CraftingData() => {
-                unimplemented!()
+            GamePacket::CraftingData(pk) => {
+                ser_packet!(stream, GamePacket::CraftingDataID, pk)
             }
             GamePacket::CraftingEvent() => {
                 unimplemented!()
@@ -580,8 +623,8 @@ impl GamePacket {
             GamePacket::AdventureSettings() => {
                 unimplemented!()
             }
-            GamePacket::BlockEntityData() => {
-                unimplemented!()
+            GamePacket::BlockEntityData(pk) => {
+                ser_packet!(stream, GamePacket::BlockEntityDataID, pk)
             }
             GamePacket::PlayerInput() => {
                 unimplemented!()
@@ -634,14 +677,14 @@ impl GamePacket {
             GamePacket::Camera(pk) => {
                 ser_packet!(stream, GamePacket::CameraID, pk)
             }
-            GamePacket::BossEvent() => {
-                unimplemented!()
+            GamePacket::BossEvent(pk) => {
+                ser_packet!(stream, GamePacket::BossEventID, pk)
             }
             GamePacket::ShowCredits() => {
                 unimplemented!()
             }
-            GamePacket::AvailableCommands() => {
-                unimplemented!()
+            GamePacket::AvailableCommands(pk) => {
+                ser_packet!(stream, GamePacket::AvailableCommandsID, pk)
             }
             GamePacket::CommandRequest(pk) => {
                 ser_packet!(stream, GamePacket::CommandRequestID, pk)
@@ -649,8 +692,8 @@ impl GamePacket {
             GamePacket::CommandBlockUpdate() => {
                 unimplemented!()
             }
-            GamePacket::CommandOutput() => {
-                unimplemented!()
+            GamePacket::CommandOutput(pk) => {
+                ser_packet!(stream, GamePacket::CommandOutputID, pk)
             }
             GamePacket::UpdateTrade() => {
                 unimplemented!()
@@ -658,23 +701,23 @@ impl GamePacket {
             GamePacket::UpdateEquipment() => {
                 unimplemented!()
             }
-            GamePacket::ResourcePackDataInfo() => {
-                unimplemented!()
+            GamePacket::ResourcePackDataInfo(pk) => {
+                ser_packet!(stream, GamePacket::ResourcePackDataInfoID, pk)
             }
-            GamePacket::ResourcePackChunkData() => {
-                unimplemented!()
+            GamePacket::ResourcePackChunkData(pk) => {
+                ser_packet!(stream, GamePacket::ResourcePackChunkDataID, pk)
             }
-            GamePacket::ResourcePackChunkRequest() => {
-                unimplemented!()
+            GamePacket::ResourcePackChunkRequest(pk) => {
+                ser_packet!(stream, GamePacket::ResourcePackChunkRequestID, pk)
             }
             GamePacket::Transfer() => {
                 unimplemented!()
             }
-            GamePacket::PlaySound() => {
-                unimplemented!()
+            GamePacket::PlaySound(pk) => {
+                ser_packet!(stream, GamePacket::PlaySoundID, pk)
             }
-            GamePacket::StopSound() => {
-                unimplemented!()
+            GamePacket::StopSound(pk) => {
+                ser_packet!(stream, GamePacket::StopSoundID, pk)
             }
             GamePacket::SetTitle(pk) => {
                 ser_packet!(stream, GamePacket::SetTitleID, pk)
@@ -730,26 +773,26 @@ impl GamePacket {
             GamePacket::SetDefaultGameType() => {
                 unimplemented!()
             }
-            GamePacket::RemoveObjective() => {
-                unimplemented!()
+            GamePacket::RemoveObjective(pk) => {
+                ser_packet!(stream, GamePacket::RemoveObjectiveID, pk)
             }
-            GamePacket::SetDisplayObjective() => {
-                unimplemented!()
+            GamePacket::SetDisplayObjective(pk) => {
+                ser_packet!(stream, GamePacket::SetDisplayObjectiveID, pk)
             }
-            GamePacket::SetScore() => {
-                unimplemented!()
+            GamePacket::SetScore(pk) => {
+                ser_packet!(stream, GamePacket::SetScoreID, pk)
             }
             GamePacket::LabTable() => {
                 unimplemented!()
             }
-            GamePacket::UpdateBlockSynced() => {
-                unimplemented!()
+            GamePacket::UpdateBlockSynced(pk) => {
+                ser_packet!(stream, GamePacket::UpdateBlockSyncedID, pk)
             }
-            GamePacket::MoveEntityDelta() => {
-                unimplemented!()
+            GamePacket::MoveEntityDelta(pk) => {
+                ser_packet!(stream, GamePacket::MoveEntityDeltaID, pk)
             }
-            GamePacket::SetScoreboardIdentity() => {
-                unimplemented!()
+            GamePacket::SetScoreboardIdentity(pk) => {
+                ser_packet!(stream, GamePacket::SetScoreboardIdentityID, pk)
             }
             GamePacket::SetLocalPlayerAsInitialized(pk) => {
                 ser_packet!(stream, GamePacket::SetLocalPlayerAsInitializedID, pk)
@@ -763,26 +806,26 @@ impl GamePacket {
             GamePacket::ScriptCustomEvent() => {
                 unimplemented!()
             }
-            GamePacket::SpawnParticleEffect() => {
-                unimplemented!()
+            GamePacket::SpawnParticleEffect(pk) => {
+                ser_packet!(stream, GamePacket::SpawnParticleEffectID, pk)
             }
             GamePacket::AvailableEntityIdentifiers() => {
                 unimplemented!()
             }
-            GamePacket::LevelSoundEventV2() => {
-                unimplemented!()
+            GamePacket::LevelSoundEventV2(pk) => {
+                ser_packet!(stream, GamePacket::LevelSoundEventV2ID, pk)
             }
-            GamePacket::NetworkChunkPublisherUpdate() => {
-                unimplemented!()
+            GamePacket::NetworkChunkPublisherUpdate(pk) => {
+                ser_packet!(stream, GamePacket::NetworkChunkPublisherUpdateID, pk)
             }
             GamePacket::BiomeDefinitionList() => {
                 unimplemented!()
             }
-            GamePacket::LevelSoundEvent() => {
-                unimplemented!()
+            GamePacket::LevelSoundEvent(pk) => {
+                ser_packet!(stream, GamePacket::LevelSoundEventID, pk)
             }
-            GamePacket::LevelEventGeneric() => {
-                unimplemented!()
+            GamePacket::LevelEventGeneric(pk) => {
+                ser_packet!(stream, GamePacket::LevelEventGenericID, pk)
             }
             GamePacket::LecternUpdate() => {
                 unimplemented!()
@@ -808,11 +851,11 @@ impl GamePacket {
             GamePacket::UpdateBlockProperties() => {
                 unimplemented!()
             }
-            GamePacket::ClientCacheBlobStatus() => {
-                unimplemented!()
+            GamePacket::ClientCacheBlobStatus(pk) => {
+                ser_packet!(stream, GamePacket::ClientCacheBlobStatusID, pk)
             }
-            GamePacket::ClientCacheMissResponse() => {
-                unimplemented!()
+            GamePacket::ClientCacheMissResponse(pk) => {
+                ser_packet!(stream, GamePacket::ClientCacheMissResponseID, pk)
             }
             GamePacket::NetworkSettings(pk) => {
                 ser_packet!(stream, GamePacket::NetworkSettingsID, pk)
@@ -826,11 +869,11 @@ impl GamePacket {
             GamePacket::PlayerEnchantOptions() => {
                 unimplemented!()
             }
-            GamePacket::ItemStackRequest() => {
-                unimplemented!()
+            GamePacket::ItemStackRequest(pk) => {
+                ser_packet!(stream, GamePacket::ItemStackRequestID, pk)
             }
-            GamePacket::ItemStackResponse() => {
-                unimplemented!()
+            GamePacket::ItemStackResponse(pk) => {
+                ser_packet!(stream, GamePacket::ItemStackResponseID, pk)
             }
             GamePacket::UpdatePlayerGameType() => {
                 unimplemented!()
@@ -853,14 +896,14 @@ impl GamePacket {
             GamePacket::FilterTextPacket() => {
                 unimplemented!()
             }
-            GamePacket::UpdateSubChunkBlocksPacket() => {
-                unimplemented!()
+            GamePacket::UpdateSubChunkBlocksPacket(pk) => {
+                ser_packet!(stream, GamePacket::UpdateSubChunkBlocksPacketID, pk)
             }
-            GamePacket::SubChunkPacket() => {
-                unimplemented!()
+            GamePacket::SubChunkPacket(pk) => {
+                ser_packet!(stream, GamePacket::SubChunkPacketID, pk)
             }
-            GamePacket::SubChunkRequestPacket() => {
-                unimplemented!()
+            GamePacket::SubChunkRequestPacket(pk) => {
+                ser_packet!(stream, GamePacket::SubChunkRequestPacketID, pk)
             }
             GamePacket::DimensionData() => {
                 unimplemented!()
@@ -932,15 +975,13 @@ impl GamePacket {
             }
             GamePacket::SetTimeID => GamePacket::SetTime(de_packet!(stream, SetTimePacket)),
             GamePacket::StartGameID => GamePacket::StartGame(de_packet!(stream, StartGamePacket)),
-            GamePacket::AddPlayerID => {
-                unimplemented!()
-            }
+            GamePacket::AddPlayerID => GamePacket::AddPlayer(de_packet!(stream, AddPlayerPacket)),
             GamePacket::AddEntityID => GamePacket::AddEntity(de_packet!(stream, AddActorPacket)),
             GamePacket::RemoveEntityID => {
                 GamePacket::RemoveEntity(de_packet!(stream, RemoveEntityPacket))
             }
             GamePacket::AddItemEntityID => {
-                unimplemented!()
+                GamePacket::AddItemEntity(de_packet!(stream, AddItemEntityPacket))
             }
             GamePacket::ServerPlayerPostMovePositionPacketID => {
                 GamePacket::ServerPlayerPostMovePositionPacket(de_packet!(
@@ -949,10 +990,10 @@ impl GamePacket {
                 ))
             }
             GamePacket::TakeItemEntityID => {
-                unimplemented!()
+                GamePacket::TakeItemEntity(de_packet!(stream, TakeItemEntityPacket))
             }
             GamePacket::MoveEntityID => {
-                unimplemented!()
+                GamePacket::MoveEntity(de_packet!(stream, MoveEntityPacket))
             }
             GamePacket::MovePlayerID => {
                 GamePacket::MovePlayer(de_packet!(stream, MovePlayerPacket))
@@ -961,7 +1002,7 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::UpdateBlockID => {
-                unimplemented!()
+                GamePacket::UpdateBlock(de_packet!(stream, UpdateBlockPacket))
             }
             GamePacket::AddPaintingID => {
                 GamePacket::AddPainting(de_packet!(stream, AddPaintingPacket))
@@ -970,16 +1011,16 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::LevelSoundEventOldID => {
-                unimplemented!()
+                GamePacket::LevelSoundEventOld(de_packet!(stream, LevelSoundEventV1Packet))
             }
             GamePacket::LevelEventID => {
-                unimplemented!()
+                GamePacket::LevelEvent(de_packet!(stream, LevelEventPacket))
             }
             GamePacket::BlockEventID => {
-                unimplemented!()
+                GamePacket::BlockEvent(de_packet!(stream, BlockEventPacket))
             }
             GamePacket::EntityEventID => {
-                unimplemented!()
+                GamePacket::EntityEvent(de_packet!(stream, EntityEventPacket))
             }
             GamePacket::MobEffectID => {
                 unimplemented!()
@@ -988,13 +1029,13 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::InventoryTransactionID => {
-                unimplemented!()
+                GamePacket::InventoryTransaction(de_packet!(stream, InventoryTransactionPacket))
             }
             GamePacket::MobEquipmentID => {
                 GamePacket::MobEquipment(de_packet!(stream, MobEquipmentPacket))
             }
             GamePacket::MobArmorEquipmentID => {
-                unimplemented!()
+                GamePacket::MobArmorEquipment(de_packet!(stream, MobArmorEquipmentPacket))
             }
             GamePacket::InteractID => GamePacket::Interact(de_packet!(stream, InteractPacket)),
             GamePacket::BlockPickRequestID => {
@@ -1010,13 +1051,13 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::SetEntityDataID => {
-                unimplemented!()
+                GamePacket::SetEntityData(de_packet!(stream, SetEntityDataPacket))
             }
             GamePacket::SetEntityMotionID => {
-                unimplemented!()
+                GamePacket::SetEntityMotion(de_packet!(stream, SetEntityMotionPacket))
             }
             GamePacket::SetEntityLinkID => {
-                unimplemented!()
+                GamePacket::SetEntityLink(de_packet!(stream, SetEntityLinkPacket))
             }
             GamePacket::SetHealthID => {
                 unimplemented!()
@@ -1041,13 +1082,13 @@ impl GamePacket {
                 GamePacket::InventoryContent(de_packet!(stream, InventoryContentPacket))
             }
             GamePacket::InventorySlotID => {
-                unimplemented!()
+                GamePacket::InventorySlot(de_packet!(stream, InventorySlotPacket))
             }
             GamePacket::ContainerSetDataID => {
                 unimplemented!()
             }
             GamePacket::CraftingDataID => {
-                unimplemented!()
+                GamePacket::CraftingData(de_packet!(stream, CraftingDataPacket))
             }
             GamePacket::CraftingEventID => {
                 unimplemented!()
@@ -1059,7 +1100,7 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::BlockEntityDataID => {
-                unimplemented!()
+                GamePacket::BlockEntityData(de_packet!(stream, BlockEntityDataPacket))
             }
             GamePacket::PlayerInputID => {
                 unimplemented!()
@@ -1101,7 +1142,7 @@ impl GamePacket {
                 GamePacket::RequestChunkRadius(de_packet!(stream, RequestChunkRadiusPacket))
             }
             GamePacket::ChunkRadiusUpdateID => {
-                unimplemented!()
+                GamePacket::ChunkRadiusUpdate(de_packet!(stream, ChunkRadiusUpdatedPacket))
             }
             GamePacket::ItemFrameDropItemID => {
                 unimplemented!()
@@ -1110,14 +1151,12 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::CameraID => GamePacket::Camera(de_packet!(stream, CameraPacket)),
-            GamePacket::BossEventID => {
-                unimplemented!()
-            }
+            GamePacket::BossEventID => GamePacket::BossEvent(de_packet!(stream, BossEventPacket)),
             GamePacket::ShowCreditsID => {
                 unimplemented!()
             }
             GamePacket::AvailableCommandsID => {
-                unimplemented!()
+                GamePacket::AvailableCommands(de_packet!(stream, AvailableCommandsPacket))
             }
             GamePacket::CommandRequestID => {
                 GamePacket::CommandRequest(de_packet!(stream, CommandRequestPacket))
@@ -1126,7 +1165,7 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::CommandOutputID => {
-                unimplemented!()
+                GamePacket::CommandOutput(de_packet!(stream, CommandOutputPacket))
             }
             GamePacket::UpdateTradeID => {
                 unimplemented!()
@@ -1135,23 +1174,19 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::ResourcePackDataInfoID => {
-                unimplemented!()
+                GamePacket::ResourcePackDataInfo(de_packet!(stream, ResourcePackDataInfoPacket))
             }
             GamePacket::ResourcePackChunkDataID => {
-                unimplemented!()
-            }
-            GamePacket::ResourcePackChunkRequestID => {
-                unimplemented!()
+                GamePacket::ResourcePackChunkData(de_packet!(stream, ResourcePackChunkDataPacket))
             }
+            GamePacket::ResourcePackChunkRequestID => GamePacket::ResourcePackChunkRequest(
+                de_packet!(stream, ResourcePackChunkRequestPacket),
+            ),
             GamePacket::TransferID => {
                 unimplemented!()
             }
-            GamePacket::PlaySoundID => {
-                unimplemented!()
-            }
-            GamePacket::StopSoundID => {
-                unimplemented!()
-            }
+            GamePacket::PlaySoundID => GamePacket::PlaySound(de_packet!(stream, PlaySoundPacket)),
+            GamePacket::StopSoundID => GamePacket::StopSound(de_packet!(stream, StopSoundPacket)),
             GamePacket::SetTitleID => GamePacket::SetTitle(de_packet!(stream, SetTitlePacket)),
             GamePacket::AddBehaviorTreeID => {
                 unimplemented!()
@@ -1205,25 +1240,23 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::RemoveObjectiveID => {
-                unimplemented!()
+                GamePacket::RemoveObjective(de_packet!(stream, RemoveObjectivePacket))
             }
             GamePacket::SetDisplayObjectiveID => {
-                unimplemented!()
-            }
-            GamePacket::SetScoreID => {
-                unimplemented!()
+                GamePacket::SetDisplayObjective(de_packet!(stream, SetDisplayObjectivePacket))
             }
+            GamePacket::SetScoreID => GamePacket::SetScore(de_packet!(stream, SetScorePacket)),
             GamePacket::LabTableID => {
                 unimplemented!()
             }
             GamePacket::UpdateBlockSyncedID => {
-                unimplemented!()
+                GamePacket::UpdateBlockSynced(de_packet!(stream, UpdateBlockSyncedPacket))
             }
             GamePacket::MoveEntityDeltaID => {
-                unimplemented!()
+                GamePacket::MoveEntityDelta(de_packet!(stream, MoveEntityDeltaPacket))
             }
             GamePacket::SetScoreboardIdentityID => {
-                unimplemented!()
+                GamePacket::SetScoreboardIdentity(de_packet!(stream, SetScoreboardIdentityPacket))
             }
             GamePacket::SetLocalPlayerAsInitializedID => GamePacket::SetLocalPlayerAsInitialized(
                 de_packet!(stream, SetLocalPlayerAsInitializedPacket),
@@ -1238,25 +1271,25 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::SpawnParticleEffectID => {
-                unimplemented!()
+                GamePacket::SpawnParticleEffect(de_packet!(stream, SpawnParticleEffectPacket))
             }
             GamePacket::AvailableEntityIdentifiersID => {
                 unimplemented!()
             }
             GamePacket::LevelSoundEventV2ID => {
-                unimplemented!()
-            }
-            GamePacket::NetworkChunkPublisherUpdateID => {
-                unimplemented!()
+                GamePacket::LevelSoundEventV2(de_packet!(stream, LevelSoundEventV2Packet))
             }
+            GamePacket::NetworkChunkPublisherUpdateID => GamePacket::NetworkChunkPublisherUpdate(
+                de_packet!(stream, NetworkChunkPublisherUpdatePacket),
+            ),
             GamePacket::BiomeDefinitionListID => {
                 unimplemented!()
             }
             GamePacket::LevelSoundEventID => {
-                unimplemented!()
+                GamePacket::LevelSoundEvent(de_packet!(stream, LevelSoundEventPacket))
             }
             GamePacket::LevelEventGenericID => {
-                unimplemented!()
+                GamePacket::LevelEventGeneric(de_packet!(stream, LevelEventGenericPacket))
             }
             GamePacket::LecternUpdateID => {
                 unimplemented!()
@@ -1283,11 +1316,11 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::ClientCacheBlobStatusID => {
-                unimplemented!()
-            }
-            GamePacket::ClientCacheMissResponseID => {
-                unimplemented!()
+                GamePacket::ClientCacheBlobStatus(de_packet!(stream, ClientCacheBlobStatusPacket))
             }
+            GamePacket::ClientCacheMissResponseID => GamePacket::ClientCacheMissResponse(
+                de_packet!(stream, ClientCacheMissResponsePacket),
+            ),
             GamePacket::NetworkSettingsID => {
                 GamePacket::NetworkSettings(de_packet!(stream, NetworkSettingsPacket))
             }
@@ -1301,10 +1334,10 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::ItemStackRequestID => {
-                unimplemented!()
+                GamePacket::ItemStackRequest(de_packet!(stream, ItemStackRequestPacket))
             }
             GamePacket::ItemStackResponseID => {
-                unimplemented!()
+                GamePacket::ItemStackResponse(de_packet!(stream, ItemStackResponsePacket))
             }
             GamePacket::UpdatePlayerGameTypeID => {
                 unimplemented!()
@@ -1328,14 +1361,14 @@ impl GamePacket {
             GamePacket::FilterTextPacketID => {
                 unimplemented!()
             }
-            GamePacket::UpdateSubChunkBlocksPacketID => {
-                unimplemented!()
-            }
+            GamePacket::UpdateSubChunkBlocksPacketID => GamePacket::UpdateSubChunkBlocksPacket(
+                de_packet!(stream, UpdateSubChunkBlocksPacket),
+            ),
             GamePacket::SubChunkPacketID => {
-                unimplemented!()
+                GamePacket::SubChunkPacket(de_packet!(stream, SubChunkPacket))
             }
             GamePacket::SubChunkRequestPacketID => {
-                unimplemented!()
+                GamePacket::SubChunkRequestPacket(de_packet!(stream, SubChunkRequestPacket))
             }
             GamePacket::DimensionDataID => {
                 unimplemented!()
@@ -0,0 +1,105 @@
+use std::collections::BTreeSet;
+
+use bedrockrs_core::int::VAR;
+
+use crate::gamepacket::GamePacket;
+use crate::packets::inventory_content_packet::InventoryContentPacket;
+use crate::packets::inventory_slot_packet::InventorySlotPacket;
+use crate::types::network_item_stack_descriptor::NetworkItemStackDescriptor;
+
+/// The slots of a container the client knows by its window id,
+/// remembers which slots changed since the last sync.
+#[derive(Debug, Clone)]
+pub struct InventoryContainer {
+    window_id: u32,
+    slots: Vec<NetworkItemStackDescriptor>,
+    dirty: BTreeSet<usize>,
+}
+
+impl InventoryContainer {
+    /// Creates a container filled with air.
+    pub fn new(window_id: u32, size: usize) -> Self {
+        Self {
+            window_id,
+            slots: vec![NetworkItemStackDescriptor::air(); size],
+            dirty: BTreeSet::new(),
+        }
+    }
+
+    pub fn window_id(&self) -> u32 {
+        self.window_id
+    }
+
+    pub fn size(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn get(&self, slot: usize) -> Option<&NetworkItemStackDescriptor> {
+        self.slots.get(slot)
+    }
+
+    pub fn slots(&self) -> &[NetworkItemStackDescriptor] {
+        &self.slots
+    }
+
+    /// Sets the item in the slot and returns the previous one,
+    /// `None` if the slot is out of bounds.
+    pub fn set(
+        &mut self,
+        slot: usize,
+        item: NetworkItemStackDescriptor,
+    ) -> Option<NetworkItemStackDescriptor> {
+        let old = std::mem::replace(self.slots.get_mut(slot)?, item);
+        self.dirty.insert(slot);
+        Some(old)
+    }
+
+    /// Replaces the item in the slot with air and returns it.
+    pub fn take(&mut self, slot: usize) -> Option<NetworkItemStackDescriptor> {
+        self.set(slot, NetworkItemStackDescriptor::air())
+    }
+
+    /// Replaces every item with air.
+    pub fn clear(&mut self) {
+        for slot in 0..self.slots.len() {
+            self.take(slot);
+        }
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    pub fn is_slot_dirty(&self, slot: usize) -> bool {
+        self.dirty.contains(&slot)
+    }
+
+    /// Marks every slot as changed, so the next sync resends the whole container.
+    pub fn mark_all_dirty(&mut self) {
+        self.dirty.extend(0..self.slots.len());
+    }
+
+    /// The packets needed to update the client, an [`InventoryContentPacket`] if
+    /// more than half of the slots changed, otherwise an [`InventorySlotPacket`] per slot.
+    pub fn sync_packets(&mut self) -> Vec<GamePacket> {
+        let dirty = std::mem::take(&mut self.dirty);
+
+        if dirty.len() * 2 > self.slots.len() {
+            return vec![GamePacket::InventoryContent(InventoryContentPacket {
+                inventory_id: VAR::new(self.window_id),
+                slots: self.slots.clone(),
+            })];
+        }
+
+        dirty
+            .into_iter()
+            .map(|slot| {
+                GamePacket::InventorySlot(InventorySlotPacket {
+                    inventory_id: VAR::new(self.window_id),
+                    slot: VAR::new(slot as u32),
+                    item: self.slots[slot].clone(),
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,7 @@
+pub use container::*;
+pub use player::*;
+pub use virtual_container::*;
+
+pub mod container;
+pub mod player;
+pub mod virtual_container;
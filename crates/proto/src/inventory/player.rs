@@ -0,0 +1,307 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+use crate::gamepacket::GamePacket;
+use crate::inventory::container::InventoryContainer;
+use crate::packets::mob_armor_equipment_packet::MobArmorEquipmentPacket;
+use crate::packets::mob_equipment_packet::MobEquipmentPacket;
+use crate::packets::player_hotbar_packet::PlayerHotbarPacket;
+use crate::types::container_id::ContainerID;
+use crate::types::network_item_stack_descriptor::NetworkItemStackDescriptor;
+
+pub const MAIN_SIZE: usize = 36;
+pub const HOTBAR_SIZE: usize = 9;
+pub const ARMOR_SIZE: usize = 4;
+pub const OFFHAND_SIZE: usize = 1;
+pub const UI_SIZE: usize = 54;
+/// The slot of the cursor in the UI container.
+pub const CURSOR_SLOT: usize = 0;
+
+/// The server side state of a player's inventories.
+///
+/// Changes are collected until [`PlayerInventory::sync`] is called, which returns
+/// only the packets needed to update the player and the players viewing them.
+#[derive(Debug, Clone)]
+pub struct PlayerInventory {
+    main: InventoryContainer,
+    armor: InventoryContainer,
+    offhand: InventoryContainer,
+    ui: InventoryContainer,
+    selected_slot: usize,
+    selected_slot_dirty: bool,
+    held_item_dirty: bool,
+}
+
+/// The packets returned by [`PlayerInventory::sync`].
+#[derive(Debug, Clone, Default)]
+pub struct InventorySync {
+    /// Packets for the player owning the inventory
+    pub player: Vec<GamePacket>,
+    /// Packets for all other players who can see the player
+    pub viewers: Vec<GamePacket>,
+}
+
+impl PlayerInventory {
+    /// Creates an empty inventory with the first hotbar slot selected.
+    pub fn new() -> Self {
+        Self {
+            main: InventoryContainer::new(
+                window_id(ContainerID::CONTAINER_ID_INVENTORY),
+                MAIN_SIZE,
+            ),
+            armor: InventoryContainer::new(window_id(ContainerID::CONTAINER_ID_ARMOR), ARMOR_SIZE),
+            offhand: InventoryContainer::new(
+                window_id(ContainerID::CONTAINER_ID_OFFHAND),
+                OFFHAND_SIZE,
+            ),
+            ui: InventoryContainer::new(
+                window_id(ContainerID::CONTAINER_ID_PLAYER_ONLY_UI),
+                UI_SIZE,
+            ),
+            selected_slot: 0,
+            selected_slot_dirty: false,
+            held_item_dirty: false,
+        }
+    }
+
+    /// The hotbar and the rest of the inventory, slots `0..9` are the hotbar.
+    pub fn main(&self) -> &InventoryContainer {
+        &self.main
+    }
+
+    pub fn main_mut(&mut self) -> &mut InventoryContainer {
+        &mut self.main
+    }
+
+    /// Helmet, chestplate, leggings and boots in that order.
+    pub fn armor(&self) -> &InventoryContainer {
+        &self.armor
+    }
+
+    pub fn armor_mut(&mut self) -> &mut InventoryContainer {
+        &mut self.armor
+    }
+
+    pub fn offhand(&self) -> &InventoryContainer {
+        &self.offhand
+    }
+
+    pub fn offhand_mut(&mut self) -> &mut InventoryContainer {
+        &mut self.offhand
+    }
+
+    /// The cursor, crafting grid and other slots of the player's UI.
+    pub fn ui(&self) -> &InventoryContainer {
+        &self.ui
+    }
+
+    pub fn ui_mut(&mut self) -> &mut InventoryContainer {
+        &mut self.ui
+    }
+
+    /// The container with the given id, `None` if it doesn't belong to the player.
+    pub fn container(&self, id: ContainerID) -> Option<&InventoryContainer> {
+        match id {
+            ContainerID::CONTAINER_ID_INVENTORY => Some(&self.main),
+            ContainerID::CONTAINER_ID_ARMOR => Some(&self.armor),
+            ContainerID::CONTAINER_ID_OFFHAND => Some(&self.offhand),
+            ContainerID::CONTAINER_ID_PLAYER_ONLY_UI => Some(&self.ui),
+            _ => None,
+        }
+    }
+
+    pub fn container_mut(&mut self, id: ContainerID) -> Option<&mut InventoryContainer> {
+        match id {
+            ContainerID::CONTAINER_ID_INVENTORY => Some(&mut self.main),
+            ContainerID::CONTAINER_ID_ARMOR => Some(&mut self.armor),
+            ContainerID::CONTAINER_ID_OFFHAND => Some(&mut self.offhand),
+            ContainerID::CONTAINER_ID_PLAYER_ONLY_UI => Some(&mut self.ui),
+            _ => None,
+        }
+    }
+
+    pub fn cursor(&self) -> &NetworkItemStackDescriptor {
+        &self.ui.slots()[CURSOR_SLOT]
+    }
+
+    /// Puts the item on the cursor and returns the previous one.
+    pub fn set_cursor(&mut self, item: NetworkItemStackDescriptor) -> NetworkItemStackDescriptor {
+        self.ui
+            .set(CURSOR_SLOT, item)
+            .expect("The cursor slot is always in the UI container")
+    }
+
+    pub fn selected_slot(&self) -> usize {
+        self.selected_slot
+    }
+
+    /// Selects a hotbar slot, returns `false` if the slot is not part of the hotbar.
+    pub fn select_slot(&mut self, slot: usize) -> bool {
+        if slot >= HOTBAR_SIZE {
+            return false;
+        }
+
+        if self.selected_slot != slot {
+            self.selected_slot = slot;
+            self.selected_slot_dirty = true;
+            self.held_item_dirty = true;
+        }
+
+        true
+    }
+
+    /// Updates the selected slot after the client changed it, unlike [`PlayerInventory::select_slot`]
+    /// this doesn't send the change back to the client.
+    pub fn client_selected_slot(&mut self, slot: usize) -> bool {
+        if slot >= HOTBAR_SIZE {
+            return false;
+        }
+
+        // Viewers still need to see the new held item
+        if self.selected_slot != slot {
+            self.selected_slot = slot;
+            self.held_item_dirty = true;
+        }
+
+        true
+    }
+
+    pub fn held_item(&self) -> &NetworkItemStackDescriptor {
+        &self.main.slots()[self.selected_slot]
+    }
+
+    /// Marks everything as changed, the next sync then sends the whole inventory,
+    /// for example after the player joined.
+    pub fn mark_all_dirty(&mut self) {
+        self.main.mark_all_dirty();
+        self.armor.mark_all_dirty();
+        self.offhand.mark_all_dirty();
+        self.ui.mark_all_dirty();
+        self.selected_slot_dirty = true;
+        self.held_item_dirty = true;
+    }
+
+    /// Collects the packets needed to sync all changes since the last sync.
+    ///
+    /// Viewers get a [`MobEquipmentPacket`] if the held item or the offhand changed
+    /// and a [`MobArmorEquipmentPacket`] if the armor changed.
+    pub fn sync(&mut self, runtime_id: &ActorRuntimeID) -> InventorySync {
+        let mut sync = InventorySync::default();
+
+        let held_item_changed = self.held_item_dirty || self.main.is_slot_dirty(self.selected_slot);
+        self.held_item_dirty = false;
+        let armor_changed = self.armor.is_dirty();
+        let offhand_changed = self.offhand.is_dirty();
+
+        for container in [
+            &mut self.main,
+            &mut self.armor,
+            &mut self.offhand,
+            &mut self.ui,
+        ] {
+            sync.player.extend(container.sync_packets());
+        }
+
+        if self.selected_slot_dirty {
+            sync.player
+                .push(GamePacket::PlayerHotbar(PlayerHotbarPacket {
+                    selected_slot: VAR::new(self.selected_slot as u32),
+                    container_id: ContainerID::CONTAINER_ID_INVENTORY,
+                    should_select_slot: true,
+                }));
+            self.selected_slot_dirty = false;
+        }
+
+        if held_item_changed {
+            sync.viewers
+                .push(GamePacket::MobEquipment(MobEquipmentPacket {
+                    runtime_id: runtime_id.clone(),
+                    item_stack_descriptor: self.held_item().clone(),
+                    slot: self.selected_slot as u8,
+                    selected_slot: self.selected_slot as u8,
+                    container: window_id(ContainerID::CONTAINER_ID_INVENTORY) as u8,
+                }));
+        }
+
+        if offhand_changed {
+            sync.viewers
+                .push(GamePacket::MobEquipment(MobEquipmentPacket {
+                    runtime_id: runtime_id.clone(),
+                    item_stack_descriptor: self.offhand.slots()[0].clone(),
+                    slot: 0,
+                    selected_slot: 0,
+                    container: window_id(ContainerID::CONTAINER_ID_OFFHAND) as u8,
+                }));
+        }
+
+        if armor_changed {
+            let armor = self.armor.slots();
+
+            sync.viewers
+                .push(GamePacket::MobArmorEquipment(MobArmorEquipmentPacket {
+                    runtime_id: runtime_id.clone(),
+                    helmet: armor[0].clone(),
+                    chestplate: armor[1].clone(),
+                    leggings: armor[2].clone(),
+                    boots: armor[3].clone(),
+                }));
+        }
+
+        sync
+    }
+}
+
+impl Default for PlayerInventory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn window_id(id: ContainerID) -> u32 {
+    id as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn equipment_containers(packets: &[GamePacket]) -> Vec<u8> {
+        packets
+            .iter()
+            .filter_map(|packet| match packet {
+                GamePacket::MobEquipment(pk) => Some(pk.container),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn offhand_changes_are_shown_to_viewers() {
+        let mut inventory = PlayerInventory::new();
+        let runtime_id = ActorRuntimeID(1);
+
+        inventory
+            .offhand_mut()
+            .set(0, NetworkItemStackDescriptor::air());
+        let sync = inventory.sync(&runtime_id);
+
+        assert_eq!(equipment_containers(&sync.viewers), [119]);
+
+        // Nothing changed since the last sync
+        assert!(inventory.sync(&runtime_id).viewers.is_empty());
+    }
+
+    #[test]
+    fn everything_is_shown_after_marking_all_dirty() {
+        let mut inventory = PlayerInventory::new();
+
+        inventory.mark_all_dirty();
+        let sync = inventory.sync(&ActorRuntimeID(1));
+
+        assert_eq!(equipment_containers(&sync.viewers), [0, 119]);
+        assert!(sync
+            .viewers
+            .iter()
+            .any(|packet| matches!(packet, GamePacket::MobArmorEquipment(_))));
+    }
+}
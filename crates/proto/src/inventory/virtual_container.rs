@@ -0,0 +1,569 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_nbt as nbt;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+
+use crate::gamepacket::GamePacket;
+use crate::inventory::container::InventoryContainer;
+use crate::packets::block_entity_data_packet::BlockEntityDataPacket;
+use crate::packets::container_close_packet::ContainerClosePacket;
+use crate::packets::container_open_packet::ContainerOpenPacket;
+use crate::packets::inventory_content_packet::InventoryContentPacket;
+use crate::packets::inventory_transaction_packet::InventoryTransactionPacket;
+use crate::packets::update_block_packet::UpdateBlockPacket;
+use crate::types::container_id::ContainerID;
+use crate::types::container_name::ContainerName;
+use crate::types::container_type::ContainerType;
+use crate::types::inventory_transaction::InventorySource;
+use crate::types::item_stack_request::{ItemStackRequest, ItemStackRequestAction};
+use crate::types::item_stack_response::ItemStackResponse;
+use crate::types::network_block_pos::NetworkBlockPos;
+use crate::types::network_item_stack_descriptor::NetworkItemStackDescriptor;
+
+/// The window id used for virtual containers, a player only ever has one open.
+const WINDOW_ID: ContainerID = ContainerID::CONTAINER_ID_FIRST;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum VirtualContainerKind {
+    Chest,
+    /// Two chests next to each other, the second one is placed at `x + 1`
+    DoubleChest,
+    Hopper,
+    Dispenser,
+}
+
+impl VirtualContainerKind {
+    pub fn size(&self) -> usize {
+        match self {
+            VirtualContainerKind::Chest => 27,
+            VirtualContainerKind::DoubleChest => 54,
+            VirtualContainerKind::Hopper => 5,
+            VirtualContainerKind::Dispenser => 9,
+        }
+    }
+
+    pub fn container_type(&self) -> ContainerType {
+        match self {
+            VirtualContainerKind::Chest | VirtualContainerKind::DoubleChest => {
+                ContainerType::CONTAINER
+            }
+            VirtualContainerKind::Hopper => ContainerType::HOPPER,
+            VirtualContainerKind::Dispenser => ContainerType::DISPENSER,
+        }
+    }
+
+    fn block_entity_id(&self) -> &'static str {
+        match self {
+            VirtualContainerKind::Chest | VirtualContainerKind::DoubleChest => "Chest",
+            VirtualContainerKind::Hopper => "Hopper",
+            VirtualContainerKind::Dispenser => "Dispenser",
+        }
+    }
+}
+
+/// A slot of a [`VirtualContainer`] clicked by the player.
+#[derive(Debug, Clone)]
+pub struct VirtualContainerClick {
+    pub slot: usize,
+    /// The item in the clicked slot
+    pub item: NetworkItemStackDescriptor,
+}
+
+type ClickHandler = Box<dyn FnMut(VirtualContainerClick) + Send + Sync>;
+
+/// A container UI backed by a fake block that only exists on the client, used for menus.
+///
+/// The fake block is placed with [`VirtualContainer::place_packets`], the client only opens
+/// containers of blocks it knows, so [`VirtualContainer::open_packets`] should be sent a tick later.
+/// The position should be close to the player but out of sight, for example a few blocks below them.
+///
+/// Items can't be taken out of a virtual container, every click is reported to the click
+/// handler and then reverted.
+///
+/// ```ignore
+/// let mut menu = VirtualContainer::new(VirtualContainerKind::Chest, position, chest_runtime_id)
+///     .title("Teleport")
+///     .replaced_blocks(vec![air_runtime_id])
+///     .on_click(|click| println!("Clicked slot {}", click.slot));
+///
+/// menu.contents_mut().set(13, compass);
+/// ```
+pub struct VirtualContainer {
+    kind: VirtualContainerKind,
+    title: Option<String>,
+    position: NetworkBlockPos,
+    block_runtime_id: u32,
+    replaced_block_runtime_ids: Vec<u32>,
+    contents: InventoryContainer,
+    on_click: Option<ClickHandler>,
+}
+
+impl VirtualContainer {
+    pub fn new(
+        kind: VirtualContainerKind,
+        position: NetworkBlockPos,
+        block_runtime_id: u32,
+    ) -> Self {
+        Self {
+            kind,
+            title: None,
+            position,
+            block_runtime_id,
+            replaced_block_runtime_ids: vec![],
+            contents: InventoryContainer::new(WINDOW_ID as u32, kind.size()),
+            on_click: None,
+        }
+    }
+
+    /// The name shown at the top of the container.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// The runtime ids of the blocks the fake blocks replace, they get restored on close.
+    /// A double chest replaces two blocks.
+    pub fn replaced_blocks(mut self, block_runtime_ids: Vec<u32>) -> Self {
+        self.replaced_block_runtime_ids = block_runtime_ids;
+        self
+    }
+
+    /// Called for every slot the player clicks.
+    pub fn on_click(
+        mut self,
+        handler: impl FnMut(VirtualContainerClick) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_click = Some(Box::new(handler));
+        self
+    }
+
+    pub fn kind(&self) -> VirtualContainerKind {
+        self.kind
+    }
+
+    pub fn contents(&self) -> &InventoryContainer {
+        &self.contents
+    }
+
+    /// Changes made while the container is open are sent with [`VirtualContainer::sync_packets`].
+    pub fn contents_mut(&mut self) -> &mut InventoryContainer {
+        &mut self.contents
+    }
+
+    /// Places the fake blocks and their block entities.
+    pub fn place_packets(&self) -> Vec<GamePacket> {
+        let positions = self.block_positions();
+        let mut packets = vec![];
+
+        for (index, position) in positions.iter().enumerate() {
+            packets.push(GamePacket::UpdateBlock(UpdateBlockPacket::new(
+                position.clone(),
+                self.block_runtime_id,
+            )));
+
+            let mut data = HashMap::from([
+                (
+                    String::from("id"),
+                    nbt::Value::String(String::from(self.kind.block_entity_id())),
+                ),
+                (String::from("x"), nbt::Value::Int(position.x.into_inner())),
+                (
+                    String::from("y"),
+                    nbt::Value::Int(position.y.into_inner() as i32),
+                ),
+                (String::from("z"), nbt::Value::Int(position.z.into_inner())),
+            ]);
+
+            if let Some(ref title) = self.title {
+                data.insert(
+                    String::from("CustomName"),
+                    nbt::Value::String(title.clone()),
+                );
+            }
+
+            // Both halves of a double chest point to each other, the first one leads
+            if positions.len() == 2 {
+                let pair = &positions[1 - index];
+                data.insert(String::from("pairx"), nbt::Value::Int(pair.x.into_inner()));
+                data.insert(String::from("pairz"), nbt::Value::Int(pair.z.into_inner()));
+                data.insert(
+                    String::from("pairlead"),
+                    nbt::Value::Byte((index == 0) as i8),
+                );
+            }
+
+            packets.push(GamePacket::BlockEntityData(BlockEntityDataPacket {
+                position: position.clone(),
+                data,
+            }));
+        }
+
+        packets
+    }
+
+    /// Opens the container and sends its contents.
+    pub fn open_packets(&mut self) -> Vec<GamePacket> {
+        // The contents are sent as a whole
+        let _ = self.contents.sync_packets();
+
+        vec![
+            GamePacket::ContainerOpen(ContainerOpenPacket {
+                container_id: WINDOW_ID,
+                container_type: self.kind.container_type(),
+                position: self.position.clone(),
+                target_actor_id: ActorUniqueID(-1),
+            }),
+            GamePacket::InventoryContent(self.content_packet()),
+        ]
+    }
+
+    /// The packets for all changes of the contents since the last sync.
+    pub fn sync_packets(&mut self) -> Vec<GamePacket> {
+        self.contents.sync_packets()
+    }
+
+    /// Closes the container from the server side and restores the replaced blocks.
+    pub fn close_packets(&self) -> Vec<GamePacket> {
+        let mut packets = vec![GamePacket::ContainerClose(ContainerClosePacket {
+            container_id: WINDOW_ID as u8,
+            container_type: self.kind.container_type(),
+            server_initiated_close: true,
+        })];
+
+        packets.extend(self.restore_packets());
+        packets
+    }
+
+    /// Handles the player closing the container, returns the packets confirming the
+    /// close and restoring the replaced blocks, `None` if the packet is for another container.
+    pub fn handle_close(&self, packet: &ContainerClosePacket) -> Option<Vec<GamePacket>> {
+        if packet.container_id != WINDOW_ID as u8 {
+            return None;
+        }
+
+        let mut packets = vec![GamePacket::ContainerClose(ContainerClosePacket {
+            container_id: WINDOW_ID as u8,
+            container_type: self.kind.container_type(),
+            server_initiated_close: false,
+        })];
+
+        packets.extend(self.restore_packets());
+        Some(packets)
+    }
+
+    /// Reports the clicked slots of a request to the click handler and rejects it,
+    /// `None` if the request doesn't touch the container.
+    ///
+    /// Used with server authoritative inventories.
+    pub fn handle_item_stack_request(
+        &mut self,
+        request: &ItemStackRequest,
+    ) -> Option<ItemStackResponse> {
+        let mut slots = vec![];
+
+        for action in &request.actions {
+            let (source, destination) = match action {
+                ItemStackRequestAction::Take {
+                    source,
+                    destination,
+                    ..
+                }
+                | ItemStackRequestAction::Place {
+                    source,
+                    destination,
+                    ..
+                }
+                | ItemStackRequestAction::Swap {
+                    source,
+                    destination,
+                }
+                | ItemStackRequestAction::PlaceInContainer {
+                    source,
+                    destination,
+                    ..
+                }
+                | ItemStackRequestAction::TakeOutContainer {
+                    source,
+                    destination,
+                    ..
+                } => (Some(source), Some(destination)),
+                ItemStackRequestAction::Drop { source, .. }
+                | ItemStackRequestAction::Destroy { source, .. }
+                | ItemStackRequestAction::Consume { source, .. } => (Some(source), None),
+                _ => (None, None),
+            };
+
+            for slot_info in [source, destination].into_iter().flatten() {
+                if slot_info.container == ContainerName::LevelEntity {
+                    slots.push(slot_info.slot as usize);
+                }
+            }
+        }
+
+        if slots.is_empty() {
+            return None;
+        }
+
+        // A slot can be both the source and the destination of different actions
+        slots.sort_unstable();
+        slots.dedup();
+        for slot in slots {
+            self.click(slot);
+        }
+
+        Some(ItemStackResponse::error(request.request_id.into_inner()))
+    }
+
+    /// Reports the clicked slots of a transaction to the click handler and returns the
+    /// packets reverting it, `None` if the transaction doesn't touch the container.
+    ///
+    /// Used with client authoritative inventories, the player's own inventory
+    /// has to be resent as well to revert the item on the cursor.
+    pub fn handle_inventory_transaction(
+        &mut self,
+        packet: &InventoryTransactionPacket,
+    ) -> Option<Vec<GamePacket>> {
+        let mut slots = vec![];
+
+        for action in &packet.actions {
+            if let InventorySource::Container { container_id } = action.source {
+                if container_id.into_inner() == WINDOW_ID as i32 {
+                    slots.push(action.slot.into_inner() as usize);
+                }
+            }
+        }
+
+        if slots.is_empty() {
+            return None;
+        }
+
+        // A slot can be both the source and the destination of different actions
+        slots.sort_unstable();
+        slots.dedup();
+        for slot in slots {
+            self.click(slot);
+        }
+
+        Some(vec![GamePacket::InventoryContent(self.content_packet())])
+    }
+
+    fn click(&mut self, slot: usize) {
+        let Some(item) = self.contents.get(slot).cloned() else {
+            return;
+        };
+
+        if let Some(ref mut on_click) = self.on_click {
+            on_click(VirtualContainerClick { slot, item });
+        }
+    }
+
+    fn content_packet(&self) -> InventoryContentPacket {
+        InventoryContentPacket {
+            inventory_id: VAR::new(WINDOW_ID as u32),
+            slots: self.contents.slots().to_vec(),
+        }
+    }
+
+    fn block_positions(&self) -> Vec<NetworkBlockPos> {
+        let mut positions = vec![self.position.clone()];
+
+        if self.kind == VirtualContainerKind::DoubleChest {
+            positions.push(NetworkBlockPos {
+                x: VAR::new(self.position.x.into_inner() + 1),
+                y: self.position.y,
+                z: self.position.z,
+            });
+        }
+
+        positions
+    }
+
+    fn restore_packets(&self) -> Vec<GamePacket> {
+        self.block_positions()
+            .into_iter()
+            .zip(&self.replaced_block_runtime_ids)
+            .map(|(position, block_runtime_id)| {
+                GamePacket::UpdateBlock(UpdateBlockPacket::new(position, *block_runtime_id))
+            })
+            .collect()
+    }
+}
+
+impl Debug for VirtualContainer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VirtualContainer")
+            .field("kind", &self.kind)
+            .field("title", &self.title)
+            .field("position", &self.position)
+            .field("block_runtime_id", &self.block_runtime_id)
+            .field(
+                "replaced_block_runtime_ids",
+                &self.replaced_block_runtime_ids,
+            )
+            .field("contents", &self.contents)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::types::inventory_transaction::{InventoryAction, InventoryTransaction};
+
+    use super::*;
+
+    const CHEST: u32 = 10;
+    const AIR: u32 = 1;
+
+    fn double_chest() -> VirtualContainer {
+        VirtualContainer::new(
+            VirtualContainerKind::DoubleChest,
+            NetworkBlockPos::new(4, -60, 8),
+            CHEST,
+        )
+        .title("Menu")
+        .replaced_blocks(vec![AIR, AIR])
+    }
+
+    fn action(container_id: i32, slot: u32) -> InventoryAction {
+        InventoryAction {
+            source: InventorySource::Container {
+                container_id: VAR::new(container_id),
+            },
+            slot: VAR::new(slot),
+            from_item: NetworkItemStackDescriptor::air(),
+            to_item: NetworkItemStackDescriptor::air(),
+        }
+    }
+
+    #[test]
+    fn placing_a_double_chest_pairs_both_halves() {
+        let packets = double_chest().place_packets();
+        assert_eq!(packets.len(), 4);
+
+        let mut pairs = vec![];
+        for (index, x) in [(0, 4), (2, 5)] {
+            let GamePacket::UpdateBlock(ref block) = packets[index] else {
+                panic!("expected a block update, got {:?}", packets[index]);
+            };
+            assert_eq!(block.position.x.into_inner(), x);
+            assert_eq!(block.block_runtime_id.into_inner(), CHEST);
+
+            let GamePacket::BlockEntityData(ref entity) = packets[index + 1] else {
+                panic!("expected block entity data, got {:?}", packets[index + 1]);
+            };
+            assert!(
+                matches!(&entity.data["CustomName"], nbt::Value::String(name) if name == "Menu")
+            );
+            assert!(matches!(entity.data["y"], nbt::Value::Int(-60)));
+            pairs.push((
+                entity.data["pairx"].clone(),
+                entity.data["pairlead"].clone(),
+            ));
+        }
+
+        assert!(matches!(
+            pairs[0],
+            (nbt::Value::Int(5), nbt::Value::Byte(1))
+        ));
+        assert!(matches!(
+            pairs[1],
+            (nbt::Value::Int(4), nbt::Value::Byte(0))
+        ));
+    }
+
+    #[test]
+    fn opening_sends_the_whole_contents() {
+        let mut container = double_chest();
+        container
+            .contents_mut()
+            .set(3, NetworkItemStackDescriptor::air());
+
+        let packets = container.open_packets();
+        assert_eq!(packets.len(), 2);
+        let GamePacket::ContainerOpen(ref open) = packets[0] else {
+            panic!("expected a container open, got {:?}", packets[0]);
+        };
+        assert_eq!(open.container_id, WINDOW_ID);
+        assert_eq!(open.container_type, ContainerType::CONTAINER);
+        let GamePacket::InventoryContent(ref content) = packets[1] else {
+            panic!("expected the contents, got {:?}", packets[1]);
+        };
+        assert_eq!(content.slots.len(), 54);
+
+        // The contents were sent with the open packets already
+        assert!(container.sync_packets().is_empty());
+    }
+
+    #[test]
+    fn closing_restores_the_replaced_blocks() {
+        let container = double_chest();
+
+        for (packets, server_initiated) in [
+            (container.close_packets(), true),
+            (
+                container
+                    .handle_close(&ContainerClosePacket {
+                        container_id: WINDOW_ID as u8,
+                        container_type: ContainerType::CONTAINER,
+                        server_initiated_close: false,
+                    })
+                    .unwrap(),
+                false,
+            ),
+        ] {
+            assert_eq!(packets.len(), 3);
+            assert!(matches!(
+                packets[0],
+                GamePacket::ContainerClose(ref close)
+                    if close.server_initiated_close == server_initiated
+            ));
+            for (packet, x) in packets[1..].iter().zip([4, 5]) {
+                let GamePacket::UpdateBlock(block) = packet else {
+                    panic!("expected a block update, got {packet:?}");
+                };
+                assert_eq!(block.position.x.into_inner(), x);
+                assert_eq!(block.block_runtime_id.into_inner(), AIR);
+            }
+        }
+    }
+
+    #[test]
+    fn closing_another_container_is_ignored() {
+        let packet = ContainerClosePacket {
+            container_id: 0,
+            container_type: ContainerType::INVENTORY,
+            server_initiated_close: false,
+        };
+
+        assert!(double_chest().handle_close(&packet).is_none());
+    }
+
+    #[test]
+    fn every_clicked_slot_is_reported_once() {
+        let clicked = Arc::new(Mutex::new(vec![]));
+        let handler_clicked = clicked.clone();
+        let mut container = double_chest().on_click(move |click| {
+            handler_clicked.lock().unwrap().push(click.slot);
+        });
+
+        let packet = InventoryTransactionPacket {
+            legacy_request_id: VAR::new(0),
+            legacy_set_item_slots: vec![],
+            actions: vec![
+                action(WINDOW_ID as i32, 3),
+                action(0, 9),
+                action(WINDOW_ID as i32, 5),
+                action(WINDOW_ID as i32, 3),
+            ],
+            transaction: InventoryTransaction::Normal,
+        };
+
+        let packets = container.handle_inventory_transaction(&packet).unwrap();
+        assert!(matches!(packets[..], [GamePacket::InventoryContent(_)]));
+        assert_eq!(*clicked.lock().unwrap(), vec![3, 5]);
+    }
+}
@@ -1,13 +1,22 @@
 extern crate core;
 
+pub mod blob_cache;
+pub mod block_update;
+pub mod boss_bar;
+pub mod command;
 pub mod compression;
 pub mod connection;
 pub mod encryption;
 pub mod error;
 pub mod gamepacket;
 pub mod info;
+pub mod inventory;
 pub mod listener;
 pub mod login;
 pub mod packets;
+pub mod scoreboard;
+pub mod sub_chunk;
 pub mod transport_layer;
 pub mod types;
+#[cfg(feature = "world")]
+pub mod world;
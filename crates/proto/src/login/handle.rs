@@ -1,30 +1,87 @@
 use crate::connection::{Connection, ConnectionShard};
 use crate::error::LoginError;
+use crate::gamepacket::GamePacket;
 use crate::login::handshake::handshake;
 use crate::login::login::login;
 use crate::login::network_settings::network_settings;
 use crate::login::packs::packs;
 use crate::login::play_status::play_status_login;
 use crate::login::provider::{LoginProviderClient, LoginProviderServer};
+use crate::login::spawn::spawn;
 use crate::login::start_game::start_game;
+use crate::packets::disconnect::DisconnectPacket;
+use crate::packets::start_game::StartGamePacket;
 
-use super::set_title::set_title;
+/// The outcome of a successful [`login_to_server`].
+#[derive(Debug)]
+pub struct LoginResult<D> {
+    /// The data the provider stashed in its hooks during the login.
+    pub session_data: D,
+    /// The [`StartGamePacket`] that was sent to the client, it holds the player's
+    /// runtime/unique IDs, position and the level settings.
+    pub start_game: StartGamePacket,
+    /// The chunk radius negotiated with the client, `None` if the provider has
+    /// [spawning](LoginProviderServer::spawn_enabled) disabled.
+    pub chunk_radius: Option<u32>,
+}
 
-pub async fn login_to_server(
+pub async fn login_to_server<P: LoginProviderServer>(
     conn: &mut ConnectionShard,
-    mut provider: impl LoginProviderServer,
-) -> Result<(), LoginError> {
-    network_settings(conn, &mut provider).await?;
+    mut provider: P,
+) -> Result<LoginResult<P::SessionData>, LoginError> {
+    let mut session_data = P::SessionData::default();
+
+    match login_stages(conn, &mut provider, &mut session_data).await {
+        Ok((start_game, chunk_radius)) => Ok(LoginResult {
+            session_data,
+            start_game,
+            chunk_radius,
+        }),
+        Err(LoginError::Abort {
+            reason,
+            disconnect_reason,
+        }) => {
+            // Let the client know why the login was aborted, if the client is already
+            // gone it doesn't matter, the abort is still what the caller needs to see
+            if conn
+                .send(GamePacket::Disconnect(DisconnectPacket {
+                    reason: disconnect_reason.clone(),
+                    message: Some(reason.clone()),
+                }))
+                .await
+                .is_ok()
+            {
+                let _ = conn.flush().await;
+            }
+
+            Err(LoginError::Abort {
+                reason,
+                disconnect_reason,
+            })
+        }
+        Err(e) => Err(e),
+    }
+}
+
+async fn login_stages<P: LoginProviderServer>(
+    conn: &mut ConnectionShard,
+    provider: &mut P,
+    session_data: &mut P::SessionData,
+) -> Result<(StartGamePacket, Option<u32>), LoginError> {
+    network_settings(conn, provider, session_data).await?;
+
+    login(conn, provider, session_data).await?;
+    play_status_login(conn, provider, session_data).await?;
+
+    handshake(conn, provider).await?;
 
-    login(conn, &mut provider).await?;
-    play_status_login(conn, &mut provider).await?;
+    packs(conn, provider, session_data).await?;
 
-    handshake(conn, &mut provider).await?;
+    let start_game = start_game(conn, provider, session_data).await?;
 
-    packs(conn, &mut provider).await?;
+    let chunk_radius = spawn(conn, provider, session_data, &start_game).await?;
 
-    start_game(conn, &mut provider).await?;
-    Ok(())
+    Ok((start_game, chunk_radius))
 }
 
 pub async fn login_to_client(
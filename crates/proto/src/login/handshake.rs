@@ -2,9 +2,9 @@ use crate::connection::ConnectionShard;
 use crate::error::LoginError;
 use crate::login::provider::LoginProviderServer;
 
-pub async fn handshake(
+pub async fn handshake<P: LoginProviderServer>(
     conn: &mut ConnectionShard,
-    provider: &mut impl LoginProviderServer,
+    provider: &mut P,
 ) -> Result<(), LoginError> {
     if !provider.encryption_enabled() {
         return Ok(());
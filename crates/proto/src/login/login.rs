@@ -1,11 +1,12 @@
 use crate::connection::ConnectionShard;
 use crate::error::LoginError;
 use crate::gamepacket::GamePacket;
-use crate::login::provider::{LoginProviderServer, LoginProviderStatus};
+use crate::login::provider::LoginProviderServer;
 
-pub async fn login(
+pub async fn login<P: LoginProviderServer>(
     conn: &mut ConnectionShard,
-    provider: &mut impl LoginProviderServer,
+    provider: &mut P,
+    session: &mut P::SessionData,
 ) -> Result<(), LoginError> {
     //////////////////////////////////////
     // Login Packet
@@ -21,12 +22,10 @@ pub async fn login(
         Err(e) => return Err(LoginError::ConnectionError(e)),
     };
 
-    match provider.on_login_pk(&mut login) {
-        LoginProviderStatus::ContinueLogin => {}
-        LoginProviderStatus::AbortLogin { reason } => {
-            return Err(LoginError::Abort { reason });
-        }
-    };
+    provider
+        .on_login_pk(&mut login, session)
+        .await
+        .into_result()?;
 
     if provider.auth_enabled() {
         todo!("impl xbox auth with data from login pk")
@@ -1,6 +1,5 @@
 pub use handle::*;
 
-mod add_actor;
 pub mod handle;
 mod handshake;
 mod login;
@@ -8,5 +7,5 @@ mod network_settings;
 mod packs;
 mod play_status;
 pub mod provider;
-mod set_title;
+mod spawn;
 mod start_game;
@@ -3,12 +3,13 @@ use bedrockrs_core::int::LE;
 use crate::connection::ConnectionShard;
 use crate::error::LoginError;
 use crate::gamepacket::GamePacket;
-use crate::login::provider::{LoginProviderServer, LoginProviderStatus};
+use crate::login::provider::LoginProviderServer;
 use crate::packets::network_settings::NetworkSettingsPacket;
 
-pub async fn network_settings(
+pub async fn network_settings<P: LoginProviderServer>(
     conn: &mut ConnectionShard,
-    provider: &mut impl LoginProviderServer,
+    provider: &mut P,
+    session: &mut P::SessionData,
 ) -> Result<(), LoginError> {
     //////////////////////////////////////
     // Network Settings Request Packet
@@ -24,12 +25,10 @@ pub async fn network_settings(
         Err(e) => return Err(LoginError::ConnectionError(e)),
     };
 
-    match provider.on_network_settings_request_pk(&mut network_settings_request) {
-        LoginProviderStatus::ContinueLogin => {}
-        LoginProviderStatus::AbortLogin { reason } => {
-            return Err(LoginError::Abort { reason });
-        }
-    };
+    provider
+        .on_network_settings_request_pk(&mut network_settings_request, session)
+        .await
+        .into_result()?;
 
     //////////////////////////////////////
     // Network Settings Packet
@@ -46,12 +45,10 @@ pub async fn network_settings(
         client_throttle_scalar: LE::new(0.0),
     };
 
-    match provider.on_network_settings_pk(&mut network_settings) {
-        LoginProviderStatus::ContinueLogin => {}
-        LoginProviderStatus::AbortLogin { reason } => {
-            return Err(LoginError::Abort { reason });
-        }
-    };
+    provider
+        .on_network_settings_pk(&mut network_settings, session)
+        .await
+        .into_result()?;
 
     match conn
         .send(GamePacket::NetworkSettings(network_settings))
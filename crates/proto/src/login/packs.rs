@@ -1,172 +1,257 @@
+use bedrockrs_core::int::LE;
+use uuid::Uuid;
+
 use crate::connection::ConnectionShard;
 use crate::error::LoginError;
 use crate::gamepacket::GamePacket;
-use crate::login::provider::packs::LoginProviderPacks;
-use crate::login::provider::{LoginProviderServer, LoginProviderStatus};
+use crate::info::MINECRAFT_VERSION;
+use crate::login::provider::packs::PackArchive;
+use crate::login::provider::LoginProviderServer;
+use crate::packets::resource_pack_chunk_data::ResourcePackChunkDataPacket;
+use crate::packets::resource_pack_data_info::ResourcePackDataInfoPacket;
 use crate::packets::resource_packs_info::ResourcePacksInfoPacket;
 use crate::packets::resource_packs_stack::ResourcePacksStackPacket;
 use crate::types::base_game_version::BaseGameVersion;
 use crate::types::experiments::Experiments;
+use crate::types::pack_info_behavior::BehaviorPackInfoType;
+use crate::types::pack_info_resource::ResourcePackInfoType;
+use crate::types::resource_pack_type::ResourcePackType;
+use crate::types::resource_packs_response_status::ResourcePacksResponseStatus;
+use crate::types::resource_packs_stack_pack::ResourcePacksStackPack;
 
-pub async fn packs(
+pub async fn packs<P: LoginProviderServer>(
     conn: &mut ConnectionShard,
-    provider: &mut impl LoginProviderServer,
+    provider: &mut P,
+    session: &mut P::SessionData,
 ) -> Result<(), LoginError> {
-    match provider.packs() {
-        LoginProviderPacks::CDN {
-            behavior_packs,
-            resource_packs,
-            cdn_urls,
-        } => {
-            //////////////////////////////////////
-            // Resource Packs Info Packet
-            //////////////////////////////////////
-
-            // TODO impl this
-            let mut resource_packs_info = ResourcePacksInfoPacket {
-                resource_pack_required: false,
-                has_addon_packs: false,
-                has_scripts: false,
-                force_server_packs_enabled: false,
-                behavior_packs: vec![],
-                resource_packs: vec![],
-                cdn_urls: cdn_urls.clone(),
-            };
-
-            match provider.on_resource_packs_info_pk(&mut resource_packs_info) {
-                LoginProviderStatus::ContinueLogin => {}
-                LoginProviderStatus::AbortLogin { reason } => {
-                    return Err(LoginError::Abort { reason });
-                }
-            };
+    let packs = provider.packs();
 
-            match conn
-                .send(GamePacket::ResourcePacksInfo(resource_packs_info))
-                .await
-            {
-                Ok(_) => {}
-                Err(e) => return Err(LoginError::ConnectionError(e)),
-            };
-
-            match conn.flush().await {
-                Ok(_) => {}
-                Err(e) => return Err(LoginError::ConnectionError(e)),
-            };
-
-            //////////////////////////////////////
-            // Resource Pack Client Response
-            // (/Client Cache Status Packet)
-            //////////////////////////////////////
-
-            match conn.recv().await {
-                Ok(GamePacket::ClientCacheStatus(mut client_cache_status)) => {
-                    match provider.on_client_cache_status_pk(&mut client_cache_status) {
-                        LoginProviderStatus::ContinueLogin => {}
-                        LoginProviderStatus::AbortLogin { reason } => {
-                            return Err(LoginError::Abort { reason });
-                        }
-                    };
+    // The packs were zipped when the pack list was built, the info packet
+    // needs the sizes of the zip files even if the client uses a CDN
+    let archives = packs.archives().clone();
 
-                    if let Err(e) = conn.set_cache_supported(client_cache_status.cache_supported).await {
-                        return Err(LoginError::ConnectionError(e));
-                    }
+    let cdn_urls = packs.cdn_urls().to_vec();
+
+    //////////////////////////////////////
+    // Resource Packs Info Packet
+    //////////////////////////////////////
+
+    let behavior_packs = packs
+        .behavior_packs()
+        .iter()
+        .map(|pack| {
+            let archive = pack_archive(
+                &archives,
+                &pack.manifest.header.uuid,
+                ResourcePackType::Behavior,
+            )?;
+            Ok(BehaviorPackInfoType::from_pack(
+                pack,
+                archive.data.len() as u64,
+            ))
+        })
+        .collect::<Result<Vec<_>, LoginError>>()?;
+    let resource_packs = packs
+        .resource_packs()
+        .iter()
+        .map(|pack| {
+            let archive = pack_archive(
+                &archives,
+                &pack.manifest.header.uuid,
+                ResourcePackType::Resources,
+            )?;
+            Ok(ResourcePackInfoType::from_pack(
+                pack,
+                archive.data.len() as u64,
+            ))
+        })
+        .collect::<Result<Vec<_>, LoginError>>()?;
+
+    let mut resource_packs_info = ResourcePacksInfoPacket {
+        // Without its behavior packs the client can't play on the server
+        resource_pack_required: !behavior_packs.is_empty(),
+        has_addon_packs: !behavior_packs.is_empty(),
+        has_scripts: behavior_packs.iter().any(|pack| pack.has_scripts)
+            || resource_packs.iter().any(|pack| pack.has_scripts),
+        force_server_packs_enabled: false,
+        behavior_packs,
+        resource_packs,
+        cdn_urls,
+    };
+
+    provider
+        .on_resource_packs_info_pk(&mut resource_packs_info, session)
+        .await
+        .into_result()?;
+
+    conn.send(GamePacket::ResourcePacksInfo(resource_packs_info))
+        .await
+        .map_err(LoginError::ConnectionError)?;
+    conn.flush().await.map_err(LoginError::ConnectionError)?;
+
+    //////////////////////////////////////
+    // Resource Pack Client Response
+    // (/Client Cache Status Packet)
+    // (/Resource Pack Chunk Request Packet)
+    //////////////////////////////////////
 
-                    match conn.recv().await {
-                        Ok(GamePacket::ResourcePackClientResponse(mut resource_pack_client_response)) => {
-                            match provider.on_resource_packs_response_pk(&mut resource_pack_client_response) {
-                                LoginProviderStatus::ContinueLogin => {}
-                                LoginProviderStatus::AbortLogin { reason } => {
-                                    return Err(LoginError::Abort { reason });
-                                }
+    loop {
+        match conn.recv().await {
+            Ok(GamePacket::ClientCacheStatus(mut client_cache_status)) => {
+                provider
+                    .on_client_cache_status_pk(&mut client_cache_status, session)
+                    .await
+                    .into_result()?;
+
+                conn.set_cache_supported(client_cache_status.cache_supported)
+                    .await
+                    .map_err(LoginError::ConnectionError)?;
+            }
+            Ok(GamePacket::ResourcePackClientResponse(mut resource_pack_client_response)) => {
+                provider
+                    .on_resource_packs_response_pk(&mut resource_pack_client_response, session)
+                    .await
+                    .into_result()?;
+
+                match resource_pack_client_response.response {
+                    ResourcePacksResponseStatus::SendPacks => {
+                        for pack_id in &resource_pack_client_response.downloading_packs {
+                            let archive = find_archive(&archives, pack_id)?;
+
+                            let mut resource_pack_data_info = ResourcePackDataInfoPacket {
+                                pack_id: archive.pack_id(),
+                                max_chunk_size: LE::new(PackArchive::CHUNK_SIZE),
+                                chunk_count: LE::new(archive.chunk_count()),
+                                file_size: LE::new(archive.data.len() as u64),
+                                file_hash: archive.hash.clone(),
+                                is_premium: false,
+                                pack_type: archive.pack_type,
                             };
+
+                            provider
+                                .on_resource_pack_data_info_pk(&mut resource_pack_data_info, session)
+                                .await
+                                .into_result()?;
+
+                            conn.send(GamePacket::ResourcePackDataInfo(resource_pack_data_info))
+                                .await
+                                .map_err(LoginError::ConnectionError)?;
                         }
-                        Ok(other) => {
-                            return Err(LoginError::FormatError(format!(
-                                "Expected ClientCacheStatus or ResourcePackClientResponse packet, got: {other:?}"
-                            )))
-                        }
-                        Err(e) => { return Err(LoginError::ConnectionError(e)) }
+
+                        conn.flush().await.map_err(LoginError::ConnectionError)?;
                     }
+                    // A refusing client gets the stack as well, it
+                    // disconnects on its own if the packs are required
+                    ResourcePacksResponseStatus::None
+                    | ResourcePacksResponseStatus::Refused
+                    | ResourcePacksResponseStatus::HaveAllPacks => {
+                        resource_packs_stack(conn, provider, session).await?;
+                    }
+                    ResourcePacksResponseStatus::Completed => break,
                 }
-                Ok(GamePacket::ResourcePackClientResponse(mut resource_pack_client_response)) => {
-                    match provider.on_resource_packs_response_pk(&mut resource_pack_client_response) {
-                        LoginProviderStatus::ContinueLogin => {}
-                        LoginProviderStatus::AbortLogin { reason } => {
-                            return Err(LoginError::Abort { reason });
-                        }
-                    };
-                }
-                Ok(other) => {
-                    return Err(LoginError::FormatError(format!(
-                        "Expected ClientCacheStatus or ResourcePackClientResponse packet, got: {other:?}"
-                    )))
-                }
-                Err(e) => { return Err(LoginError::ConnectionError(e)) }
             }
+            Ok(GamePacket::ResourcePackChunkRequest(mut resource_pack_chunk_request)) => {
+                provider
+                    .on_resource_pack_chunk_request_pk(&mut resource_pack_chunk_request, session)
+                    .await
+                    .into_result()?;
 
-            //////////////////////////////////////
-            // Resource Packs Stack Packet
-            //////////////////////////////////////
-
-            // TODO impl this
-            let mut resource_packs_stack = ResourcePacksStackPacket {
-                texture_pack_required: false,
-                addons: vec![],
-                texture_packs: vec![],
-                base_game_version: BaseGameVersion(String::from("1.0")),
-                experiments: Experiments {
-                    experiments: vec![],
-                    ever_toggled: false,
-                },
-                include_editor_packs: false,
-            };
-
-            match provider.on_resource_packs_stack_pk(&mut resource_packs_stack) {
-                LoginProviderStatus::ContinueLogin => {}
-                LoginProviderStatus::AbortLogin { reason } => {
-                    return Err(LoginError::Abort { reason });
-                }
-            };
+                let archive = find_archive(&archives, &resource_pack_chunk_request.pack_id)?;
+                let chunk_index = resource_pack_chunk_request.chunk_index.into_inner();
+
+                let (progress, data) = archive.chunk(chunk_index).ok_or_else(|| {
+                    LoginError::FormatError(format!(
+                        "Requested chunk {chunk_index} of pack {}, which only has {} chunks",
+                        archive.pack_id(),
+                        archive.chunk_count()
+                    ))
+                })?;
 
-            match conn
-                .send(GamePacket::ResourcePackStack(resource_packs_stack))
+                conn.send(GamePacket::ResourcePackChunkData(ResourcePackChunkDataPacket {
+                    pack_id: archive.pack_id(),
+                    chunk_index: LE::new(chunk_index),
+                    progress: LE::new(progress),
+                    data: data.to_vec(),
+                }))
                 .await
-            {
-                Ok(_) => {}
-                Err(e) => return Err(LoginError::ConnectionError(e)),
-            };
-
-            match conn.flush().await {
-                Ok(_) => {}
-                Err(e) => return Err(LoginError::ConnectionError(e)),
-            };
-
-            //////////////////////////////////////
-            // Resource Pack Client Response
-            //////////////////////////////////////
-
-            match conn.recv().await {
-                Ok(GamePacket::ResourcePackClientResponse(mut resource_pack_client_response)) => {
-                    match provider.on_resource_packs_response_pk(&mut resource_pack_client_response)
-                    {
-                        LoginProviderStatus::ContinueLogin => {}
-                        LoginProviderStatus::AbortLogin { reason } => {
-                            return Err(LoginError::Abort { reason });
-                        }
-                    };
-                }
-                Ok(other) => {
-                    return Err(LoginError::FormatError(format!(
-                        "Expected ResourcePackClientResponse packet, got: {other:?}"
-                    )))
-                }
-                Err(e) => return Err(LoginError::ConnectionError(e)),
+                .map_err(LoginError::ConnectionError)?;
+                conn.flush().await.map_err(LoginError::ConnectionError)?;
             }
+            Ok(other) => {
+                return Err(LoginError::FormatError(format!(
+                    "Expected ClientCacheStatus, ResourcePackClientResponse or ResourcePackChunkRequest packet, got: {other:?}"
+                )))
+            }
+            Err(e) => return Err(LoginError::ConnectionError(e)),
         }
-        LoginProviderPacks::DirectNetworkTransfer { .. } => {
-            todo!("impl LoginProviderbedrockrs_addon::DirectNetworkTransfer in login process")
-        }
+    }
+
+    Ok(())
+}
+
+async fn resource_packs_stack<P: LoginProviderServer>(
+    conn: &mut ConnectionShard,
+    provider: &mut P,
+    session: &mut P::SessionData,
+) -> Result<(), LoginError> {
+    //////////////////////////////////////
+    // Resource Packs Stack Packet
+    //////////////////////////////////////
+
+    let packs = provider.packs();
+
+    let mut resource_packs_stack = ResourcePacksStackPacket {
+        texture_pack_required: !packs.behavior_packs().is_empty(),
+        addons: packs
+            .behavior_packs()
+            .iter()
+            .map(|pack| ResourcePacksStackPack::from_manifest(&pack.manifest))
+            .collect(),
+        texture_packs: packs
+            .resource_packs()
+            .iter()
+            .map(|pack| ResourcePacksStackPack::from_manifest(&pack.manifest))
+            .collect(),
+        base_game_version: BaseGameVersion(String::from(MINECRAFT_VERSION)),
+        experiments: Experiments {
+            experiments: vec![],
+            ever_toggled: false,
+        },
+        include_editor_packs: false,
     };
 
+    provider
+        .on_resource_packs_stack_pk(&mut resource_packs_stack, session)
+        .await
+        .into_result()?;
+
+    conn.send(GamePacket::ResourcePackStack(resource_packs_stack))
+        .await
+        .map_err(LoginError::ConnectionError)?;
+    conn.flush().await.map_err(LoginError::ConnectionError)?;
+
     Ok(())
 }
+
+fn find_archive<'a>(
+    archives: &'a [PackArchive],
+    pack_id: &str,
+) -> Result<&'a PackArchive, LoginError> {
+    archives
+        .iter()
+        .find(|archive| archive.matches_id(pack_id))
+        .ok_or_else(|| LoginError::FormatError(format!("Client requested unknown pack: {pack_id}")))
+}
+
+/// The archive of a pack in the pack list, a provider building the packs
+/// by hand might have left it out.
+fn pack_archive<'a>(
+    archives: &'a [PackArchive],
+    uuid: &Uuid,
+    pack_type: ResourcePackType,
+) -> Result<&'a PackArchive, LoginError> {
+    archives
+        .iter()
+        .find(|archive| archive.uuid == *uuid && archive.pack_type == pack_type)
+        .ok_or_else(|| LoginError::FormatError(format!("Pack {uuid} has no archive")))
+}
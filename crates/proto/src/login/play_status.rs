@@ -1,13 +1,14 @@
 use crate::connection::ConnectionShard;
 use crate::error::LoginError;
 use crate::gamepacket::GamePacket;
-use crate::login::provider::{LoginProviderServer, LoginProviderStatus};
+use crate::login::provider::LoginProviderServer;
 use crate::packets::play_status::PlayStatusPacket;
 use crate::types::play_status::PlayStatusType;
 
-pub async fn play_status_login(
+pub async fn play_status_login<P: LoginProviderServer>(
     conn: &mut ConnectionShard,
-    provider: &mut impl LoginProviderServer,
+    provider: &mut P,
+    session: &mut P::SessionData,
 ) -> Result<(), LoginError> {
     //////////////////////////////////////
     // Play Status Packet (Login)
@@ -17,12 +18,10 @@ pub async fn play_status_login(
         status: PlayStatusType::LoginSuccess,
     };
 
-    match provider.on_play_status_pk(&mut play_status) {
-        LoginProviderStatus::ContinueLogin => {}
-        LoginProviderStatus::AbortLogin { reason } => {
-            return Err(LoginError::Abort { reason });
-        }
-    };
+    provider
+        .on_play_status_pk(&mut play_status, session)
+        .await
+        .into_result()?;
 
     match conn.send(GamePacket::PlayStatus(play_status)).await {
         Ok(_) => {}
@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+use bedrockrs_addon::behavior::BehaviorPack;
+use bedrockrs_addon::resource::ResourcePack;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream, ToSocketAddrs};
+use tokio::time::timeout;
+
+use crate::error::CDNError;
+use crate::login::provider::packs::{LoginProviderPacks, PackArchive};
+use crate::types::pack_url::PackURL;
+
+/// The maximum size of a request's head, bigger requests get rejected.
+const MAX_REQUEST_SIZE: usize = 8 * 1024;
+/// How long a client may take to send the head of its request before the connection is closed.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A minimal HTTP server that serves zipped packs for [`LoginProviderPacks::CDN`].
+///
+/// The packs are zipped once when the server gets bound and are served at
+/// `http://<addr>/<uuid>_<version>`, the same id the client uses for the pack.
+///
+/// ```ignore
+/// let (cdn, packs) = PackCDN::bind("127.0.0.1:8080", behavior_packs, resource_packs).await?;
+/// tokio::spawn(cdn.run());
+/// // Hand `packs` to the login provider
+/// ```
+pub struct PackCDN {
+    listener: TcpListener,
+    /// The zip files by pack id, sharing the data of the archives
+    archives: Arc<HashMap<String, Arc<[u8]>>>,
+}
+
+impl PackCDN {
+    /// Zips the packs, binds the server to `addr` and returns it with the matching
+    /// [`LoginProviderPacks::CDN`], which contains a [`PackURL`] for every pack.
+    ///
+    /// If bound to an unspecified address (e.g. `0.0.0.0`) the urls point to `127.0.0.1`.
+    pub async fn bind(
+        addr: impl ToSocketAddrs,
+        behavior_packs: Vec<BehaviorPack>,
+        resource_packs: Vec<ResourcePack>,
+    ) -> Result<(Self, LoginProviderPacks), CDNError> {
+        let listener = TcpListener::bind(addr)
+            .await
+            .map_err(|e| CDNError::IOError(Arc::new(e)))?;
+
+        let mut local_addr = listener
+            .local_addr()
+            .map_err(|e| CDNError::IOError(Arc::new(e)))?;
+        if local_addr.ip().is_unspecified() {
+            local_addr.set_ip(IpAddr::V4(Ipv4Addr::LOCALHOST));
+        }
+
+        let mut packs = LoginProviderPacks::cdn(behavior_packs, resource_packs, vec![])?;
+
+        if let LoginProviderPacks::CDN {
+            cdn_urls, archives, ..
+        } = &mut packs
+        {
+            *cdn_urls = archives
+                .iter()
+                .map(|archive| PackURL::new(archive, pack_url(&local_addr, archive)))
+                .collect();
+        }
+
+        let archives = packs
+            .archives()
+            .iter()
+            .map(|archive| (archive.pack_id(), archive.data.clone()))
+            .collect();
+
+        Ok((
+            Self {
+                listener,
+                archives: Arc::new(archives),
+            },
+            packs,
+        ))
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Serves the packs until accepting a connection fails, every
+    /// connection gets handled in its own task.
+    pub async fn run(self) -> Result<(), CDNError> {
+        loop {
+            let (stream, _) = self
+                .listener
+                .accept()
+                .await
+                .map_err(|e| CDNError::IOError(Arc::new(e)))?;
+
+            let archives = self.archives.clone();
+
+            tokio::spawn(async move {
+                // A failing connection only affects that one download
+                let _ = handle_connection(stream, &archives).await;
+            });
+        }
+    }
+}
+
+fn pack_url(addr: &SocketAddr, archive: &PackArchive) -> String {
+    format!("http://{addr}/{}", archive.pack_id())
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    archives: &HashMap<String, Arc<[u8]>>,
+) -> io::Result<()> {
+    let request = match timeout(REQUEST_TIMEOUT, read_request_head(&mut stream)).await {
+        Ok(Ok(Some(request))) => request,
+        // The client closed the connection
+        Ok(Ok(None)) => return Ok(()),
+        Ok(Err(e)) => return Err(e),
+        Err(_) => return respond(&mut stream, "408 Request Timeout", &[]).await,
+    };
+
+    if request.len() > MAX_REQUEST_SIZE {
+        return respond(&mut stream, "431 Request Header Fields Too Large", &[]).await;
+    }
+
+    let request = String::from_utf8_lossy(&request);
+    let mut request_line = request.lines().next().unwrap_or_default().split(' ');
+
+    let (method, path) = match (request_line.next(), request_line.next()) {
+        (Some(method), Some(path)) => (method, path),
+        _ => return respond(&mut stream, "400 Bad Request", &[]).await,
+    };
+
+    if method != "GET" && method != "HEAD" {
+        return respond(&mut stream, "405 Method Not Allowed", &[]).await;
+    }
+
+    // Clients may append `.zip` or query parameters to the url
+    let pack_id = path.trim_start_matches('/');
+    let pack_id = pack_id.split('?').next().unwrap_or_default();
+    let pack_id = pack_id.trim_end_matches(".zip");
+
+    match archives.get(pack_id) {
+        Some(data) if method == "HEAD" => write_head(&mut stream, "200 OK", data.len()).await,
+        Some(data) => respond(&mut stream, "200 OK", data).await,
+        None => respond(&mut stream, "404 Not Found", &[]).await,
+    }
+}
+
+/// Reads until the end of the request's head, the body is of no interest.
+/// Stops early once the head gets too big, returns `None` if the client closed the connection.
+async fn read_request_head(stream: &mut TcpStream) -> io::Result<Option<Vec<u8>>> {
+    let mut request = Vec::new();
+    let mut buf = [0; 1024];
+
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut buf).await?;
+        if n == 0 {
+            return Ok(None);
+        }
+
+        request.extend_from_slice(&buf[..n]);
+
+        if request.len() > MAX_REQUEST_SIZE {
+            break;
+        }
+    }
+
+    Ok(Some(request))
+}
+
+async fn write_head(stream: &mut TcpStream, status: &str, len: usize) -> io::Result<()> {
+    let head = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: application/zip\r\nContent-Length: {len}\r\nConnection: close\r\n\r\n"
+    );
+
+    stream.write_all(head.as_bytes()).await?;
+    stream.flush().await
+}
+
+async fn respond(stream: &mut TcpStream, status: &str, body: &[u8]) -> io::Result<()> {
+    write_head(stream, status, body.len()).await?;
+
+    stream.write_all(body).await?;
+    stream.flush().await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use bedrockrs_addon::Addon;
+
+    use super::*;
+
+    struct TestPack(PathBuf);
+
+    impl TestPack {
+        fn new() -> Self {
+            let path =
+                std::env::temp_dir().join(format!("bedrockrs_proto_cdn_{}", std::process::id()));
+            let _ = fs::remove_dir_all(&path);
+            fs::create_dir_all(&path).unwrap();
+
+            fs::write(
+                path.join("manifest.json"),
+                r#"{
+                    "format_version": 2,
+                    "header": {
+                        "name": "Test",
+                        "version": [1, 0, 0],
+                        "uuid": "5b6d3a0e-4b4e-4c4f-9d34-2f7f6c1e8a11"
+                    },
+                    "modules": [],
+                    "dependencies": [],
+                    "metadata": {}
+                }"#,
+            )
+            .unwrap();
+
+            Self(path)
+        }
+    }
+
+    impl Drop for TestPack {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    async fn get(addr: SocketAddr, path: &str) -> (String, Vec<u8>) {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(format!("GET {path} HTTP/1.1\r\nHost: {addr}\r\n\r\n").as_bytes())
+            .await
+            .unwrap();
+
+        let mut response = vec![];
+        stream.read_to_end(&mut response).await.unwrap();
+
+        let head_len = response.windows(4).position(|w| w == b"\r\n\r\n").unwrap() + 4;
+        let body = response.split_off(head_len);
+        let status = String::from_utf8(response)
+            .unwrap()
+            .lines()
+            .next()
+            .unwrap()
+            .to_string();
+
+        (status, body)
+    }
+
+    #[tokio::test]
+    async fn serves_packs_on_localhost() {
+        let pack = TestPack::new();
+        let resource_pack = ResourcePack::import(&pack.0).unwrap();
+
+        let (cdn, packs) = PackCDN::bind("127.0.0.1:0", vec![], vec![resource_pack])
+            .await
+            .unwrap();
+        let addr = cdn.local_addr().unwrap();
+        tokio::spawn(cdn.run());
+
+        assert_eq!(packs.cdn_urls().len(), 1);
+        let archive = &packs.archives()[0];
+
+        let (status, body) = get(addr, &format!("/{}", archive.pack_id())).await;
+        assert_eq!(status, "HTTP/1.1 200 OK");
+        assert_eq!(body, &archive.data[..]);
+
+        let (status, body) = get(addr, &format!("/{}.zip?download=1", archive.pack_id())).await;
+        assert_eq!(status, "HTTP/1.1 200 OK");
+        assert_eq!(body, &archive.data[..]);
+
+        let (status, body) = get(addr, "/unknown_1.0.0").await;
+        assert_eq!(status, "HTTP/1.1 404 Not Found");
+        assert!(body.is_empty());
+    }
+}
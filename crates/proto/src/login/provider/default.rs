@@ -1,14 +1,8 @@
+use std::sync::Arc;
+
 use crate::compression::Compression;
 use crate::login::provider::packs::LoginProviderPacks;
-use crate::login::provider::{LoginProviderServer, LoginProviderStatus};
-use crate::packets::client_cache_status::ClientCacheStatusPacket;
-use crate::packets::login::LoginPacket;
-use crate::packets::network_settings::NetworkSettingsPacket;
-use crate::packets::network_settings_request::NetworkSettingsRequestPacket;
-use crate::packets::play_status::PlayStatusPacket;
-use crate::packets::resource_packs_info::ResourcePacksInfoPacket;
-use crate::packets::resource_packs_response::ResourcePacksResponsePacket;
-use crate::packets::resource_packs_stack::ResourcePacksStackPacket;
+use crate::login::provider::LoginProviderServer;
 
 pub struct DefaultLoginProvider {
     packs: LoginProviderPacks,
@@ -21,6 +15,7 @@ impl DefaultLoginProvider {
                 behavior_packs: vec![],
                 resource_packs: vec![],
                 cdn_urls: vec![],
+                archives: Arc::new([]),
             },
         }
     }
@@ -33,6 +28,8 @@ impl Default for DefaultLoginProvider {
 }
 
 impl LoginProviderServer for DefaultLoginProvider {
+    type SessionData = ();
+
     fn compression(&self) -> Compression {
         Compression::None
     }
@@ -47,44 +44,4 @@ impl LoginProviderServer for DefaultLoginProvider {
     fn packs(&self) -> &LoginProviderPacks {
         &self.packs
     }
-
-    fn on_network_settings_request_pk(
-        &mut self,
-        pk: &mut NetworkSettingsRequestPacket,
-    ) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
-    }
-
-    fn on_network_settings_pk(&mut self, pk: &mut NetworkSettingsPacket) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
-    }
-
-    fn on_login_pk(&mut self, pk: &mut LoginPacket) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
-    }
-
-    fn on_play_status_pk(&mut self, pk: &mut PlayStatusPacket) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
-    }
-    fn on_resource_packs_info_pk(
-        &mut self,
-        pk: &mut ResourcePacksInfoPacket,
-    ) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
-    }
-    fn on_resource_packs_stack_pk(
-        &mut self,
-        pk: &mut ResourcePacksStackPacket,
-    ) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
-    }
-    fn on_resource_packs_response_pk(
-        &mut self,
-        pk: &mut ResourcePacksResponsePacket,
-    ) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
-    }
-    fn on_client_cache_status_pk(&self, pk: &mut ClientCacheStatusPacket) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
-    }
 }
@@ -1,8 +1,12 @@
 pub use bedrockrs_addon::*;
+#[cfg(feature = "cdn")]
+pub use cdn::*;
 pub use default::*;
 pub use provider::*;
 pub use status::*;
 
+#[cfg(feature = "cdn")]
+pub mod cdn;
 pub mod default;
 pub mod packs;
 pub mod provider;
@@ -1,16 +1,167 @@
+use std::sync::Arc;
+
 use bedrockrs_addon::behavior::BehaviorPack;
+use bedrockrs_addon::error::AddonError;
 use bedrockrs_addon::resource::ResourcePack;
+use bedrockrs_addon::Addon;
+use ring::digest::{digest, SHA256};
+use uuid::Uuid;
 
 use crate::types::pack_url::PackURL;
+use crate::types::resource_pack_type::ResourcePackType;
 
+/// The packs sent to clients during the login.
+///
+/// Zipping the packs is slow and blocking, so it is done once by the constructors
+/// and the `archives` are reused for every login.
 pub enum LoginProviderPacks {
     CDN {
         behavior_packs: Vec<BehaviorPack>,
         resource_packs: Vec<ResourcePack>,
         cdn_urls: Vec<PackURL>,
+        /// The zipped packs, found by the uuid of their pack
+        archives: Arc<[PackArchive]>,
     },
     DirectNetworkTransfer {
         behavior_packs: Vec<BehaviorPack>,
         resource_packs: Vec<ResourcePack>,
+        /// The zipped packs, found by the uuid of their pack
+        archives: Arc<[PackArchive]>,
     },
 }
+
+impl LoginProviderPacks {
+    /// Packs the client downloads from the given urls, the packs are still zipped
+    /// as the client needs to know their sizes.
+    pub fn cdn(
+        behavior_packs: Vec<BehaviorPack>,
+        resource_packs: Vec<ResourcePack>,
+        cdn_urls: Vec<PackURL>,
+    ) -> Result<Self, AddonError> {
+        let archives = zip_packs(&behavior_packs, &resource_packs)?;
+
+        Ok(LoginProviderPacks::CDN {
+            behavior_packs,
+            resource_packs,
+            cdn_urls,
+            archives,
+        })
+    }
+
+    /// Packs sent to the client over the connection itself.
+    pub fn direct_network_transfer(
+        behavior_packs: Vec<BehaviorPack>,
+        resource_packs: Vec<ResourcePack>,
+    ) -> Result<Self, AddonError> {
+        let archives = zip_packs(&behavior_packs, &resource_packs)?;
+
+        Ok(LoginProviderPacks::DirectNetworkTransfer {
+            behavior_packs,
+            resource_packs,
+            archives,
+        })
+    }
+
+    pub fn behavior_packs(&self) -> &[BehaviorPack] {
+        match self {
+            LoginProviderPacks::CDN { behavior_packs, .. } => behavior_packs,
+            LoginProviderPacks::DirectNetworkTransfer { behavior_packs, .. } => behavior_packs,
+        }
+    }
+
+    pub fn resource_packs(&self) -> &[ResourcePack] {
+        match self {
+            LoginProviderPacks::CDN { resource_packs, .. } => resource_packs,
+            LoginProviderPacks::DirectNetworkTransfer { resource_packs, .. } => resource_packs,
+        }
+    }
+
+    pub fn cdn_urls(&self) -> &[PackURL] {
+        match self {
+            LoginProviderPacks::CDN { cdn_urls, .. } => cdn_urls,
+            LoginProviderPacks::DirectNetworkTransfer { .. } => &[],
+        }
+    }
+
+    /// The zipped behavior and resource packs.
+    pub fn archives(&self) -> &Arc<[PackArchive]> {
+        match self {
+            LoginProviderPacks::CDN { archives, .. } => archives,
+            LoginProviderPacks::DirectNetworkTransfer { archives, .. } => archives,
+        }
+    }
+}
+
+fn zip_packs(
+    behavior_packs: &[BehaviorPack],
+    resource_packs: &[ResourcePack],
+) -> Result<Arc<[PackArchive]>, AddonError> {
+    let behavior_packs = behavior_packs
+        .iter()
+        .map(|pack| PackArchive::new(pack, ResourcePackType::Behavior));
+    let resource_packs = resource_packs
+        .iter()
+        .map(|pack| PackArchive::new(pack, ResourcePackType::Resources));
+
+    behavior_packs.chain(resource_packs).collect()
+}
+
+/// A zipped pack as it gets sent to the client.
+#[derive(Debug, Clone)]
+pub struct PackArchive {
+    pub uuid: Uuid,
+    pub version: String,
+    pub pack_type: ResourcePackType,
+    /// The zip file, shared by every login and the pack CDN
+    pub data: Arc<[u8]>,
+    /// The SHA-256 hash of `data`
+    pub hash: Vec<u8>,
+}
+
+impl PackArchive {
+    /// The maximum size of a chunk the pack gets split into when it is sent to the client.
+    pub const CHUNK_SIZE: u32 = 1024 * 128;
+
+    pub fn new(pack: &impl Addon, pack_type: ResourcePackType) -> Result<Self, AddonError> {
+        let header = &pack.manifest().header;
+        let data = pack.zip()?;
+        let hash = digest(&SHA256, &data).as_ref().to_vec();
+
+        Ok(Self {
+            uuid: header.uuid,
+            version: header.version.to_string(),
+            pack_type,
+            data: data.into(),
+            hash,
+        })
+    }
+
+    /// The id the client refers to the pack with, uuid and version seperated by a `_`.
+    pub fn pack_id(&self) -> String {
+        format!("{}_{}", self.uuid, self.version)
+    }
+
+    /// Whether the id the client sent refers to this pack, the client
+    /// sometimes leaves out the version.
+    pub fn matches_id(&self, id: &str) -> bool {
+        match id.split_once('_') {
+            Some((uuid, version)) => uuid == self.uuid.to_string() && version == self.version,
+            None => id == self.uuid.to_string(),
+        }
+    }
+
+    pub fn chunk_count(&self) -> u32 {
+        self.data.len().div_ceil(Self::CHUNK_SIZE as usize) as u32
+    }
+
+    /// Returns the offset and data of the chunk at `index`, `None` if it is out of bounds.
+    pub fn chunk(&self, index: u32) -> Option<(u64, &[u8])> {
+        let start = (index as usize).checked_mul(Self::CHUNK_SIZE as usize)?;
+        if start >= self.data.len() {
+            return None;
+        }
+        let end = (start + Self::CHUNK_SIZE as usize).min(self.data.len());
+
+        Some((start as u64, &self.data[start..end]))
+    }
+}
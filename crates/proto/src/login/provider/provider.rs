@@ -1,57 +1,174 @@
+use std::future::Future;
+
 use crate::compression::Compression;
 use crate::login::provider::packs::LoginProviderPacks;
 use crate::login::provider::status::LoginProviderStatus;
+use crate::packets::chunk_radius_updated::ChunkRadiusUpdatedPacket;
 use crate::packets::client_cache_status::ClientCacheStatusPacket;
+use crate::packets::level_chunk::LevelChunkPacket;
 use crate::packets::login::LoginPacket;
+use crate::packets::network_chunk_publisher_update::NetworkChunkPublisherUpdatePacket;
 use crate::packets::network_settings::NetworkSettingsPacket;
 use crate::packets::network_settings_request::NetworkSettingsRequestPacket;
 use crate::packets::play_status::PlayStatusPacket;
+use crate::packets::request_chunk_radius::RequestChunkRadiusPacket;
+use crate::packets::resource_pack_chunk_request::ResourcePackChunkRequestPacket;
+use crate::packets::resource_pack_data_info::ResourcePackDataInfoPacket;
 use crate::packets::resource_packs_info::ResourcePacksInfoPacket;
 use crate::packets::resource_packs_response::ResourcePacksResponsePacket;
 use crate::packets::resource_packs_stack::ResourcePacksStackPacket;
+use crate::packets::set_local_player_as_initialized::SetLocalPlayerAsInitializedPacket;
+use crate::packets::start_game::StartGamePacket;
+use crate::types::chunk_pos::ChunkPos;
+
+/// Drives the server side of the login process.
+///
+/// Every `on_*_pk` hook is awaited by [`login_to_server`](crate::login::login_to_server) at its
+/// stage of the login, so hooks are free to hit a database, a ban service or anything else
+/// async before deciding whether the login continues.
+pub trait LoginProviderServer: Send {
+    /// Data the provider wants to keep per session, it gets created when the login starts,
+    /// is handed to every hook and ends up in the [`LoginResult`](crate::login::LoginResult).
+    type SessionData: Default + Send;
 
-pub trait LoginProviderServer {
     fn compression(&self) -> Compression;
     fn encryption_enabled(&self) -> bool;
     fn auth_enabled(&self) -> bool;
 
     fn packs(&self) -> &LoginProviderPacks;
 
+    /// Whether the login also walks the client through spawning (chunk radius, the initial
+    /// chunks and waiting for the client to be initialized), if not the login ends right
+    /// after the [`StartGamePacket`] and the server has to do it on its own.
+    fn spawn_enabled(&self) -> bool {
+        false
+    }
+
+    /// The largest chunk radius the server sends chunks in, the radius requested
+    /// by the client is clamped to it before it is answered.
+    fn max_chunk_radius(&self) -> u32 {
+        32
+    }
+
     fn on_network_settings_request_pk(
         &mut self,
         _pk: &mut NetworkSettingsRequestPacket,
-    ) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
+        _session: &mut Self::SessionData,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
     }
-    fn on_network_settings_pk(&mut self, _pk: &mut NetworkSettingsPacket) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
+    fn on_network_settings_pk(
+        &mut self,
+        _pk: &mut NetworkSettingsPacket,
+        _session: &mut Self::SessionData,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
     }
-    fn on_login_pk(&mut self, _pk: &mut LoginPacket) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
+    fn on_login_pk(
+        &mut self,
+        _pk: &mut LoginPacket,
+        _session: &mut Self::SessionData,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
     }
-    fn on_play_status_pk(&mut self, _pk: &mut PlayStatusPacket) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
+    fn on_play_status_pk(
+        &mut self,
+        _pk: &mut PlayStatusPacket,
+        _session: &mut Self::SessionData,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
     }
     fn on_resource_packs_info_pk(
         &mut self,
         _pk: &mut ResourcePacksInfoPacket,
-    ) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
+        _session: &mut Self::SessionData,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
     }
     fn on_resource_packs_stack_pk(
         &mut self,
         _pk: &mut ResourcePacksStackPacket,
-    ) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
+        _session: &mut Self::SessionData,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
     }
     fn on_resource_packs_response_pk(
         &mut self,
         _pk: &mut ResourcePacksResponsePacket,
-    ) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
+        _session: &mut Self::SessionData,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
+    }
+    fn on_resource_pack_data_info_pk(
+        &mut self,
+        _pk: &mut ResourcePackDataInfoPacket,
+        _session: &mut Self::SessionData,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
+    }
+    fn on_resource_pack_chunk_request_pk(
+        &mut self,
+        _pk: &mut ResourcePackChunkRequestPacket,
+        _session: &mut Self::SessionData,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
     }
-    fn on_client_cache_status_pk(&self, _pk: &mut ClientCacheStatusPacket) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
+    fn on_client_cache_status_pk(
+        &mut self,
+        _pk: &mut ClientCacheStatusPacket,
+        _session: &mut Self::SessionData,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
+    }
+    /// Gets called with a [`StartGamePacket::default`] before it is sent, this is where the
+    /// provider sets the player's position, runtime/unique IDs, the level settings and the
+    /// item/block registries.
+    fn on_start_game_pk(
+        &mut self,
+        _pk: &mut StartGamePacket,
+        _session: &mut Self::SessionData,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
+    }
+    fn on_request_chunk_radius_pk(
+        &mut self,
+        _pk: &mut RequestChunkRadiusPacket,
+        _session: &mut Self::SessionData,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
+    }
+    /// Gets called with the radius the client requested, already clamped to
+    /// [`Self::max_chunk_radius`] and the client's own maximum.
+    /// Lower it further to cap the view distance.
+    fn on_chunk_radius_updated_pk(
+        &mut self,
+        _pk: &mut ChunkRadiusUpdatedPacket,
+        _session: &mut Self::SessionData,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
+    }
+    fn on_network_chunk_publisher_update_pk(
+        &mut self,
+        _pk: &mut NetworkChunkPublisherUpdatePacket,
+        _session: &mut Self::SessionData,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
+    }
+    /// Gets called for every chunk inside the chunk radius, nearest first, returning `None`
+    /// skips the chunk.
+    fn on_spawn_chunk(
+        &mut self,
+        _pos: ChunkPos,
+        _session: &mut Self::SessionData,
+    ) -> impl Future<Output = Option<LevelChunkPacket>> + Send {
+        async { None }
+    }
+    fn on_set_local_player_as_initialized_pk(
+        &mut self,
+        _pk: &mut SetLocalPlayerAsInitializedPacket,
+        _session: &mut Self::SessionData,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
     }
 }
 
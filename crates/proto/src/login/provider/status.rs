@@ -1,4 +1,36 @@
+use crate::error::LoginError;
+use crate::types::disconnect_reason::DisconnectReason;
+
 pub enum LoginProviderStatus {
     ContinueLogin,
-    AbortLogin { reason: String },
+    /// Aborts the login, the client gets disconnected with the given
+    /// `reason` as message and `disconnect_reason` for telemetry.
+    AbortLogin {
+        reason: String,
+        disconnect_reason: DisconnectReason,
+    },
+}
+
+impl LoginProviderStatus {
+    /// Shorthand for aborting the login with [`DisconnectReason::Unknown`].
+    pub fn abort(reason: impl Into<String>) -> Self {
+        Self::AbortLogin {
+            reason: reason.into(),
+            disconnect_reason: DisconnectReason::Unknown,
+        }
+    }
+
+    /// Turns the status into a result, aborting becomes a [`LoginError::Abort`].
+    pub fn into_result(self) -> Result<(), LoginError> {
+        match self {
+            LoginProviderStatus::ContinueLogin => Ok(()),
+            LoginProviderStatus::AbortLogin {
+                reason,
+                disconnect_reason,
+            } => Err(LoginError::Abort {
+                reason,
+                disconnect_reason,
+            }),
+        }
+    }
 }
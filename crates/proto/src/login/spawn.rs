@@ -0,0 +1,177 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_core::Vec3;
+
+use crate::connection::ConnectionShard;
+use crate::error::LoginError;
+use crate::gamepacket::GamePacket;
+use crate::login::provider::LoginProviderServer;
+use crate::packets::chunk_radius_updated::ChunkRadiusUpdatedPacket;
+use crate::packets::network_chunk_publisher_update::NetworkChunkPublisherUpdatePacket;
+use crate::packets::play_status::PlayStatusPacket;
+use crate::packets::start_game::StartGamePacket;
+use crate::types::chunk_pos::ChunkPos;
+use crate::types::play_status::PlayStatusType;
+
+/// Sends the player spawn status, if the provider has spawning enabled the chunk radius gets
+/// negotiated and the chunks around the player are sent beforehand, afterwards it waits until
+/// the client reports to be initialized.
+///
+/// Returns the negotiated chunk radius, `None` if spawning is disabled.
+pub async fn spawn<P: LoginProviderServer>(
+    conn: &mut ConnectionShard,
+    provider: &mut P,
+    session: &mut P::SessionData,
+    start_game: &StartGamePacket,
+) -> Result<Option<u32>, LoginError> {
+    if !provider.spawn_enabled() {
+        send_player_spawn(conn).await?;
+        return Ok(None);
+    }
+
+    //////////////////////////////////////
+    // Request Chunk Radius Packet
+    //////////////////////////////////////
+
+    // The client may send other packets (e.g. ticking) before it requests its chunk radius
+    let mut request_chunk_radius = loop {
+        match conn.recv().await {
+            Ok(GamePacket::RequestChunkRadius(pk)) => break pk,
+            Ok(_) => continue,
+            Err(e) => return Err(LoginError::ConnectionError(e)),
+        }
+    };
+
+    provider
+        .on_request_chunk_radius_pk(&mut request_chunk_radius, session)
+        .await
+        .into_result()?;
+
+    //////////////////////////////////////
+    // Chunk Radius Updated Packet
+    //////////////////////////////////////
+
+    // The requested radius is untrusted, so it is clamped before any chunks are built for it
+    let mut max_chunk_radius = provider.max_chunk_radius();
+    if request_chunk_radius.chunk_radius_max != 0 {
+        max_chunk_radius = max_chunk_radius.min(request_chunk_radius.chunk_radius_max as u32);
+    }
+
+    let mut chunk_radius_updated = ChunkRadiusUpdatedPacket {
+        chunk_radius: VAR::<u32>::new(
+            request_chunk_radius
+                .chunk_radius
+                .into_inner()
+                .min(max_chunk_radius),
+        ),
+    };
+
+    provider
+        .on_chunk_radius_updated_pk(&mut chunk_radius_updated, session)
+        .await
+        .into_result()?;
+
+    let chunk_radius = chunk_radius_updated
+        .chunk_radius
+        .into_inner()
+        .min(provider.max_chunk_radius());
+    chunk_radius_updated.chunk_radius = VAR::<u32>::new(chunk_radius);
+
+    conn.send(GamePacket::ChunkRadiusUpdate(chunk_radius_updated))
+        .await
+        .map_err(LoginError::ConnectionError)?;
+
+    //////////////////////////////////////
+    // Network Chunk Publisher Update Packet
+    //////////////////////////////////////
+
+    let position = Vec3 {
+        x: VAR::<i32>::new(start_game.position.x.into_inner().floor() as i32),
+        y: VAR::<i32>::new(start_game.position.y.into_inner().floor() as i32),
+        z: VAR::<i32>::new(start_game.position.z.into_inner().floor() as i32),
+    };
+    let center = ChunkPos::new(position.x.into_inner() >> 4, position.z.into_inner() >> 4);
+
+    let mut network_chunk_publisher_update = NetworkChunkPublisherUpdatePacket {
+        position,
+        radius: VAR::<u32>::new(chunk_radius.saturating_mul(16)),
+        saved_chunks: vec![],
+    };
+
+    provider
+        .on_network_chunk_publisher_update_pk(&mut network_chunk_publisher_update, session)
+        .await
+        .into_result()?;
+
+    conn.send(GamePacket::NetworkChunkPublisherUpdate(
+        network_chunk_publisher_update,
+    ))
+    .await
+    .map_err(LoginError::ConnectionError)?;
+
+    //////////////////////////////////////
+    // Level Chunk Packets
+    //////////////////////////////////////
+
+    for pos in chunks_in_radius(&center, chunk_radius) {
+        if let Some(level_chunk) = provider.on_spawn_chunk(pos, session).await {
+            conn.send(GamePacket::LevelChunk(level_chunk))
+                .await
+                .map_err(LoginError::ConnectionError)?;
+        }
+    }
+
+    send_player_spawn(conn).await?;
+
+    //////////////////////////////////////
+    // Set Local Player As Initialized Packet
+    //////////////////////////////////////
+
+    let mut set_local_player_as_initialized = loop {
+        match conn.recv().await {
+            Ok(GamePacket::SetLocalPlayerAsInitialized(pk)) => break pk,
+            Ok(_) => continue,
+            Err(e) => return Err(LoginError::ConnectionError(e)),
+        }
+    };
+
+    provider
+        .on_set_local_player_as_initialized_pk(&mut set_local_player_as_initialized, session)
+        .await
+        .into_result()?;
+
+    Ok(Some(chunk_radius))
+}
+
+async fn send_player_spawn(conn: &mut ConnectionShard) -> Result<(), LoginError> {
+    conn.send(GamePacket::PlayStatus(PlayStatusPacket {
+        status: PlayStatusType::PlayerSpawn,
+    }))
+    .await
+    .map_err(LoginError::ConnectionError)?;
+    conn.flush().await.map_err(LoginError::ConnectionError)?;
+
+    Ok(())
+}
+
+/// All chunk positions in a circle around `center`, sorted nearest first, so the client
+/// can render the area around the player as early as possible.
+fn chunks_in_radius(center: &ChunkPos, radius: u32) -> Vec<ChunkPos> {
+    let radius = radius.min(i32::MAX as u32) as i32;
+    let (center_x, center_z) = (center.x.into_inner(), center.z.into_inner());
+
+    let mut offsets = vec![];
+    for x in -radius..=radius {
+        for z in -radius..=radius {
+            let distance = x as i64 * x as i64 + z as i64 * z as i64;
+            if distance <= radius as i64 * radius as i64 {
+                offsets.push((distance, x, z));
+            }
+        }
+    }
+    offsets.sort_by_key(|(distance, _, _)| *distance);
+
+    offsets
+        .into_iter()
+        .map(|(_, x, z)| ChunkPos::new(center_x + x, center_z + z))
+        .collect()
+}
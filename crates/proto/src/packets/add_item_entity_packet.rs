@@ -0,0 +1,21 @@
+use bedrockrs_core::{
+    int::{LE, VAR},
+    Vec3,
+};
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::{actor_runtime_id::ActorRuntimeID, actor_unique_id::ActorUniqueID};
+
+use crate::types::dataitem::DataItem;
+use crate::types::network_item_stack_descriptor::NetworkItemStackDescriptor;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct AddItemEntityPacket {
+    pub target_actor_id: ActorUniqueID,
+    pub target_runtime_id: ActorRuntimeID,
+    pub item: NetworkItemStackDescriptor,
+    pub position: Vec3<LE<f32>>,
+    pub velocity: Vec3<LE<f32>>,
+    #[len_repr(VAR::<u32>)]
+    pub actor_data: Vec<DataItem>,
+    pub from_fishing: bool,
+}
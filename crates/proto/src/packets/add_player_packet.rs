@@ -0,0 +1,37 @@
+use bedrockrs_core::{
+    int::{LE, VAR},
+    Vec3,
+};
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+use bedrockrs_shared::world::gamemode::Gamemode;
+use uuid::Uuid;
+
+use crate::types::ability_data::AbilityData;
+use crate::types::actor_link::ActorLink;
+use crate::types::dataitem::DataItem;
+use crate::types::network_item_stack_descriptor::NetworkItemStackDescriptor;
+use crate::types::property_sync_data::PropertySyncData;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct AddPlayerPacket {
+    pub uuid: Uuid,
+    pub username: String,
+    pub target_runtime_id: ActorRuntimeID,
+    pub platform_chat_id: String,
+    pub position: Vec3<LE<f32>>,
+    pub velocity: Vec3<LE<f32>>,
+    pub pitch: LE<f32>,
+    pub yaw: LE<f32>,
+    pub head_yaw: LE<f32>,
+    pub carried_item: NetworkItemStackDescriptor,
+    pub gamemode: Gamemode,
+    #[len_repr(VAR::<u32>)]
+    pub actor_data: Vec<DataItem>,
+    pub synched_properties: PropertySyncData,
+    pub abilities: AbilityData,
+    #[len_repr(VAR::<u32>)]
+    pub actor_links: Vec<ActorLink>,
+    pub device_id: String,
+    pub build_platform: LE<i32>,
+}
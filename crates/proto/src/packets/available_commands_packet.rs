@@ -0,0 +1,127 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::types::command_data::CommandData;
+use crate::types::command_enum::{
+    ChainedSubcommand, CommandEnum, CommandEnumConstraint, CommandSoftEnum,
+};
+
+/// Sends the commands the client shows in its autocomplete.
+///
+/// Use the [`AvailableCommandsBuilder`](crate::command::AvailableCommandsBuilder)
+/// instead of filling the index tables by hand.
+#[derive(Debug, Clone, Default)]
+pub struct AvailableCommandsPacket {
+    /// All values of all enums
+    pub enum_values: Vec<String>,
+    pub chained_subcommand_values: Vec<String>,
+    /// Suffixes of parameters like the `L` in `/xp 10L`
+    pub suffixes: Vec<String>,
+    pub enums: Vec<CommandEnum>,
+    pub chained_subcommands: Vec<ChainedSubcommand>,
+    pub commands: Vec<CommandData>,
+    pub soft_enums: Vec<CommandSoftEnum>,
+    pub enum_constraints: Vec<CommandEnumConstraint>,
+}
+
+fn serialize_list<T: ProtoCodec>(list: &[T], stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+    VAR::<u32>::new(list.len().try_into()?).proto_serialize(stream)?;
+
+    for item in list {
+        item.proto_serialize(stream)?;
+    }
+
+    Ok(())
+}
+
+fn deserialize_list<T: ProtoCodec>(stream: &mut Cursor<&[u8]>) -> Result<Vec<T>, ProtoCodecError> {
+    let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+
+    let mut list = vec![];
+    for _ in 0..len {
+        list.push(T::proto_deserialize(stream)?);
+    }
+
+    Ok(list)
+}
+
+impl ProtoCodec for AvailableCommandsPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        serialize_list(&self.enum_values, stream)?;
+        serialize_list(&self.chained_subcommand_values, stream)?;
+        serialize_list(&self.suffixes, stream)?;
+
+        // The size of the value indices depends on the amount of values
+        let value_count = self.enum_values.len();
+
+        VAR::<u32>::new(self.enums.len().try_into()?).proto_serialize(stream)?;
+        for command_enum in &self.enums {
+            command_enum.name.proto_serialize(stream)?;
+
+            VAR::<u32>::new(command_enum.value_indices.len().try_into()?)
+                .proto_serialize(stream)?;
+
+            for index in &command_enum.value_indices {
+                if value_count <= u8::MAX as usize {
+                    u8::try_from(*index)?.proto_serialize(stream)?;
+                } else if value_count <= u16::MAX as usize {
+                    LE::<u16>::new(u16::try_from(*index)?).proto_serialize(stream)?;
+                } else {
+                    LE::<u32>::new(*index).proto_serialize(stream)?;
+                }
+            }
+        }
+
+        serialize_list(&self.chained_subcommands, stream)?;
+        serialize_list(&self.commands, stream)?;
+        serialize_list(&self.soft_enums, stream)?;
+        serialize_list(&self.enum_constraints, stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let enum_values: Vec<String> = deserialize_list(stream)?;
+        let chained_subcommand_values = deserialize_list(stream)?;
+        let suffixes = deserialize_list(stream)?;
+
+        let value_count = enum_values.len();
+
+        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        let mut enums = vec![];
+        for _ in 0..len {
+            let name = String::proto_deserialize(stream)?;
+
+            let index_count = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+            let mut value_indices = vec![];
+            for _ in 0..index_count {
+                value_indices.push(if value_count <= u8::MAX as usize {
+                    u8::proto_deserialize(stream)? as u32
+                } else if value_count <= u16::MAX as usize {
+                    LE::<u16>::proto_deserialize(stream)?.into_inner() as u32
+                } else {
+                    LE::<u32>::proto_deserialize(stream)?.into_inner()
+                });
+            }
+
+            enums.push(CommandEnum {
+                name,
+                value_indices,
+            });
+        }
+
+        Ok(Self {
+            enum_values,
+            chained_subcommand_values,
+            suffixes,
+            enums,
+            chained_subcommands: deserialize_list(stream)?,
+            commands: deserialize_list(stream)?,
+            soft_enums: deserialize_list(stream)?,
+            enum_constraints: deserialize_list(stream)?,
+        })
+    }
+}
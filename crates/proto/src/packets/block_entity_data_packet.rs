@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use bedrockrs_nbt as nbt;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::types::network_block_pos::NetworkBlockPos;
+
+/// Sets the NBT of a block entity, for example the text of a sign or the name of a chest.
+#[derive(Debug, Clone)]
+pub struct BlockEntityDataPacket {
+    pub position: NetworkBlockPos,
+    pub data: HashMap<String, nbt::Value>,
+}
+
+impl ProtoCodec for BlockEntityDataPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.position.proto_serialize(stream)?;
+        self.data.proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let position = NetworkBlockPos::proto_deserialize(stream)?;
+
+        let data = HashMap::proto_deserialize(stream)?;
+
+        Ok(Self { position, data })
+    }
+}
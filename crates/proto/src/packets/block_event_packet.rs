@@ -0,0 +1,24 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::block_event_type::BlockEventType;
+use crate::types::network_block_pos::NetworkBlockPos;
+
+/// Animates a block, like opening a chest.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct BlockEventPacket {
+    pub position: NetworkBlockPos,
+    pub event_type: BlockEventType,
+    pub event_data: VAR<i32>,
+}
+
+impl BlockEventPacket {
+    /// Opens or closes the lid of a chest, ender chest or shulker box.
+    pub fn chest(position: NetworkBlockPos, open: bool) -> Self {
+        Self {
+            position,
+            event_type: BlockEventType::ChangeState,
+            event_data: VAR::new(open as i32),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+
+use crate::types::boss_event::BossEvent;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct BossEventPacket {
+    /// The actor the boss bar belongs to
+    pub boss_actor_unique_id: ActorUniqueID,
+    pub event: BossEvent,
+}
@@ -0,0 +1,50 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+/// Sent by clients to tell which blobs of the chunks they received they have and which they miss.
+#[derive(Debug, Clone)]
+pub struct ClientCacheBlobStatusPacket {
+    /// The blobs the server has to send with a `ClientCacheMissResponsePacket`
+    pub missing_blob_ids: Vec<LE<u64>>,
+    /// The blobs the client already has
+    pub acknowledged_blob_ids: Vec<LE<u64>>,
+}
+
+impl ProtoCodec for ClientCacheBlobStatusPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        VAR::<u32>::new(self.missing_blob_ids.len().try_into()?).proto_serialize(stream)?;
+        VAR::<u32>::new(self.acknowledged_blob_ids.len().try_into()?).proto_serialize(stream)?;
+
+        for blob_id in &self.missing_blob_ids {
+            blob_id.proto_serialize(stream)?;
+        }
+        for blob_id in &self.acknowledged_blob_ids {
+            blob_id.proto_serialize(stream)?;
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let missing_len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        let acknowledged_len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+
+        let mut missing_blob_ids = vec![];
+        for _ in 0..missing_len {
+            missing_blob_ids.push(LE::<u64>::proto_deserialize(stream)?);
+        }
+
+        let mut acknowledged_blob_ids = vec![];
+        for _ in 0..acknowledged_len {
+            acknowledged_blob_ids.push(LE::<u64>::proto_deserialize(stream)?);
+        }
+
+        Ok(Self {
+            missing_blob_ids,
+            acknowledged_blob_ids,
+        })
+    }
+}
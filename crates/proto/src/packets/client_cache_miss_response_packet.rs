@@ -0,0 +1,11 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::cache_blob::CacheBlob;
+
+/// Sends the blobs a client reported as missing.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ClientCacheMissResponsePacket {
+    #[len_repr(VAR::<u32>)]
+    pub blobs: Vec<CacheBlob>,
+}
@@ -0,0 +1,65 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::types::command_origin_data::CommandOriginData;
+use crate::types::command_output_message::{CommandOutputMessage, CommandOutputType};
+
+/// The result of a command, sent as answer to a `CommandRequestPacket`.
+#[derive(Debug, Clone)]
+pub struct CommandOutputPacket {
+    /// The origin of the request being answered
+    pub command_origin: CommandOriginData,
+    pub output_type: CommandOutputType,
+    pub success_count: VAR<u32>,
+    pub output_messages: Vec<CommandOutputMessage>,
+    /// Only sent if the output type is [`CommandOutputType::DataSet`]
+    pub data_set: String,
+}
+
+impl ProtoCodec for CommandOutputPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.command_origin.proto_serialize(stream)?;
+        self.output_type.proto_serialize(stream)?;
+        self.success_count.proto_serialize(stream)?;
+
+        VAR::<u32>::new(self.output_messages.len().try_into()?).proto_serialize(stream)?;
+        for message in &self.output_messages {
+            message.proto_serialize(stream)?;
+        }
+
+        if self.output_type == CommandOutputType::DataSet {
+            self.data_set.proto_serialize(stream)?;
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let command_origin = CommandOriginData::proto_deserialize(stream)?;
+        let output_type = CommandOutputType::proto_deserialize(stream)?;
+        let success_count = VAR::<u32>::proto_deserialize(stream)?;
+
+        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        let mut output_messages = vec![];
+        for _ in 0..len {
+            output_messages.push(CommandOutputMessage::proto_deserialize(stream)?);
+        }
+
+        let data_set = if output_type == CommandOutputType::DataSet {
+            String::proto_deserialize(stream)?
+        } else {
+            String::new()
+        };
+
+        Ok(Self {
+            command_origin,
+            output_type,
+            success_count,
+            output_messages,
+            data_set,
+        })
+    }
+}
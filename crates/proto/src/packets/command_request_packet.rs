@@ -5,8 +5,9 @@ use crate::types::command_origin_data::CommandOriginData;
 
 #[derive(ProtoCodec, Debug, Clone)]
 pub struct CommandRequestPacket {
-    command: String,
-    command_origin: CommandOriginData,
-    is_internal_source: bool,
-    version: VAR<i32>,
+    /// The command as typed by the player, including the leading `/`
+    pub command: String,
+    pub command_origin: CommandOriginData,
+    pub is_internal_source: bool,
+    pub version: VAR<i32>,
 }
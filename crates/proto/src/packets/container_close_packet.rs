@@ -4,7 +4,9 @@ use crate::types::container_type::ContainerType;
 
 #[derive(ProtoCodec, Debug, Clone)]
 pub struct ContainerClosePacket {
-    container_id: u8,
-    container_type: ContainerType,
-    server_initiated_close: bool,
+    pub container_id: u8,
+    pub container_type: ContainerType,
+    /// If the server closed the container, client side closes have to be confirmed
+    /// by sending the packet back with this set to `false`
+    pub server_initiated_close: bool,
 }
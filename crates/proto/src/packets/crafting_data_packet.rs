@@ -0,0 +1,22 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::container_mix_data_entry::ContainerMixDataEntry;
+use crate::types::material_reducer_data_entry::MaterialReducerDataEntry;
+use crate::types::potion_mix_data_entry::PotionMixDataEntry;
+use crate::types::recipe::Recipe;
+
+/// Sends all recipes the client can use in crafting tables, furnaces, brewing stands and so on.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct CraftingDataPacket {
+    #[len_repr(VAR::<u32>)]
+    pub recipes: Vec<Recipe>,
+    #[len_repr(VAR::<u32>)]
+    pub potion_mixes: Vec<PotionMixDataEntry>,
+    #[len_repr(VAR::<u32>)]
+    pub container_mixes: Vec<ContainerMixDataEntry>,
+    #[len_repr(VAR::<u32>)]
+    pub material_reducers: Vec<MaterialReducerDataEntry>,
+    /// Removes all recipes the client knows before adding the new ones
+    pub clear_recipes: bool,
+}
@@ -0,0 +1,13 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+use crate::types::actor_event::ActorEvent;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct EntityEventPacket {
+    pub target_runtime_id: ActorRuntimeID,
+    pub event: ActorEvent,
+    /// Additional data of the event, e.g. the item id for `UseItem`
+    pub data: VAR<i32>,
+}
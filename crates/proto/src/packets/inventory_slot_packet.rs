@@ -0,0 +1,14 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::network_item_stack_descriptor::NetworkItemStackDescriptor;
+
+/// Updates a single slot of a container, use an
+/// [`InventoryContentPacket`](super::inventory_content_packet::InventoryContentPacket)
+/// to update a whole container.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct InventorySlotPacket {
+    pub inventory_id: VAR<u32>,
+    pub slot: VAR<u32>,
+    pub item: NetworkItemStackDescriptor,
+}
@@ -0,0 +1,201 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::item_stack_request::ItemStackRequest;
+
+/// Sent by the client to move, craft or drop items in server authoritative inventories,
+/// every request has to be answered with an
+/// [`ItemStackResponsePacket`](super::item_stack_response_packet::ItemStackResponsePacket).
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ItemStackRequestPacket {
+    #[len_repr(VAR::<u32>)]
+    pub requests: Vec<ItemStackRequest>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use bedrockrs_core::int::LE;
+    use bedrockrs_proto_core::ProtoCodec;
+
+    use crate::types::container_name::ContainerName;
+    use crate::types::item_stack_request::{ItemStackRequestAction, ItemStackRequestSlotInfo};
+    use crate::types::network_item_instance_descriptor::NetworkItemInstanceDescriptor;
+    use crate::types::recipe_ingredient::RecipeIngredient;
+
+    use super::*;
+
+    fn slot(container: ContainerName, slot: u8) -> ItemStackRequestSlotInfo {
+        ItemStackRequestSlotInfo {
+            container,
+            slot,
+            stack_network_id: VAR::new(-(slot as i32) - 1),
+        }
+    }
+
+    /// Every action once, in the order of their ids.
+    fn actions() -> Vec<ItemStackRequestAction> {
+        let hotbar = slot(ContainerName::Hotbar, 2);
+        let cursor = slot(ContainerName::Cursor, 0);
+
+        vec![
+            ItemStackRequestAction::Take {
+                count: 3,
+                source: hotbar.clone(),
+                destination: cursor.clone(),
+            },
+            ItemStackRequestAction::Place {
+                count: 3,
+                source: cursor.clone(),
+                destination: hotbar.clone(),
+            },
+            ItemStackRequestAction::Swap {
+                source: hotbar.clone(),
+                destination: slot(ContainerName::Inventory, 20),
+            },
+            ItemStackRequestAction::Drop {
+                count: 1,
+                source: hotbar.clone(),
+                randomly: true,
+            },
+            ItemStackRequestAction::Destroy {
+                count: 64,
+                source: hotbar.clone(),
+            },
+            ItemStackRequestAction::Consume {
+                count: 1,
+                source: hotbar.clone(),
+            },
+            ItemStackRequestAction::Create { results_slot: 0 },
+            ItemStackRequestAction::PlaceInContainer {
+                count: 1,
+                source: cursor.clone(),
+                destination: hotbar.clone(),
+            },
+            ItemStackRequestAction::TakeOutContainer {
+                count: 1,
+                source: hotbar.clone(),
+                destination: cursor.clone(),
+            },
+            ItemStackRequestAction::LabTableCombine,
+            ItemStackRequestAction::BeaconPayment {
+                primary_effect: VAR::new(1),
+                secondary_effect: VAR::new(10),
+            },
+            ItemStackRequestAction::MineBlock {
+                hotbar_slot: VAR::new(2),
+                predicted_durability: VAR::new(130),
+                stack_network_id: VAR::new(17),
+            },
+            ItemStackRequestAction::CraftRecipe {
+                recipe_network_id: VAR::new(42),
+                number_of_crafts: 1,
+            },
+            ItemStackRequestAction::CraftRecipeAuto {
+                recipe_network_id: VAR::new(42),
+                number_of_crafts: 2,
+                times_crafted: 2,
+                ingredients: vec![
+                    RecipeIngredient::item(5, 0x7fff, 1),
+                    RecipeIngredient::tag("minecraft:logs", 1),
+                    RecipeIngredient::empty(),
+                ],
+            },
+            ItemStackRequestAction::CraftCreative {
+                creative_item_network_id: VAR::new(300),
+                number_of_crafts: 1,
+            },
+            ItemStackRequestAction::CraftRecipeOptional {
+                recipe_network_id: VAR::new(43),
+                filter_string_index: LE::new(0),
+            },
+            ItemStackRequestAction::CraftGrindstone {
+                recipe_network_id: VAR::new(44),
+                number_of_crafts: 1,
+                cost: VAR::new(-5),
+            },
+            ItemStackRequestAction::CraftLoom {
+                pattern: String::from("bri"),
+                times_crafted: 1,
+            },
+            ItemStackRequestAction::CraftNonImplemented,
+            ItemStackRequestAction::CraftResultsDeprecated {
+                result_items: vec![
+                    NetworkItemInstanceDescriptor {
+                        id: VAR::new(5),
+                        stack_size: LE::new(4),
+                        aux_value: VAR::new(0),
+                        block_runtime_id: VAR::new(1234),
+                        user_data_buffer: vec![1, 2, 3],
+                    },
+                    NetworkItemInstanceDescriptor::air(),
+                ],
+                times_crafted: 1,
+            },
+        ]
+    }
+
+    #[test]
+    fn every_action_round_trips() {
+        let packet = ItemStackRequestPacket {
+            requests: vec![
+                ItemStackRequest {
+                    request_id: VAR::new(-1),
+                    actions: actions(),
+                    filter_strings: vec![String::from("Renamed")],
+                    filter_cause: LE::new(0),
+                },
+                ItemStackRequest {
+                    request_id: VAR::new(-3),
+                    actions: vec![],
+                    filter_strings: vec![],
+                    filter_cause: LE::new(0),
+                },
+            ],
+        };
+
+        let mut stream = vec![];
+        packet.proto_serialize(&mut stream).unwrap();
+
+        let mut cursor = Cursor::new(stream.as_slice());
+        let decoded = ItemStackRequestPacket::proto_deserialize(&mut cursor).unwrap();
+        assert_eq!(cursor.position() as usize, stream.len());
+
+        // The actions don't implement PartialEq, so they are compared by their encoding
+        let mut encoded = vec![];
+        decoded.proto_serialize(&mut encoded).unwrap();
+        assert_eq!(encoded, stream);
+
+        assert_eq!(decoded.requests.len(), 2);
+        assert_eq!(decoded.requests[0].request_id.into_inner(), -1);
+        assert_eq!(decoded.requests[0].actions.len(), 20);
+        assert_eq!(decoded.requests[0].filter_strings, ["Renamed"]);
+        assert!(matches!(
+            &decoded.requests[0].actions[0],
+            ItemStackRequestAction::Take {
+                count: 3,
+                source: ItemStackRequestSlotInfo {
+                    container: ContainerName::Hotbar,
+                    slot: 2,
+                    ..
+                },
+                ..
+            }
+        ));
+        assert!(matches!(
+            &decoded.requests[0].actions[19],
+            ItemStackRequestAction::CraftResultsDeprecated { result_items, times_crafted: 1 }
+                if result_items.len() == 2 && result_items[0].user_data_buffer == [1, 2, 3]
+        ));
+        assert!(decoded.requests[1].actions.is_empty());
+    }
+
+    #[test]
+    fn unknown_actions_are_rejected() {
+        let stream = [20];
+
+        let mut cursor = Cursor::new(stream.as_slice());
+        assert!(ItemStackRequestAction::proto_deserialize(&mut cursor).is_err());
+    }
+}
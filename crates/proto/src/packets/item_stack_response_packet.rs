@@ -0,0 +1,94 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::item_stack_response::ItemStackResponse;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ItemStackResponsePacket {
+    #[len_repr(VAR::<u32>)]
+    pub responses: Vec<ItemStackResponse>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use bedrockrs_proto_core::ProtoCodec;
+
+    use crate::types::container_name::ContainerName;
+    use crate::types::item_stack_response::{
+        ItemStackResponseContainerInfo, ItemStackResponseResult, ItemStackResponseSlotInfo,
+    };
+
+    use super::*;
+
+    fn round_trip(packet: &ItemStackResponsePacket) -> ItemStackResponsePacket {
+        let mut stream = vec![];
+        packet.proto_serialize(&mut stream).unwrap();
+
+        let mut cursor = Cursor::new(stream.as_slice());
+        let decoded = ItemStackResponsePacket::proto_deserialize(&mut cursor).unwrap();
+        assert_eq!(cursor.position() as usize, stream.len());
+
+        decoded
+    }
+
+    #[test]
+    fn accepted_and_rejected_responses_round_trip() {
+        let slot = ItemStackResponseSlotInfo {
+            slot: 2,
+            hotbar_slot: 2,
+            count: 61,
+            stack_network_id: VAR::new(17),
+            custom_name: String::from("Sword"),
+            filtered_custom_name: String::from("S***d"),
+            durability_correction: VAR::new(-1),
+        };
+        let mut rejected = ItemStackResponse::error(-3);
+        // Only accepted responses send their containers
+        rejected.containers = vec![ItemStackResponseContainerInfo {
+            container: ContainerName::Hotbar,
+            slots: vec![slot.clone()],
+        }];
+
+        let decoded = round_trip(&ItemStackResponsePacket {
+            responses: vec![
+                ItemStackResponse::ok(
+                    -1,
+                    vec![
+                        ItemStackResponseContainerInfo {
+                            container: ContainerName::Hotbar,
+                            slots: vec![slot],
+                        },
+                        ItemStackResponseContainerInfo {
+                            container: ContainerName::Cursor,
+                            slots: vec![],
+                        },
+                    ],
+                ),
+                rejected,
+            ],
+        });
+
+        let [accepted, rejected] = &decoded.responses[..] else {
+            panic!("expected two responses, got {:?}", decoded.responses);
+        };
+
+        assert_eq!(accepted.result, ItemStackResponseResult::Ok);
+        assert_eq!(accepted.request_id.into_inner(), -1);
+        assert_eq!(accepted.containers.len(), 2);
+        assert_eq!(accepted.containers[0].container, ContainerName::Hotbar);
+        let slot = &accepted.containers[0].slots[0];
+        assert_eq!((slot.slot, slot.hotbar_slot, slot.count), (2, 2, 61));
+        assert_eq!(slot.stack_network_id.into_inner(), 17);
+        assert_eq!(slot.custom_name, "Sword");
+        assert_eq!(slot.filtered_custom_name, "S***d");
+        assert_eq!(slot.durability_correction.into_inner(), -1);
+        assert_eq!(accepted.containers[1].container, ContainerName::Cursor);
+        assert!(accepted.containers[1].slots.is_empty());
+
+        assert_eq!(rejected.result, ItemStackResponseResult::Error);
+        assert_eq!(rejected.request_id.into_inner(), -3);
+        assert!(rejected.containers.is_empty());
+    }
+}
@@ -1,43 +1,59 @@
+use std::io::{Cursor, Read};
+use std::sync::Arc;
+
 use crate::types::chunk_pos::ChunkPos;
 use bedrockrs_core::int::{LE, VAR};
 use bedrockrs_proto_core::error::ProtoCodecError;
 use bedrockrs_proto_core::ProtoCodec;
 
+/// Sent instead of the sub chunk count if the client has to request all sub chunks
+const REQUEST_UNLIMITED: u32 = u32::MAX;
+/// Sent instead of the sub chunk count if the client has to request the sub chunks
+/// up to the highest sub chunk, which follows as a `u16`
+const REQUEST_LIMITED: u32 = u32::MAX - 1;
+
 #[derive(Debug, Clone)]
 pub struct LevelChunkPacket {
     pub chunk_position: ChunkPos,
     pub dimension_id: VAR<i32>,
     pub sub_chunk_count: VAR<u32>,
     pub cache_enabled: bool,
+    /// The ids of the sub chunk blobs followed by the id of the biome blob,
+    /// only sent if the blob cache is enabled
+    pub blob_ids: Vec<LE<u64>>,
+    /// The chunk data, without the parts sent as blobs if the blob cache is enabled
     pub serialized_chunk_data: Vec<u8>,
 
     pub client_needs_to_request_subchunks: bool,
+    /// The highest sub chunk the client has to request, `-1` if the client has to request all sub chunks
     pub client_request_subchunk_limit: VAR<i32>,
 }
 
 impl ProtoCodec for LevelChunkPacket {
-    fn proto_serialize(
-        &self,
-        stream: &mut Vec<u8>,
-    ) -> Result<(), bedrockrs_proto_core::error::ProtoCodecError> {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
         self.chunk_position.proto_serialize(stream)?;
         self.dimension_id.proto_serialize(stream)?;
 
         if !self.client_needs_to_request_subchunks {
             self.sub_chunk_count.proto_serialize(stream)?;
         } else {
-            if !(self.client_request_subchunk_limit.into_inner() < 0) {
-                VAR::<u32>::new(u32::MAX - 1).proto_serialize(stream)?;
-                self.client_request_subchunk_limit.proto_serialize(stream)?;
+            let limit = self.client_request_subchunk_limit.into_inner();
+
+            if limit >= 0 {
+                VAR::<u32>::new(REQUEST_LIMITED).proto_serialize(stream)?;
+                LE::<u16>::new(limit.try_into()?).proto_serialize(stream)?;
             } else {
-                VAR::<u32>::new(u32::MAX).proto_serialize(stream)?;
+                VAR::<u32>::new(REQUEST_UNLIMITED).proto_serialize(stream)?;
             }
         }
 
         self.cache_enabled.proto_serialize(stream)?;
         if self.cache_enabled {
-            // todo: implement sending with cached blobs.
-            unimplemented!()
+            VAR::<u32>::new(self.blob_ids.len().try_into()?).proto_serialize(stream)?;
+
+            for blob_id in &self.blob_ids {
+                blob_id.proto_serialize(stream)?;
+            }
         }
 
         let len = self
@@ -50,14 +66,134 @@ impl ProtoCodec for LevelChunkPacket {
 
         stream.extend_from_slice(&self.serialized_chunk_data);
 
-        println!("finish");
-
         Ok(())
     }
 
-    fn proto_deserialize(
-        stream: &mut std::io::Cursor<&[u8]>,
-    ) -> Result<Self, bedrockrs_proto_core::error::ProtoCodecError> {
-        unreachable!()
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let chunk_position = ChunkPos::proto_deserialize(stream)?;
+        let dimension_id = VAR::<i32>::proto_deserialize(stream)?;
+
+        let (sub_chunk_count, client_needs_to_request_subchunks, client_request_subchunk_limit) =
+            match VAR::<u32>::proto_deserialize(stream)?.into_inner() {
+                REQUEST_UNLIMITED => (VAR::new(0), true, VAR::new(-1)),
+                REQUEST_LIMITED => {
+                    let limit = LE::<u16>::proto_deserialize(stream)?.into_inner();
+                    (VAR::new(0), true, VAR::new(limit as i32))
+                }
+                count => (VAR::new(count), false, VAR::new(-1)),
+            };
+
+        let cache_enabled = bool::proto_deserialize(stream)?;
+
+        let mut blob_ids = vec![];
+        if cache_enabled {
+            let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+
+            for _ in 0..len {
+                blob_ids.push(LE::<u64>::proto_deserialize(stream)?);
+            }
+        }
+
+        let len: usize = VAR::<u32>::proto_deserialize(stream)?
+            .into_inner()
+            .try_into()?;
+
+        let remaining = stream
+            .get_ref()
+            .len()
+            .saturating_sub(stream.position() as usize);
+        if len > remaining {
+            return Err(ProtoCodecError::FormatMismatch(format!(
+                "Serialized chunk data is {len} bytes long, but only {remaining} bytes are left"
+            )));
+        }
+
+        let mut serialized_chunk_data = vec![0; len];
+        stream
+            .read_exact(&mut serialized_chunk_data)
+            .map_err(|e| ProtoCodecError::IOError(Arc::new(e)))?;
+
+        Ok(Self {
+            chunk_position,
+            dimension_id,
+            sub_chunk_count,
+            cache_enabled,
+            blob_ids,
+            serialized_chunk_data,
+            client_needs_to_request_subchunks,
+            client_request_subchunk_limit,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level_chunk(sub_chunk_count: u32, request_limit: Option<i32>) -> LevelChunkPacket {
+        LevelChunkPacket {
+            chunk_position: ChunkPos::new(3, -7),
+            dimension_id: VAR::new(0),
+            sub_chunk_count: VAR::new(sub_chunk_count),
+            cache_enabled: false,
+            blob_ids: vec![],
+            serialized_chunk_data: vec![0],
+            client_needs_to_request_subchunks: request_limit.is_some(),
+            client_request_subchunk_limit: VAR::new(request_limit.unwrap_or(-1)),
+        }
+    }
+
+    fn round_trip(packet: &LevelChunkPacket) -> LevelChunkPacket {
+        let mut stream = vec![];
+        packet.proto_serialize(&mut stream).unwrap();
+
+        let mut cursor = Cursor::new(stream.as_slice());
+        let decoded = LevelChunkPacket::proto_deserialize(&mut cursor).unwrap();
+        assert_eq!(cursor.position() as usize, stream.len());
+
+        decoded
+    }
+
+    #[test]
+    fn level_chunk_with_sub_chunk_count_round_trips() {
+        let decoded = round_trip(&level_chunk(5, None));
+
+        assert_eq!(decoded.chunk_position.x.into_inner(), 3);
+        assert_eq!(decoded.chunk_position.z.into_inner(), -7);
+        assert_eq!(decoded.sub_chunk_count.into_inner(), 5);
+        assert!(!decoded.client_needs_to_request_subchunks);
+        assert_eq!(decoded.serialized_chunk_data, [0]);
+    }
+
+    #[test]
+    fn level_chunk_requesting_all_sub_chunks_round_trips() {
+        let decoded = round_trip(&level_chunk(0, Some(-1)));
+
+        assert_eq!(decoded.sub_chunk_count.into_inner(), 0);
+        assert!(decoded.client_needs_to_request_subchunks);
+        assert_eq!(decoded.client_request_subchunk_limit.into_inner(), -1);
+        assert_eq!(decoded.serialized_chunk_data, [0]);
+    }
+
+    #[test]
+    fn level_chunk_requesting_limited_sub_chunks_round_trips() {
+        let mut packet = level_chunk(0, Some(12));
+        packet.cache_enabled = true;
+        packet.blob_ids = vec![LE::new(1), LE::new(u64::MAX)];
+
+        let decoded = round_trip(&packet);
+
+        assert!(decoded.client_needs_to_request_subchunks);
+        assert_eq!(decoded.client_request_subchunk_limit.into_inner(), 12);
+        assert!(decoded.cache_enabled);
+        assert_eq!(
+            decoded
+                .blob_ids
+                .iter()
+                .map(|id| id.into_inner())
+                .collect::<Vec<_>>(),
+            [1, u64::MAX]
+        );
+        assert_eq!(decoded.serialized_chunk_data, [0]);
     }
 }
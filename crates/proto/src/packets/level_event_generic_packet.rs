@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_nbt as nbt;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::types::level_event::LevelEvent;
+
+/// A level event with its data given as NBT, used for events that need more than a single number.
+#[derive(Debug, Clone)]
+pub struct LevelEventGenericPacket {
+    pub event_id: VAR<i32>,
+    pub event_data: HashMap<String, nbt::Value>,
+}
+
+impl LevelEventGenericPacket {
+    pub fn new(event: LevelEvent, event_data: HashMap<String, nbt::Value>) -> Self {
+        Self {
+            event_id: event.into(),
+            event_data,
+        }
+    }
+
+    /// The event, [`LevelEvent::Unknown`] for events without a variant.
+    pub fn event(&self) -> LevelEvent {
+        LevelEvent::from(self.event_id.into_inner())
+    }
+}
+
+impl ProtoCodec for LevelEventGenericPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.event_id.proto_serialize(stream)?;
+        self.event_data.proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let event_id = VAR::<i32>::proto_deserialize(stream)?;
+
+        let event_data = HashMap::proto_deserialize(stream)?;
+
+        Ok(Self {
+            event_id,
+            event_data,
+        })
+    }
+}
@@ -0,0 +1,69 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::level_event::LevelEvent;
+
+/// Plays a sound, shows particles or changes the weather, depending on the event.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct LevelEventPacket {
+    pub event_id: VAR<i32>,
+    pub position: Vec3<LE<f32>>,
+    pub data: VAR<i32>,
+}
+
+impl LevelEventPacket {
+    pub fn new(event: LevelEvent, position: Vec3<f32>, data: i32) -> Self {
+        Self {
+            event_id: event.into(),
+            position: Vec3 {
+                x: LE::new(position.x),
+                y: LE::new(position.y),
+                z: LE::new(position.z),
+            },
+            data: VAR::new(data),
+        }
+    }
+
+    /// Shows a particle by the id of its type.
+    pub fn particle(particle_id: i32, position: Vec3<f32>, data: i32) -> Self {
+        Self {
+            event_id: VAR::new(LevelEvent::ADD_PARTICLE_MASK | particle_id),
+            position: Vec3 {
+                x: LE::new(position.x),
+                y: LE::new(position.y),
+                z: LE::new(position.z),
+            },
+            data: VAR::new(data),
+        }
+    }
+
+    /// Starts rain at full strength, or stops it.
+    pub fn rain(raining: bool) -> Self {
+        match raining {
+            true => Self::new(LevelEvent::StartRaining, Self::origin(), 65535),
+            false => Self::new(LevelEvent::StopRaining, Self::origin(), 0),
+        }
+    }
+
+    /// Starts a thunderstorm at full strength, or stops it.
+    pub fn thunder(thundering: bool) -> Self {
+        match thundering {
+            true => Self::new(LevelEvent::StartThunderstorm, Self::origin(), 65535),
+            false => Self::new(LevelEvent::StopThunderstorm, Self::origin(), 0),
+        }
+    }
+
+    /// The event, [`LevelEvent::Unknown`] for particles and events without a variant.
+    pub fn event(&self) -> LevelEvent {
+        LevelEvent::from(self.event_id.into_inner())
+    }
+
+    fn origin() -> Vec3<f32> {
+        Vec3 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::level_sound_event::LevelSoundEvent;
+
+/// Plays a sound of a block or actor, sent by clients for their own sounds like steps.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct LevelSoundEventPacket {
+    pub event_id: VAR<u32>,
+    pub position: Vec3<LE<f32>>,
+    /// Extra data of the event, the block runtime id for block sounds like
+    /// [`LevelSoundEvent::Place`] or the note of [`LevelSoundEvent::Note`]
+    pub data: VAR<i32>,
+    /// The identifier of the actor the sound belongs to, like `minecraft:cow`
+    pub actor_identifier: String,
+    pub is_baby_mob: bool,
+    /// Plays the sound for everyone regardless of the distance, like thunder
+    pub is_global: bool,
+}
+
+impl LevelSoundEventPacket {
+    pub fn new(event: LevelSoundEvent, position: Vec3<f32>) -> Self {
+        Self {
+            event_id: event.into(),
+            position: Vec3 {
+                x: LE::new(position.x),
+                y: LE::new(position.y),
+                z: LE::new(position.z),
+            },
+            data: VAR::new(-1),
+            actor_identifier: String::new(),
+            is_baby_mob: false,
+            is_global: false,
+        }
+    }
+
+    /// The event, [`LevelSoundEvent::Unknown`] for events without a variant.
+    pub fn event(&self) -> LevelSoundEvent {
+        LevelSoundEvent::from(self.event_id.into_inner())
+    }
+}
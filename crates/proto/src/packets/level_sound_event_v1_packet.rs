@@ -0,0 +1,14 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// The oldest version of the `LevelSoundEventPacket`, the actor is given by its legacy type id.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct LevelSoundEventV1Packet {
+    pub event_id: u8,
+    pub position: Vec3<LE<f32>>,
+    pub data: VAR<i32>,
+    pub actor_type: VAR<i32>,
+    pub is_baby_mob: bool,
+    pub is_global: bool,
+}
@@ -0,0 +1,14 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// An older version of the `LevelSoundEventPacket` with a single byte event id.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct LevelSoundEventV2Packet {
+    pub event_id: u8,
+    pub position: Vec3<LE<f32>>,
+    pub data: VAR<i32>,
+    pub actor_identifier: String,
+    pub is_baby_mob: bool,
+    pub is_global: bool,
+}
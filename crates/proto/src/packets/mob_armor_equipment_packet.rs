@@ -0,0 +1,14 @@
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+use crate::types::network_item_stack_descriptor::NetworkItemStackDescriptor;
+
+/// Shows the armor worn by an actor to other players.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct MobArmorEquipmentPacket {
+    pub runtime_id: ActorRuntimeID,
+    pub helmet: NetworkItemStackDescriptor,
+    pub chestplate: NetworkItemStackDescriptor,
+    pub leggings: NetworkItemStackDescriptor,
+    pub boots: NetworkItemStackDescriptor,
+}
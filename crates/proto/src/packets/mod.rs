@@ -1,43 +1,86 @@
 pub mod add_actor_packet;
+pub mod add_item_entity_packet;
 pub mod add_painting_packet;
+pub mod add_player_packet;
 pub mod animate;
+pub mod available_commands_packet;
+pub mod block_entity_data_packet;
+pub mod block_event_packet;
+pub mod boss_event_packet;
 pub mod camera_packet;
 pub mod chunk_radius_updated;
+pub mod client_cache_blob_status_packet;
+pub mod client_cache_miss_response_packet;
 pub mod client_cache_status;
+pub mod command_output_packet;
 pub mod command_request_packet;
 pub mod container_close_packet;
 pub mod container_open_packet;
 pub mod correct_player_move_prediction_packet;
+pub mod crafting_data_packet;
 pub mod debug_info_packet;
 pub mod disconnect;
 pub mod emote_list;
+pub mod entity_event_packet;
 pub mod handshake_server_to_client;
 pub mod interact;
 pub mod inventory_content_packet;
+pub mod inventory_slot_packet;
+pub mod inventory_transaction_packet;
+pub mod item_stack_request_packet;
+pub mod item_stack_response_packet;
 pub mod level_chunk;
+pub mod level_event_generic_packet;
+pub mod level_event_packet;
+pub mod level_sound_event_packet;
+pub mod level_sound_event_v1_packet;
+pub mod level_sound_event_v2_packet;
 pub mod login;
+pub mod mob_armor_equipment_packet;
 pub mod mob_equipment_packet;
 pub mod modal_form_request;
 pub mod modal_form_response;
+pub mod move_entity_delta_packet;
+pub mod move_entity_packet;
+pub mod network_chunk_publisher_update;
 pub mod network_settings;
 pub mod network_settings_request;
 pub mod packet_violation_warning;
+pub mod play_sound_packet;
 pub mod play_status;
 pub mod player_action;
 pub mod player_auth_input;
 pub mod player_hotbar_packet;
 pub mod player_move;
 pub mod remove_actor_packet;
+pub mod remove_objective_packet;
 pub mod request_chunk_radius;
+pub mod resource_pack_chunk_data;
+pub mod resource_pack_chunk_request;
+pub mod resource_pack_data_info;
 pub mod resource_packs_info;
 pub mod resource_packs_response;
 pub mod resource_packs_stack;
 pub mod server_player_post_move_position_packet;
 pub mod server_settings_request;
 pub mod server_settings_response;
+pub mod set_display_objective_packet;
+pub mod set_entity_data_packet;
+pub mod set_entity_link_packet;
+pub mod set_entity_motion_packet;
 pub mod set_local_player_as_initialized;
+pub mod set_score_packet;
+pub mod set_scoreboard_identity_packet;
 pub mod set_time_packet;
 pub mod set_title_packet;
+pub mod spawn_particle_effect_packet;
 pub mod start_game;
+pub mod stop_sound_packet;
+pub mod sub_chunk_packet;
+pub mod sub_chunk_request_packet;
+pub mod take_item_entity_packet;
 pub mod text_message;
 pub mod toast_request_packet;
+pub mod update_block_packet;
+pub mod update_block_synced_packet;
+pub mod update_sub_chunk_blocks_packet;
@@ -0,0 +1,108 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::LE;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+use crate::packets::move_entity_packet::{angle_to_byte, byte_to_angle};
+
+/// Moves an actor, only the coordinates and rotations that changed are sent.
+#[derive(Debug, Clone)]
+pub struct MoveEntityDeltaPacket {
+    pub target_runtime_id: ActorRuntimeID,
+    pub on_ground: bool,
+    /// Teleports the actor instead of interpolating the movement
+    pub teleport: bool,
+    pub force_move: bool,
+    pub x: Option<f32>,
+    pub y: Option<f32>,
+    pub z: Option<f32>,
+    /// The rotations are in degrees, they get sent with a precision of 360/256 degrees
+    pub pitch: Option<f32>,
+    pub yaw: Option<f32>,
+    pub head_yaw: Option<f32>,
+}
+
+impl MoveEntityDeltaPacket {
+    const FLAG_HAS_X: u16 = 0x01;
+    const FLAG_HAS_Y: u16 = 0x02;
+    const FLAG_HAS_Z: u16 = 0x04;
+    const FLAG_HAS_PITCH: u16 = 0x08;
+    const FLAG_HAS_YAW: u16 = 0x10;
+    const FLAG_HAS_HEAD_YAW: u16 = 0x20;
+    const FLAG_ON_GROUND: u16 = 0x40;
+    const FLAG_TELEPORT: u16 = 0x80;
+    const FLAG_FORCE_MOVE: u16 = 0x100;
+}
+
+impl ProtoCodec for MoveEntityDeltaPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        let mut flags = 0;
+        for (set, flag) in [
+            (self.x.is_some(), Self::FLAG_HAS_X),
+            (self.y.is_some(), Self::FLAG_HAS_Y),
+            (self.z.is_some(), Self::FLAG_HAS_Z),
+            (self.pitch.is_some(), Self::FLAG_HAS_PITCH),
+            (self.yaw.is_some(), Self::FLAG_HAS_YAW),
+            (self.head_yaw.is_some(), Self::FLAG_HAS_HEAD_YAW),
+            (self.on_ground, Self::FLAG_ON_GROUND),
+            (self.teleport, Self::FLAG_TELEPORT),
+            (self.force_move, Self::FLAG_FORCE_MOVE),
+        ] {
+            if set {
+                flags |= flag;
+            }
+        }
+
+        self.target_runtime_id.proto_serialize(stream)?;
+        LE::<u16>::new(flags).proto_serialize(stream)?;
+
+        for coordinate in [self.x, self.y, self.z].into_iter().flatten() {
+            LE::<f32>::new(coordinate).proto_serialize(stream)?;
+        }
+        for rotation in [self.pitch, self.yaw, self.head_yaw].into_iter().flatten() {
+            angle_to_byte(rotation).proto_serialize(stream)?;
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let target_runtime_id = ActorRuntimeID::proto_deserialize(stream)?;
+        let flags = LE::<u16>::proto_deserialize(stream)?.into_inner();
+
+        let mut coordinate = |flag: u16| -> Result<Option<f32>, ProtoCodecError> {
+            match flags & flag != 0 {
+                true => Ok(Some(LE::<f32>::proto_deserialize(stream)?.into_inner())),
+                false => Ok(None),
+            }
+        };
+        let x = coordinate(Self::FLAG_HAS_X)?;
+        let y = coordinate(Self::FLAG_HAS_Y)?;
+        let z = coordinate(Self::FLAG_HAS_Z)?;
+
+        let mut rotation = |flag: u16| -> Result<Option<f32>, ProtoCodecError> {
+            match flags & flag != 0 {
+                true => Ok(Some(byte_to_angle(u8::proto_deserialize(stream)?))),
+                false => Ok(None),
+            }
+        };
+        let pitch = rotation(Self::FLAG_HAS_PITCH)?;
+        let yaw = rotation(Self::FLAG_HAS_YAW)?;
+        let head_yaw = rotation(Self::FLAG_HAS_HEAD_YAW)?;
+
+        Ok(Self {
+            target_runtime_id,
+            on_ground: flags & Self::FLAG_ON_GROUND != 0,
+            teleport: flags & Self::FLAG_TELEPORT != 0,
+            force_move: flags & Self::FLAG_FORCE_MOVE != 0,
+            x,
+            y,
+            z,
+            pitch,
+            yaw,
+            head_yaw,
+        })
+    }
+}
@@ -0,0 +1,78 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::LE;
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+/// Moves an actor to an absolute position.
+#[derive(Debug, Clone)]
+pub struct MoveEntityPacket {
+    pub target_runtime_id: ActorRuntimeID,
+    pub on_ground: bool,
+    /// Teleports the actor instead of interpolating the movement
+    pub teleport: bool,
+    pub force_move: bool,
+    pub position: Vec3<LE<f32>>,
+    /// The rotations are in degrees, they get sent with a precision of 360/256 degrees
+    pub pitch: f32,
+    pub yaw: f32,
+    pub head_yaw: f32,
+}
+
+impl MoveEntityPacket {
+    const FLAG_ON_GROUND: u8 = 0x01;
+    const FLAG_TELEPORT: u8 = 0x02;
+    const FLAG_FORCE_MOVE: u8 = 0x04;
+}
+
+impl ProtoCodec for MoveEntityPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        let mut flags = 0;
+        if self.on_ground {
+            flags |= Self::FLAG_ON_GROUND;
+        }
+        if self.teleport {
+            flags |= Self::FLAG_TELEPORT;
+        }
+        if self.force_move {
+            flags |= Self::FLAG_FORCE_MOVE;
+        }
+
+        self.target_runtime_id.proto_serialize(stream)?;
+        flags.proto_serialize(stream)?;
+        self.position.proto_serialize(stream)?;
+        angle_to_byte(self.pitch).proto_serialize(stream)?;
+        angle_to_byte(self.yaw).proto_serialize(stream)?;
+        angle_to_byte(self.head_yaw).proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let target_runtime_id = ActorRuntimeID::proto_deserialize(stream)?;
+        let flags = u8::proto_deserialize(stream)?;
+        let position = Vec3::<LE<f32>>::proto_deserialize(stream)?;
+
+        Ok(Self {
+            target_runtime_id,
+            on_ground: flags & Self::FLAG_ON_GROUND != 0,
+            teleport: flags & Self::FLAG_TELEPORT != 0,
+            force_move: flags & Self::FLAG_FORCE_MOVE != 0,
+            position,
+            pitch: byte_to_angle(u8::proto_deserialize(stream)?),
+            yaw: byte_to_angle(u8::proto_deserialize(stream)?),
+            head_yaw: byte_to_angle(u8::proto_deserialize(stream)?),
+        })
+    }
+}
+
+/// Compresses an angle in degrees into a single byte, wrapping around at 360 degrees.
+pub(crate) fn angle_to_byte(angle: f32) -> u8 {
+    (angle / (360.0 / 256.0)).round() as i32 as u8
+}
+
+pub(crate) fn byte_to_angle(byte: u8) -> f32 {
+    byte as f32 * (360.0 / 256.0)
+}
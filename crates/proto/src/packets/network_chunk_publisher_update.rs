@@ -0,0 +1,17 @@
+use bedrockrs_core::{
+    int::{LE, VAR},
+    Vec3,
+};
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::chunk_pos::ChunkPos;
+
+/// Tells the client around which position and in which radius (in blocks) chunks
+/// are going to be published, the client only renders chunks inside of it.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct NetworkChunkPublisherUpdatePacket {
+    pub position: Vec3<VAR<i32>>,
+    pub radius: VAR<u32>,
+    #[len_repr(LE::<u32>)]
+    pub saved_chunks: Vec<ChunkPos>,
+}
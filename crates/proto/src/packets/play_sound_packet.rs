@@ -0,0 +1,30 @@
+use bedrockrs_core::int::LE;
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::network_block_pos::NetworkBlockPos;
+
+/// Plays a sound by its name from the resource packs, like `random.levelup`.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct PlaySoundPacket {
+    pub name: String,
+    /// The position multiplied by 8
+    pub position: NetworkBlockPos,
+    pub volume: LE<f32>,
+    pub pitch: LE<f32>,
+}
+
+impl PlaySoundPacket {
+    pub fn new(name: impl Into<String>, position: Vec3<f32>, volume: f32, pitch: f32) -> Self {
+        Self {
+            name: name.into(),
+            position: NetworkBlockPos::new(
+                (position.x * 8.0) as i32,
+                (position.y * 8.0) as i32,
+                (position.z * 8.0) as i32,
+            ),
+            volume: LE::new(volume),
+            pitch: LE::new(pitch),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// Removes an objective from the client, clearing the display slots it was shown in.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct RemoveObjectivePacket {
+    pub objective_name: String,
+}
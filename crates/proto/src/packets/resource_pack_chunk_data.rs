@@ -0,0 +1,13 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_derive::ProtoCodec;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ResourcePackChunkDataPacket {
+    /// The pack's uuid and version seperated by a `_`
+    pub pack_id: String,
+    pub chunk_index: LE<u32>,
+    /// The offset of the chunk's data in the packs zip file
+    pub progress: LE<u64>,
+    #[len_repr(VAR::<u32>)]
+    pub data: Vec<u8>,
+}
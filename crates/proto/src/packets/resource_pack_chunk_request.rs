@@ -0,0 +1,9 @@
+use bedrockrs_core::int::LE;
+use bedrockrs_proto_derive::ProtoCodec;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ResourcePackChunkRequestPacket {
+    /// The pack's uuid and version seperated by a `_`
+    pub pack_id: String,
+    pub chunk_index: LE<u32>,
+}
@@ -0,0 +1,20 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::resource_pack_type::ResourcePackType;
+
+/// Announces a pack the client asked to download, the pack is then
+/// requested chunk by chunk with [`ResourcePackChunkRequestPacket`](crate::packets::resource_pack_chunk_request::ResourcePackChunkRequestPacket)s.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ResourcePackDataInfoPacket {
+    /// The pack's uuid and version seperated by a `_`
+    pub pack_id: String,
+    pub max_chunk_size: LE<u32>,
+    pub chunk_count: LE<u32>,
+    pub file_size: LE<u64>,
+    /// The SHA-256 hash of the packs zip file
+    #[len_repr(VAR::<u32>)]
+    pub file_hash: Vec<u8>,
+    pub is_premium: bool,
+    pub pack_type: ResourcePackType,
+}
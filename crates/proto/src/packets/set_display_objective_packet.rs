@@ -0,0 +1,15 @@
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::objective_sort_order::ObjectiveSortOrder;
+
+/// Shows an objective in a display slot, the objective is created if the client doesn't know it yet.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct SetDisplayObjectivePacket {
+    /// `sidebar`, `list` or `belowname`
+    pub display_slot: String,
+    pub objective_name: String,
+    pub display_name: String,
+    /// Always `dummy`
+    pub criteria_name: String,
+    pub sort_order: ObjectiveSortOrder,
+}
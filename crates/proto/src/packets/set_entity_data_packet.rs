@@ -0,0 +1,16 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+use crate::types::dataitem::DataItem;
+use crate::types::property_sync_data::PropertySyncData;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct SetEntityDataPacket {
+    pub target_runtime_id: ActorRuntimeID,
+    /// Only the data items that changed need to be sent
+    #[len_repr(VAR::<u32>)]
+    pub actor_data: Vec<DataItem>,
+    pub synched_properties: PropertySyncData,
+    pub tick: VAR<u64>,
+}
@@ -0,0 +1,8 @@
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::actor_link::ActorLink;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct SetEntityLinkPacket {
+    pub link: ActorLink,
+}
@@ -0,0 +1,15 @@
+use bedrockrs_core::{
+    int::{LE, VAR},
+    Vec3,
+};
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct SetEntityMotionPacket {
+    pub target_runtime_id: ActorRuntimeID,
+    pub motion: Vec3<LE<f32>>,
+    /// The server tick the motion was set at, `0` if the server doesn't use
+    /// server authoritative movement
+    pub tick: VAR<u64>,
+}
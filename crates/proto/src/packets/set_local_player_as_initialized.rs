@@ -3,5 +3,5 @@ use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
 
 #[derive(ProtoCodec, Debug, Clone)]
 pub struct SetLocalPlayerAsInitializedPacket {
-    player_id: ActorRuntimeID,
+    pub player_id: ActorRuntimeID,
 }
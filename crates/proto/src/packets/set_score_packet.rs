@@ -0,0 +1,65 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::types::score_entry::{ScoreAction, ScoreEntry, ScoreIdentity};
+
+#[derive(Debug, Clone)]
+pub struct SetScorePacket {
+    pub action: ScoreAction,
+    pub entries: Vec<ScoreEntry>,
+}
+
+impl ProtoCodec for SetScorePacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.action.proto_serialize(stream)?;
+
+        VAR::<u32>::new(self.entries.len().try_into()?).proto_serialize(stream)?;
+        for entry in &self.entries {
+            entry.scoreboard_id.proto_serialize(stream)?;
+            entry.objective_name.proto_serialize(stream)?;
+            entry.score.proto_serialize(stream)?;
+
+            if self.action == ScoreAction::Change {
+                match &entry.identity {
+                    Some(identity) => identity.proto_serialize(stream)?,
+                    None => {
+                        return Err(ProtoCodecError::FormatMismatch(String::from(
+                            "Changed scores need an identity",
+                        )))
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let action = ScoreAction::proto_deserialize(stream)?;
+
+        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        let mut entries = vec![];
+        for _ in 0..len {
+            let scoreboard_id = VAR::<i64>::proto_deserialize(stream)?;
+            let objective_name = String::proto_deserialize(stream)?;
+            let score = LE::<i32>::proto_deserialize(stream)?;
+
+            let identity = match action {
+                ScoreAction::Change => Some(ScoreIdentity::proto_deserialize(stream)?),
+                ScoreAction::Remove => None,
+            };
+
+            entries.push(ScoreEntry {
+                scoreboard_id,
+                objective_name,
+                score,
+                identity,
+            });
+        }
+
+        Ok(Self { action, entries })
+    }
+}
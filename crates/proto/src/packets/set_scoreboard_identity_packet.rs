@@ -0,0 +1,63 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+
+use crate::types::score_entry::{ScoreboardIdentityAction, ScoreboardIdentityEntry};
+
+/// Links scoreboard ids to players or clears them again.
+#[derive(Debug, Clone)]
+pub struct SetScoreboardIdentityPacket {
+    pub action: ScoreboardIdentityAction,
+    pub entries: Vec<ScoreboardIdentityEntry>,
+}
+
+impl ProtoCodec for SetScoreboardIdentityPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.action.proto_serialize(stream)?;
+
+        VAR::<u32>::new(self.entries.len().try_into()?).proto_serialize(stream)?;
+        for entry in &self.entries {
+            entry.scoreboard_id.proto_serialize(stream)?;
+
+            if self.action == ScoreboardIdentityAction::Register {
+                match &entry.player_unique_id {
+                    Some(unique_id) => unique_id.proto_serialize(stream)?,
+                    None => {
+                        return Err(ProtoCodecError::FormatMismatch(String::from(
+                            "Registered identities need a player unique id",
+                        )))
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let action = ScoreboardIdentityAction::proto_deserialize(stream)?;
+
+        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        let mut entries = vec![];
+        for _ in 0..len {
+            let scoreboard_id = VAR::<i64>::proto_deserialize(stream)?;
+
+            let player_unique_id = match action {
+                ScoreboardIdentityAction::Register => {
+                    Some(ActorUniqueID::proto_deserialize(stream)?)
+                }
+                ScoreboardIdentityAction::Clear => None,
+            };
+
+            entries.push(ScoreboardIdentityEntry {
+                scoreboard_id,
+                player_unique_id,
+            });
+        }
+
+        Ok(Self { action, entries })
+    }
+}
@@ -0,0 +1,32 @@
+use bedrockrs_core::int::LE;
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+
+/// Spawns a particle effect by its identifier, like `minecraft:heart_particle`.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct SpawnParticleEffectPacket {
+    pub dimension_id: u8,
+    /// The actor the particles are attached to, -1 for none
+    pub actor_unique_id: ActorUniqueID,
+    pub position: Vec3<LE<f32>>,
+    pub effect_name: String,
+    /// Molang variables as JSON
+    pub molang_variables: Option<String>,
+}
+
+impl SpawnParticleEffectPacket {
+    pub fn new(dimension_id: u8, effect_name: impl Into<String>, position: Vec3<f32>) -> Self {
+        Self {
+            dimension_id,
+            actor_unique_id: ActorUniqueID(-1),
+            position: Vec3 {
+                x: LE::new(position.x),
+                y: LE::new(position.y),
+                z: LE::new(position.z),
+            },
+            effect_name: effect_name.into(),
+            molang_variables: None,
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use bedrockrs_core::int::{LE, VAR};
 use bedrockrs_core::{Vec2, Vec3};
 use bedrockrs_nbt as nbt;
@@ -8,8 +10,12 @@ use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
 use bedrockrs_shared::actor_unique_id::ActorUniqueID;
 use bedrockrs_shared::world::gamemode::Gamemode;
 
+use crate::info::MINECRAFT_VERSION;
+use crate::types::block_property::BlockProperty;
+use crate::types::item_entry::ItemEntry;
 use crate::types::level_settings::LevelSettings;
 use crate::types::network_permissions::NetworkPermissions;
+use crate::types::player_movement_mode::PlayerMovementMode;
 use crate::types::player_movement_settings::PlayerMovementSettings;
 
 #[derive(ProtoCodec, Debug, Clone)]
@@ -27,13 +33,15 @@ pub struct StartGamePacket {
     pub movement_settings: PlayerMovementSettings,
     pub current_level_time: LE<u64>,
     pub enchantment_seed: VAR<i32>,
-    // TODO Add real value
+    /// Custom blocks the client should know about.
     #[len_repr(VAR::<u32>)]
-    pub block_properties: Vec<u8>,
-    // TODO Add real value
+    pub block_properties: Vec<BlockProperty>,
+    /// The item registry, maps item identifiers to their runtime IDs.
     #[len_repr(VAR::<u32>)]
-    pub items: Vec<u8>,
+    pub items: Vec<ItemEntry>,
     pub multiplayer_correlation_id: String,
+    /// Enables server authoritative inventories, the client then sends
+    /// `ItemStackRequest` packets which have to be answered with `ItemStackResponse` packets.
     pub enable_item_stack_net_manager: bool,
     pub server_version: String,
     // TODO: This can now be a concrete type rather than an NBT value.
@@ -45,3 +53,49 @@ pub struct StartGamePacket {
     pub use_block_network_id_hashes: bool,
     pub network_permission: NetworkPermissions,
 }
+
+impl Default for StartGamePacket {
+    /// A minimal survival world with the player spawning at `0, 64, 0`.
+    /// Meant to be adjusted by the login provider before it gets sent.
+    fn default() -> Self {
+        Self {
+            target_actor_id: ActorUniqueID(1),
+            target_runtime_id: ActorRuntimeID(1),
+            actor_game_type: Gamemode::Survival,
+            position: Vec3 {
+                x: LE::new(0.0),
+                y: LE::new(64.0),
+                z: LE::new(0.0),
+            },
+            rotation: Vec2 {
+                x: LE::new(0.0),
+                y: LE::new(0.0),
+            },
+            settings: LevelSettings::default(),
+            level_id: String::new(),
+            level_name: String::from("Bedrock level"),
+            template_content_identity: String::new(),
+            trial: false,
+            movement_settings: PlayerMovementSettings {
+                authority_mode: PlayerMovementMode::Client,
+                rewind_history_size: VAR::new(0),
+                server_authoritative_block_breaking: false,
+            },
+            current_level_time: LE::new(0),
+            enchantment_seed: VAR::new(0),
+            block_properties: vec![],
+            items: vec![],
+            multiplayer_correlation_id: Uuid::new_v4().to_string(),
+            enable_item_stack_net_manager: false,
+            server_version: String::from(MINECRAFT_VERSION),
+            player_property_data: nbt::Value::Compound(HashMap::new()),
+            block_type_registry_checksum: LE::new(0),
+            world_template_id: Uuid::nil(),
+            enable_clientside_world_generation: false,
+            use_block_network_id_hashes: false,
+            network_permission: NetworkPermissions {
+                server_auth_sound_enabled: false,
+            },
+        }
+    }
+}
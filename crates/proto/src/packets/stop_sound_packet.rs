@@ -0,0 +1,9 @@
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// Stops a sound played with a `PlaySoundPacket`.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct StopSoundPacket {
+    pub name: String,
+    /// Stops all sounds, the name is ignored
+    pub stop_all: bool,
+}
@@ -0,0 +1,140 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::types::sub_chunk_entry::SubChunkEntry;
+use crate::types::sub_chunk_pos::SubChunkPos;
+
+/// The answer to a `SubChunkRequestPacket`.
+#[derive(Debug, Clone)]
+pub struct SubChunkPacket {
+    /// If the entries are sent with blob ids for the client blob cache
+    pub cache_enabled: bool,
+    pub dimension_id: VAR<i32>,
+    pub center_position: SubChunkPos,
+    pub entries: Vec<SubChunkEntry>,
+}
+
+impl ProtoCodec for SubChunkPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.cache_enabled.proto_serialize(stream)?;
+        self.dimension_id.proto_serialize(stream)?;
+        self.center_position.proto_serialize(stream)?;
+
+        LE::<u32>::new(self.entries.len().try_into()?).proto_serialize(stream)?;
+        for entry in &self.entries {
+            entry.serialize(stream, self.cache_enabled)?;
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let cache_enabled = bool::proto_deserialize(stream)?;
+        let dimension_id = VAR::<i32>::proto_deserialize(stream)?;
+        let center_position = SubChunkPos::proto_deserialize(stream)?;
+
+        let len = LE::<u32>::proto_deserialize(stream)?.into_inner();
+        let mut entries = vec![];
+        for _ in 0..len {
+            entries.push(SubChunkEntry::deserialize(stream, cache_enabled)?);
+        }
+
+        Ok(Self {
+            cache_enabled,
+            dimension_id,
+            center_position,
+            entries,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::sub_chunk_entry::{HeightMapType, SubChunkOffset, SubChunkRequestResult};
+
+    fn packet(cache_enabled: bool) -> SubChunkPacket {
+        let offset = |y| SubChunkOffset { x: 1, y, z: -1 };
+
+        SubChunkPacket {
+            cache_enabled,
+            dimension_id: VAR::new(1),
+            center_position: SubChunkPos::new(4, -2, 9),
+            entries: vec![
+                SubChunkEntry {
+                    offset: offset(0),
+                    result: SubChunkRequestResult::Success,
+                    payload: vec![8, 0, 1],
+                    heightmap_type: HeightMapType::HasData,
+                    heightmap: (0..256).map(|i| (i % 16) as i8).collect(),
+                    blob_id: LE::new(if cache_enabled { 42 } else { 0 }),
+                },
+                SubChunkEntry {
+                    offset: offset(1),
+                    result: SubChunkRequestResult::SuccessAllAir,
+                    payload: vec![],
+                    heightmap_type: HeightMapType::TooLow,
+                    heightmap: vec![],
+                    blob_id: LE::new(0),
+                },
+                SubChunkEntry::empty(offset(2), SubChunkRequestResult::YIndexOutOfBounds),
+            ],
+        }
+    }
+
+    fn round_trip(packet: &SubChunkPacket) -> SubChunkPacket {
+        let mut stream = vec![];
+        packet.proto_serialize(&mut stream).unwrap();
+
+        let mut cursor = Cursor::new(stream.as_slice());
+        let decoded = SubChunkPacket::proto_deserialize(&mut cursor).unwrap();
+        assert_eq!(cursor.position() as usize, stream.len());
+
+        decoded
+    }
+
+    fn assert_same_entries(decoded: &SubChunkPacket, packet: &SubChunkPacket) {
+        assert_eq!(decoded.cache_enabled, packet.cache_enabled);
+        assert_eq!(decoded.dimension_id.into_inner(), 1);
+        assert_eq!(decoded.center_position.y.into_inner(), -2);
+        assert_eq!(decoded.entries.len(), packet.entries.len());
+
+        for (decoded, entry) in decoded.entries.iter().zip(&packet.entries) {
+            assert_eq!(decoded.offset, entry.offset);
+            assert_eq!(decoded.result, entry.result);
+            assert_eq!(decoded.payload, entry.payload);
+            assert_eq!(decoded.heightmap_type, entry.heightmap_type);
+            assert_eq!(decoded.heightmap, entry.heightmap);
+            assert_eq!(decoded.blob_id.into_inner(), entry.blob_id.into_inner());
+        }
+    }
+
+    #[test]
+    fn round_trips_without_the_cache() {
+        let packet = packet(false);
+        assert_same_entries(&round_trip(&packet), &packet);
+    }
+
+    #[test]
+    fn round_trips_with_the_cache() {
+        let packet = packet(true);
+        assert_same_entries(&round_trip(&packet), &packet);
+    }
+
+    #[test]
+    fn payloads_longer_than_the_stream_are_rejected() {
+        let mut stream = vec![];
+        packet(false).proto_serialize(&mut stream).unwrap();
+        // Cache flag, dimension, center position and entry count
+        let header_len = 1 + 1 + 3 + 4;
+        // Turn the payload length of the first entry, after its offset and result, into 1151
+        stream[header_len + 4] = 0xFF;
+        stream.truncate(header_len + 8);
+
+        let result = SubChunkPacket::proto_deserialize(&mut Cursor::new(stream.as_slice()));
+        assert!(matches!(result, Err(ProtoCodecError::FormatMismatch(_))));
+    }
+}
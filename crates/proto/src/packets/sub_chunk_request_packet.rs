@@ -0,0 +1,78 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::types::sub_chunk_entry::SubChunkOffset;
+use crate::types::sub_chunk_pos::SubChunkPos;
+
+/// Sent by clients to request the sub chunks of a chunk sent with
+/// `client_needs_to_request_subchunks`.
+#[derive(Debug, Clone)]
+pub struct SubChunkRequestPacket {
+    pub dimension_id: VAR<i32>,
+    pub center_position: SubChunkPos,
+    /// The requested sub chunks relative to the center position
+    pub offsets: Vec<SubChunkOffset>,
+}
+
+impl ProtoCodec for SubChunkRequestPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.dimension_id.proto_serialize(stream)?;
+        self.center_position.proto_serialize(stream)?;
+
+        LE::<u32>::new(self.offsets.len().try_into()?).proto_serialize(stream)?;
+        for offset in &self.offsets {
+            offset.proto_serialize(stream)?;
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let dimension_id = VAR::<i32>::proto_deserialize(stream)?;
+        let center_position = SubChunkPos::proto_deserialize(stream)?;
+
+        let len = LE::<u32>::proto_deserialize(stream)?.into_inner();
+        let mut offsets = vec![];
+        for _ in 0..len {
+            offsets.push(SubChunkOffset::proto_deserialize(stream)?);
+        }
+
+        Ok(Self {
+            dimension_id,
+            center_position,
+            offsets,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        let packet = SubChunkRequestPacket {
+            dimension_id: VAR::new(2),
+            center_position: SubChunkPos::new(-3, 0, 7),
+            offsets: vec![
+                SubChunkOffset { x: 0, y: -4, z: 0 },
+                SubChunkOffset { x: 1, y: 19, z: -1 },
+            ],
+        };
+
+        let mut stream = vec![];
+        packet.proto_serialize(&mut stream).unwrap();
+
+        let mut cursor = Cursor::new(stream.as_slice());
+        let decoded = SubChunkRequestPacket::proto_deserialize(&mut cursor).unwrap();
+
+        assert_eq!(cursor.position() as usize, stream.len());
+        assert_eq!(decoded.dimension_id.into_inner(), 2);
+        assert_eq!(decoded.center_position.x.into_inner(), -3);
+        assert_eq!(decoded.center_position.z.into_inner(), 7);
+        assert_eq!(decoded.offsets, packet.offsets);
+    }
+}
@@ -0,0 +1,9 @@
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+/// Plays the animation of an item actor flying towards the actor that picked it up.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct TakeItemEntityPacket {
+    pub item_runtime_id: ActorRuntimeID,
+    pub actor_runtime_id: ActorRuntimeID,
+}
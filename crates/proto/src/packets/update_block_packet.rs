@@ -0,0 +1,37 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::block_layer::BlockLayer;
+use crate::types::block_update_flags::BlockUpdateFlags;
+use crate::types::network_block_pos::NetworkBlockPos;
+
+/// Changes a single block in the world of the client.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct UpdateBlockPacket {
+    pub position: NetworkBlockPos,
+    pub block_runtime_id: VAR<u32>,
+    pub flags: BlockUpdateFlags,
+    pub layer: BlockLayer,
+}
+
+impl UpdateBlockPacket {
+    /// Changes the block on the standard layer with the flags the vanilla server usually uses.
+    pub fn new(position: NetworkBlockPos, block_runtime_id: u32) -> Self {
+        Self {
+            position,
+            block_runtime_id: VAR::new(block_runtime_id),
+            flags: BlockUpdateFlags::NETWORK,
+            layer: BlockLayer::Standard,
+        }
+    }
+
+    pub fn with_flags(mut self, flags: BlockUpdateFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    pub fn with_layer(mut self, layer: BlockLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+}
@@ -0,0 +1,19 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+
+use crate::types::actor_block_sync_message::ActorBlockSyncMessage;
+use crate::types::block_layer::BlockLayer;
+use crate::types::block_update_flags::BlockUpdateFlags;
+use crate::types::network_block_pos::NetworkBlockPos;
+
+/// Changes a block together with an actor, like a falling block landing.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct UpdateBlockSyncedPacket {
+    pub position: NetworkBlockPos,
+    pub block_runtime_id: VAR<u32>,
+    pub flags: BlockUpdateFlags,
+    pub layer: BlockLayer,
+    pub actor_unique_id: ActorUniqueID,
+    pub message: ActorBlockSyncMessage,
+}
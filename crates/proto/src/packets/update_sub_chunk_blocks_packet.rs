@@ -0,0 +1,17 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::block_change_entry::BlockChangeEntry;
+use crate::types::sub_chunk_pos::SubChunkPos;
+
+/// Changes many blocks of a sub chunk at once.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct UpdateSubChunkBlocksPacket {
+    pub sub_chunk_position: SubChunkPos,
+    /// The changes on the standard layer
+    #[len_repr(VAR::<u32>)]
+    pub standard_blocks: Vec<BlockChangeEntry>,
+    /// The changes on the liquid layer
+    #[len_repr(VAR::<u32>)]
+    pub extra_blocks: Vec<BlockChangeEntry>,
+}
@@ -0,0 +1,467 @@
+use std::collections::{HashMap, HashSet};
+
+use bedrockrs_core::int::{LE, VAR};
+
+use crate::gamepacket::GamePacket;
+use crate::packets::remove_objective_packet::RemoveObjectivePacket;
+use crate::packets::set_display_objective_packet::SetDisplayObjectivePacket;
+use crate::packets::set_score_packet::SetScorePacket;
+use crate::scoreboard::objective::{DisplaySlot, Objective};
+use crate::scoreboard::viewer::{ScoreboardViewer, ViewedDisplay};
+use crate::types::objective_sort_order::ObjectiveSortOrder;
+use crate::types::score_entry::{ScoreAction, ScoreEntry, ScoreIdentity};
+
+/// The scoreboard of a server, clients are kept up to date with [`Scoreboard::sync`].
+#[derive(Debug, Clone, Default)]
+pub struct Scoreboard {
+    objectives: HashMap<String, Objective>,
+    displays: HashMap<DisplaySlot, (String, ObjectiveSortOrder)>,
+    /// The scoreboard ids of every identity that ever had a score
+    identities: HashMap<ScoreIdentity, i64>,
+    next_scoreboard_id: i64,
+}
+
+impl Scoreboard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an objective with the `dummy` criteria, returns false if it already exists.
+    pub fn add_objective(
+        &mut self,
+        name: impl Into<String>,
+        display_name: impl Into<String>,
+    ) -> bool {
+        let name = name.into();
+        if self.objectives.contains_key(&name) {
+            return false;
+        }
+
+        self.objectives.insert(
+            name.clone(),
+            Objective {
+                name,
+                display_name: display_name.into(),
+                criteria: String::from("dummy"),
+                scores: HashMap::new(),
+            },
+        );
+
+        true
+    }
+
+    /// Removes an objective and clears the display slots it was shown in.
+    pub fn remove_objective(&mut self, name: &str) -> Option<Objective> {
+        self.displays.retain(|_, (objective, _)| objective != name);
+        self.objectives.remove(name)
+    }
+
+    pub fn objective(&self, name: &str) -> Option<&Objective> {
+        self.objectives.get(name)
+    }
+
+    pub fn objectives(&self) -> impl Iterator<Item = &Objective> {
+        self.objectives.values()
+    }
+
+    /// Changes the display name of an objective, returns false if it doesn't exist.
+    pub fn set_display_name(&mut self, objective: &str, display_name: impl Into<String>) -> bool {
+        match self.objectives.get_mut(objective) {
+            Some(objective) => {
+                objective.display_name = display_name.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Shows an objective in a slot, returns false if it doesn't exist.
+    pub fn set_display(
+        &mut self,
+        slot: DisplaySlot,
+        objective: &str,
+        sort_order: ObjectiveSortOrder,
+    ) -> bool {
+        if !self.objectives.contains_key(objective) {
+            return false;
+        }
+
+        self.displays
+            .insert(slot, (objective.to_string(), sort_order));
+        true
+    }
+
+    pub fn clear_display(&mut self, slot: DisplaySlot) {
+        self.displays.remove(&slot);
+    }
+
+    /// The name and sort order of the objective shown in a slot.
+    pub fn display(&self, slot: DisplaySlot) -> Option<(&str, ObjectiveSortOrder)> {
+        self.displays
+            .get(&slot)
+            .map(|(objective, sort_order)| (objective.as_str(), *sort_order))
+    }
+
+    pub fn score(&self, objective: &str, identity: &ScoreIdentity) -> Option<i32> {
+        self.objectives.get(objective)?.score(identity)
+    }
+
+    /// Sets a score and returns the previous one, does nothing if the objective doesn't exist.
+    pub fn set_score(
+        &mut self,
+        objective: &str,
+        identity: ScoreIdentity,
+        score: i32,
+    ) -> Option<i32> {
+        let objective = self.objectives.get_mut(objective)?;
+
+        if !self.identities.contains_key(&identity) {
+            self.next_scoreboard_id += 1;
+            self.identities
+                .insert(identity.clone(), self.next_scoreboard_id);
+        }
+
+        objective.scores.insert(identity, score)
+    }
+
+    /// Adds to a score, missing scores start at 0. Returns the new score.
+    pub fn add_score(
+        &mut self,
+        objective: &str,
+        identity: ScoreIdentity,
+        amount: i32,
+    ) -> Option<i32> {
+        let score = self
+            .score(objective, &identity)
+            .unwrap_or(0)
+            .wrapping_add(amount);
+
+        self.set_score(objective, identity, score)?;
+        Some(score)
+    }
+
+    pub fn remove_score(&mut self, objective: &str, identity: &ScoreIdentity) -> Option<i32> {
+        self.objectives.get_mut(objective)?.scores.remove(identity)
+    }
+
+    /// Removes the scores of an identity from all objectives, like when an actor is removed.
+    pub fn reset_scores(&mut self, identity: &ScoreIdentity) {
+        for objective in self.objectives.values_mut() {
+            objective.scores.remove(identity);
+        }
+    }
+
+    /// The packets needed to bring a viewer up to date, only the displayed objectives
+    /// and the scores that changed since the last sync are sent.
+    pub fn sync(&self, viewer: &mut ScoreboardViewer) -> Vec<GamePacket> {
+        let mut packets = vec![];
+
+        let displays: HashMap<DisplaySlot, ViewedDisplay> = self
+            .displays
+            .iter()
+            .filter_map(|(slot, (name, sort_order))| {
+                let objective = self.objectives.get(name)?;
+                Some((
+                    *slot,
+                    ViewedDisplay {
+                        objective_name: objective.name.clone(),
+                        display_name: objective.display_name.clone(),
+                        criteria: objective.criteria.clone(),
+                        sort_order: *sort_order,
+                    },
+                ))
+            })
+            .collect();
+
+        // Objectives that aren't shown anymore are removed from the client. The client can't
+        // change the display name of an objective or hide it from a single slot, such
+        // objectives are removed and shown again instead.
+        let removed: HashSet<String> = viewer
+            .displays
+            .iter()
+            .filter(|(slot, viewed)| {
+                let shown = displays
+                    .values()
+                    .any(|display| display.objective_name == viewed.objective_name);
+
+                !shown
+                    || displays.get(slot).map_or(true, |display| {
+                        display.objective_name == viewed.objective_name
+                            && (display.display_name != viewed.display_name
+                                || display.criteria != viewed.criteria)
+                    })
+            })
+            .map(|(_, viewed)| viewed.objective_name.clone())
+            .collect();
+
+        for objective_name in removed {
+            viewer
+                .displays
+                .retain(|_, viewed| viewed.objective_name != objective_name);
+            viewer.scores.remove(&objective_name);
+
+            packets.push(GamePacket::RemoveObjective(RemoveObjectivePacket {
+                objective_name,
+            }));
+        }
+
+        for slot in DisplaySlot::ALL {
+            let Some(display) = displays.get(&slot) else {
+                continue;
+            };
+
+            if viewer.displays.get(&slot) == Some(display) {
+                continue;
+            }
+
+            packets.push(GamePacket::SetDisplayObjective(SetDisplayObjectivePacket {
+                display_slot: slot.name().to_string(),
+                objective_name: display.objective_name.clone(),
+                display_name: display.display_name.clone(),
+                criteria_name: display.criteria.clone(),
+                sort_order: display.sort_order,
+            }));
+
+            viewer.displays.insert(slot, display.clone());
+        }
+
+        let displayed: HashSet<&str> = viewer
+            .displays
+            .values()
+            .map(|viewed| viewed.objective_name.as_str())
+            .collect();
+
+        let mut changed = vec![];
+        let mut removed = vec![];
+
+        for objective_name in displayed {
+            let objective = &self.objectives[objective_name];
+            let known = viewer.scores.entry(objective_name.to_string()).or_default();
+
+            let mut current = HashMap::new();
+            for (identity, score) in &objective.scores {
+                let scoreboard_id = self.identities[identity];
+                current.insert(scoreboard_id, *score);
+
+                if known.get(&scoreboard_id) != Some(score) {
+                    changed.push(ScoreEntry {
+                        scoreboard_id: VAR::new(scoreboard_id),
+                        objective_name: objective_name.to_string(),
+                        score: LE::new(*score),
+                        identity: Some(identity.clone()),
+                    });
+                }
+            }
+
+            for (scoreboard_id, score) in known.iter() {
+                if !current.contains_key(scoreboard_id) {
+                    removed.push(ScoreEntry {
+                        scoreboard_id: VAR::new(*scoreboard_id),
+                        objective_name: objective_name.to_string(),
+                        score: LE::new(*score),
+                        identity: None,
+                    });
+                }
+            }
+
+            *known = current;
+        }
+
+        if !removed.is_empty() {
+            packets.push(GamePacket::SetScore(SetScorePacket {
+                action: ScoreAction::Remove,
+                entries: removed,
+            }));
+        }
+
+        if !changed.is_empty() {
+            packets.push(GamePacket::SetScore(SetScorePacket {
+                action: ScoreAction::Change,
+                entries: changed,
+            }));
+        }
+
+        packets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fake_player(name: &str) -> ScoreIdentity {
+        ScoreIdentity::FakePlayer(name.to_string())
+    }
+
+    /// The objective, scoreboard id and score of a sent score.
+    type SentScore = (String, i64, i32);
+
+    fn sent_scores(packets: &[GamePacket]) -> Vec<(ScoreAction, Vec<SentScore>)> {
+        packets
+            .iter()
+            .filter_map(|packet| match packet {
+                GamePacket::SetScore(pk) => Some((
+                    pk.action,
+                    pk.entries
+                        .iter()
+                        .map(|entry| {
+                            (
+                                entry.objective_name.clone(),
+                                entry.scoreboard_id.into_inner(),
+                                entry.score.into_inner(),
+                            )
+                        })
+                        .collect(),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn removed_objectives(packets: &[GamePacket]) -> Vec<&str> {
+        packets
+            .iter()
+            .filter_map(|packet| match packet {
+                GamePacket::RemoveObjective(pk) => Some(pk.objective_name.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn shown_objectives(packets: &[GamePacket]) -> Vec<(&str, &str)> {
+        packets
+            .iter()
+            .filter_map(|packet| match packet {
+                GamePacket::SetDisplayObjective(pk) => {
+                    Some((pk.display_slot.as_str(), pk.objective_name.as_str()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn scoreboard() -> Scoreboard {
+        let mut scoreboard = Scoreboard::new();
+        scoreboard.add_objective("kills", "Kills");
+        scoreboard.set_display(
+            DisplaySlot::Sidebar,
+            "kills",
+            ObjectiveSortOrder::Descending,
+        );
+        scoreboard
+    }
+
+    #[test]
+    fn only_changed_scores_are_sent() {
+        let mut scoreboard = scoreboard();
+        let mut viewer = ScoreboardViewer::new();
+
+        scoreboard.set_score("kills", fake_player("a"), 1);
+        let packets = scoreboard.sync(&mut viewer);
+
+        assert_eq!(shown_objectives(&packets), [("sidebar", "kills")]);
+        assert_eq!(
+            sent_scores(&packets),
+            [(ScoreAction::Change, vec![(String::from("kills"), 1, 1)])]
+        );
+
+        // Nothing changed since the last sync
+        assert!(scoreboard.sync(&mut viewer).is_empty());
+
+        scoreboard.set_score("kills", fake_player("b"), 5);
+        scoreboard.add_score("kills", fake_player("a"), 0);
+        let packets = scoreboard.sync(&mut viewer);
+
+        assert!(shown_objectives(&packets).is_empty());
+        assert_eq!(
+            sent_scores(&packets),
+            [(ScoreAction::Change, vec![(String::from("kills"), 2, 5)])]
+        );
+    }
+
+    #[test]
+    fn removed_scores_are_sent() {
+        let mut scoreboard = scoreboard();
+        let mut viewer = ScoreboardViewer::new();
+
+        scoreboard.set_score("kills", fake_player("a"), 3);
+        scoreboard.sync(&mut viewer);
+
+        scoreboard.reset_scores(&fake_player("a"));
+        let packets = scoreboard.sync(&mut viewer);
+
+        assert_eq!(
+            sent_scores(&packets),
+            [(ScoreAction::Remove, vec![(String::from("kills"), 1, 3)])]
+        );
+
+        // The identity keeps its scoreboard id
+        scoreboard.set_score("kills", fake_player("a"), 4);
+        assert_eq!(
+            sent_scores(&scoreboard.sync(&mut viewer)),
+            [(ScoreAction::Change, vec![(String::from("kills"), 1, 4)])]
+        );
+    }
+
+    #[test]
+    fn hidden_objectives_are_removed() {
+        let mut scoreboard = scoreboard();
+        let mut viewer = ScoreboardViewer::new();
+
+        scoreboard.set_score("kills", fake_player("a"), 1);
+        scoreboard.sync(&mut viewer);
+
+        scoreboard.clear_display(DisplaySlot::Sidebar);
+        let packets = scoreboard.sync(&mut viewer);
+
+        assert_eq!(removed_objectives(&packets), ["kills"]);
+        assert!(sent_scores(&packets).is_empty());
+
+        // Showing it again resends its scores
+        scoreboard.set_display(DisplaySlot::List, "kills", ObjectiveSortOrder::Ascending);
+        let packets = scoreboard.sync(&mut viewer);
+
+        assert_eq!(shown_objectives(&packets), [("list", "kills")]);
+        assert_eq!(
+            sent_scores(&packets),
+            [(ScoreAction::Change, vec![(String::from("kills"), 1, 1)])]
+        );
+    }
+
+    #[test]
+    fn renamed_objectives_are_shown_again() {
+        let mut scoreboard = scoreboard();
+        let mut viewer = ScoreboardViewer::new();
+
+        scoreboard.set_score("kills", fake_player("a"), 1);
+        scoreboard.sync(&mut viewer);
+
+        scoreboard.set_display_name("kills", "Frags");
+        let packets = scoreboard.sync(&mut viewer);
+
+        assert_eq!(removed_objectives(&packets), ["kills"]);
+        assert_eq!(shown_objectives(&packets), [("sidebar", "kills")]);
+        assert_eq!(
+            sent_scores(&packets),
+            [(ScoreAction::Change, vec![(String::from("kills"), 1, 1)])]
+        );
+    }
+
+    #[test]
+    fn reset_viewers_get_everything_again() {
+        let mut scoreboard = scoreboard();
+        let mut viewer = ScoreboardViewer::new();
+
+        scoreboard.set_score("kills", fake_player("a"), 1);
+        scoreboard.sync(&mut viewer);
+
+        viewer.reset();
+        let packets = scoreboard.sync(&mut viewer);
+
+        assert_eq!(shown_objectives(&packets), [("sidebar", "kills")]);
+        assert_eq!(
+            sent_scores(&packets),
+            [(ScoreAction::Change, vec![(String::from("kills"), 1, 1)])]
+        );
+    }
+}
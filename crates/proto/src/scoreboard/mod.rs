@@ -0,0 +1,7 @@
+pub use manager::*;
+pub use objective::*;
+pub use viewer::*;
+
+pub mod manager;
+pub mod objective;
+pub mod viewer;
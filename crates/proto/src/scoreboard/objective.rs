@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use crate::types::score_entry::ScoreIdentity;
+
+/// A place on the HUD an objective can be shown in.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum DisplaySlot {
+    Sidebar,
+    /// The player list opened from the pause menu
+    List,
+    /// Below the name tags of players
+    BelowName,
+}
+
+impl DisplaySlot {
+    pub const ALL: [DisplaySlot; 3] = [
+        DisplaySlot::Sidebar,
+        DisplaySlot::List,
+        DisplaySlot::BelowName,
+    ];
+
+    /// The name of the slot used in `SetDisplayObjectivePacket`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            DisplaySlot::Sidebar => "sidebar",
+            DisplaySlot::List => "list",
+            DisplaySlot::BelowName => "belowname",
+        }
+    }
+}
+
+/// A named set of scores.
+#[derive(Debug, Clone)]
+pub struct Objective {
+    pub(crate) name: String,
+    pub(crate) display_name: String,
+    pub(crate) criteria: String,
+    pub(crate) scores: HashMap<ScoreIdentity, i32>,
+}
+
+impl Objective {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    pub fn criteria(&self) -> &str {
+        &self.criteria
+    }
+
+    pub fn score(&self, identity: &ScoreIdentity) -> Option<i32> {
+        self.scores.get(identity).copied()
+    }
+
+    pub fn scores(&self) -> impl Iterator<Item = (&ScoreIdentity, i32)> {
+        self.scores
+            .iter()
+            .map(|(identity, score)| (identity, *score))
+    }
+}
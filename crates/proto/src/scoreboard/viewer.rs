@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use crate::scoreboard::objective::DisplaySlot;
+use crate::types::objective_sort_order::ObjectiveSortOrder;
+
+/// What a single client currently knows of the scoreboard, kept by the server for every player.
+#[derive(Debug, Clone, Default)]
+pub struct ScoreboardViewer {
+    pub(crate) displays: HashMap<DisplaySlot, ViewedDisplay>,
+    /// The scores by scoreboard id of every displayed objective
+    pub(crate) scores: HashMap<String, HashMap<i64, i32>>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct ViewedDisplay {
+    pub objective_name: String,
+    pub display_name: String,
+    pub criteria: String,
+    pub sort_order: ObjectiveSortOrder,
+}
+
+impl ScoreboardViewer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forgets everything sent to the client, the next sync sends the whole scoreboard again.
+    pub fn reset(&mut self) {
+        self.displays.clear();
+        self.scores.clear();
+    }
+}
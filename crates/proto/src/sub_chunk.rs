@@ -0,0 +1,186 @@
+//! Answers sub chunk requests, see [`answer_sub_chunk_request`].
+
+use bedrockrs_core::int::LE;
+use bedrockrs_proto_core::error::ProtoCodecError;
+
+use crate::packets::sub_chunk_packet::SubChunkPacket;
+use crate::packets::sub_chunk_request_packet::SubChunkRequestPacket;
+use crate::types::sub_chunk_entry::{HeightMapType, SubChunkEntry, SubChunkRequestResult};
+
+/// A sub chunk as returned by the source of [`answer_sub_chunk_request`].
+#[derive(Debug, Clone)]
+pub enum SubChunkData {
+    Found {
+        /// The serialized sub chunk followed by the NBT of its block entities
+        payload: Vec<u8>,
+        /// The highest block of every column relative to the sub chunk, indexed by `x + z * 16`
+        heightmap: Option<Vec<i8>>,
+    },
+    AllAir,
+    /// The chunk isn't loaded or generated yet
+    NotFound,
+    /// The sub chunk is above or below the world
+    OutOfBounds,
+    InvalidDimension,
+}
+
+/// Builds the answer to a sub chunk request, the source is called with the dimension
+/// and the position (in sub chunks) of every requested sub chunk.
+///
+/// Fails if the source returns a heightmap that doesn't have an entry for every column.
+pub fn answer_sub_chunk_request(
+    request: &SubChunkRequestPacket,
+    mut source: impl FnMut(i32, i32, i32, i32) -> SubChunkData,
+) -> Result<SubChunkPacket, ProtoCodecError> {
+    let dimension_id = request.dimension_id.into_inner();
+    let center = &request.center_position;
+
+    let entries = request
+        .offsets
+        .iter()
+        .map(|offset| {
+            let data = source(
+                dimension_id,
+                center.x.into_inner() + offset.x as i32,
+                center.y.into_inner() + offset.y as i32,
+                center.z.into_inner() + offset.z as i32,
+            );
+
+            let result = match data {
+                SubChunkData::Found { payload, heightmap } => {
+                    let heightmap_type = match heightmap.as_deref() {
+                        Some(heightmap) => heightmap_type(heightmap)?,
+                        None => HeightMapType::NoData,
+                    };
+
+                    return Ok(SubChunkEntry {
+                        offset: *offset,
+                        result: SubChunkRequestResult::Success,
+                        payload,
+                        heightmap: match heightmap_type {
+                            HeightMapType::HasData => heightmap.unwrap_or_default(),
+                            _ => vec![],
+                        },
+                        heightmap_type,
+                        blob_id: LE::new(0),
+                    });
+                }
+                SubChunkData::AllAir => SubChunkRequestResult::SuccessAllAir,
+                SubChunkData::NotFound => SubChunkRequestResult::ChunkNotFound,
+                SubChunkData::OutOfBounds => SubChunkRequestResult::YIndexOutOfBounds,
+                SubChunkData::InvalidDimension => SubChunkRequestResult::InvalidDimension,
+            };
+
+            Ok(SubChunkEntry::empty(*offset, result))
+        })
+        .collect::<Result<_, ProtoCodecError>>()?;
+
+    Ok(SubChunkPacket {
+        cache_enabled: false,
+        dimension_id: request.dimension_id,
+        center_position: request.center_position.clone(),
+        entries,
+    })
+}
+
+/// Heightmaps with every column above or below the sub chunk don't need to be sent.
+fn heightmap_type(heightmap: &[i8]) -> Result<HeightMapType, ProtoCodecError> {
+    if heightmap.len() != SubChunkEntry::HEIGHTMAP_SIZE {
+        return Err(ProtoCodecError::FormatMismatch(format!(
+            "Expected a heightmap with {} entries, got {}",
+            SubChunkEntry::HEIGHTMAP_SIZE,
+            heightmap.len()
+        )));
+    }
+
+    Ok(if heightmap.iter().all(|height| *height > 15) {
+        HeightMapType::TooHigh
+    } else if heightmap.iter().all(|height| *height < 0) {
+        HeightMapType::TooLow
+    } else {
+        HeightMapType::HasData
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bedrockrs_core::int::VAR;
+
+    use super::*;
+    use crate::types::sub_chunk_entry::SubChunkOffset;
+    use crate::types::sub_chunk_pos::SubChunkPos;
+
+    fn request(offsets: usize) -> SubChunkRequestPacket {
+        SubChunkRequestPacket {
+            dimension_id: VAR::new(0),
+            center_position: SubChunkPos::new(1, 2, 3),
+            offsets: (0..offsets as i8)
+                .map(|y| SubChunkOffset { x: 0, y, z: 0 })
+                .collect(),
+        }
+    }
+
+    fn found(heightmap: Option<Vec<i8>>) -> SubChunkData {
+        SubChunkData::Found {
+            payload: vec![1, 2, 3],
+            heightmap,
+        }
+    }
+
+    #[test]
+    fn heightmaps_outside_the_sub_chunk_are_not_sent() {
+        let mut heightmaps = vec![
+            Some(vec![16; 256]),
+            Some(vec![-1; 256]),
+            Some(vec![5; 256]),
+            None,
+        ]
+        .into_iter();
+
+        let answer =
+            answer_sub_chunk_request(&request(4), |_, _, _, _| found(heightmaps.next().unwrap()))
+                .unwrap();
+
+        let types: Vec<_> = answer
+            .entries
+            .iter()
+            .map(|entry| entry.heightmap_type)
+            .collect();
+        assert_eq!(
+            types,
+            [
+                HeightMapType::TooHigh,
+                HeightMapType::TooLow,
+                HeightMapType::HasData,
+                HeightMapType::NoData,
+            ]
+        );
+        assert_eq!(answer.entries[2].heightmap, [5; 256]);
+        assert!(answer.entries[0].heightmap.is_empty());
+    }
+
+    #[test]
+    fn heightmaps_of_the_wrong_size_are_rejected() {
+        for heightmap in [vec![], vec![0; 255]] {
+            let answer =
+                answer_sub_chunk_request(&request(1), |_, _, _, _| found(Some(heightmap.clone())));
+            assert!(answer.is_err());
+        }
+    }
+
+    #[test]
+    fn sub_chunks_are_requested_from_the_source() {
+        let mut requested = vec![];
+        let answer = answer_sub_chunk_request(&request(2), |dimension, x, y, z| {
+            requested.push((dimension, x, y, z));
+            SubChunkData::NotFound
+        })
+        .unwrap();
+
+        assert_eq!(requested, [(0, 1, 2, 3), (0, 1, 3, 3)]);
+        assert!(answer
+            .entries
+            .iter()
+            .all(|entry| entry.result == SubChunkRequestResult::ChunkNotFound));
+    }
+}
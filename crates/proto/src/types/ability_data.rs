@@ -0,0 +1,34 @@
+use bedrockrs_core::int::LE;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// The abilities of a player, made up of layers that are applied on top of each other.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct AbilityData {
+    pub target_player_raw_id: LE<i64>,
+    pub player_permissions: u8,
+    pub command_permissions: u8,
+    #[len_repr(LE::<u8>)]
+    pub layers: Vec<AbilityLayer>,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct AbilityLayer {
+    pub layer_type: AbilityLayerType,
+    /// The abilities that are set by this layer, as bitset
+    pub abilities_set: LE<u32>,
+    /// The values of the set abilities, as bitset
+    pub ability_values: LE<u32>,
+    pub fly_speed: LE<f32>,
+    pub walk_speed: LE<f32>,
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u16>)]
+pub enum AbilityLayerType {
+    CustomCache = 0,
+    Base = 1,
+    Spectator = 2,
+    Commands = 3,
+    Editor = 4,
+    LoadingScreen = 5,
+}
@@ -0,0 +1,13 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// Links a block change to an actor, like a falling block turning into a block.
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(VAR::<u64>)]
+pub enum ActorBlockSyncMessage {
+    None = 0,
+    /// The actor was created from the block
+    Create = 1,
+    /// The actor became the block
+    Destroy = 2,
+}
@@ -0,0 +1,330 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_core::error::ProtoCodecError;
+
+/// The keys of the data items in an actor's metadata, the type of
+/// their value is noted next to every key.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum ActorDataKey {
+    /// Int64, the first 64 [`ActorFlags`](crate::types::actor_flags::ActorFlags)
+    Flags = 0,
+    StructuralIntegrity = 1,
+    /// Int
+    Variant = 2,
+    /// Byte
+    ColorIndex = 3,
+    /// String, the name tag
+    Name = 4,
+    /// Int64
+    Owner = 5,
+    /// Int64
+    Target = 6,
+    /// Short
+    AirSupply = 7,
+    EffectColor = 8,
+    EffectAmbience = 9,
+    JumpDuration = 10,
+    Hurt = 11,
+    HurtDirection = 12,
+    RowTimeLeft = 13,
+    RowTimeRight = 14,
+    Value = 15,
+    DisplayTileRuntimeID = 16,
+    DisplayOffset = 17,
+    CustomDisplay = 18,
+    Swell = 19,
+    OldSwell = 20,
+    SwellDirection = 21,
+    ChargeAmount = 22,
+    CarryBlockRuntimeID = 23,
+    ClientEvent = 24,
+    UsingItem = 25,
+    /// Byte
+    PlayerFlags = 26,
+    PlayerIndex = 27,
+    /// Pos
+    BedPosition = 28,
+    PowerX = 29,
+    PowerY = 30,
+    PowerZ = 31,
+    AuxPower = 32,
+    FishX = 33,
+    FishZ = 34,
+    FishAngle = 35,
+    AuxValueData = 36,
+    /// Int64
+    LeashHolder = 37,
+    /// Float
+    Scale = 38,
+    HasNpc = 39,
+    NpcData = 40,
+    Actions = 41,
+    /// Short
+    AirSupplyMax = 42,
+    /// Int
+    MarkVariant = 43,
+    ContainerType = 44,
+    ContainerSize = 45,
+    ContainerStrengthModifier = 46,
+    BlockTarget = 47,
+    Inventory = 48,
+    TargetA = 49,
+    TargetB = 50,
+    TargetC = 51,
+    AerialAttack = 52,
+    /// Float, the width of the bounding box
+    Width = 53,
+    /// Float, the height of the bounding box
+    Height = 54,
+    FuseTime = 55,
+    SeatOffset = 56,
+    SeatLockPassengerRotation = 57,
+    SeatLockPassengerRotationDegrees = 58,
+    SeatRotationOffset = 59,
+    SeatRotationOffsetDegrees = 60,
+    DataRadius = 61,
+    DataWaiting = 62,
+    DataParticle = 63,
+    PeekID = 64,
+    AttachFace = 65,
+    Attached = 66,
+    AttachedPosition = 67,
+    TradeTarget = 68,
+    Career = 69,
+    HasCommandBlock = 70,
+    CommandName = 71,
+    LastCommandOutput = 72,
+    TrackCommandOutput = 73,
+    ControllingSeatIndex = 74,
+    Strength = 75,
+    StrengthMax = 76,
+    DataSpellCastingColor = 77,
+    DataLifetimeTicks = 78,
+    PoseIndex = 79,
+    DataTickOffset = 80,
+    /// Byte
+    AlwaysShowNameTag = 81,
+    ColorTwoIndex = 82,
+    NameAuthor = 83,
+    /// String, shown below the name tag
+    Score = 84,
+    BalloonAnchor = 85,
+    PuffedState = 86,
+    BubbleTime = 87,
+    Agent = 88,
+    SittingAmount = 89,
+    SittingAmountPrevious = 90,
+    EatingCounter = 91,
+    /// Int64, the second 64 [`ActorFlags`](crate::types::actor_flags::ActorFlags)
+    FlagsTwo = 92,
+    LayingAmount = 93,
+    LayingAmountPrevious = 94,
+    DataDuration = 95,
+    DataSpawnTime = 96,
+    DataChangeRate = 97,
+    DataChangeOnPickup = 98,
+    DataPickupCount = 99,
+    InteractText = 100,
+    TradeTier = 101,
+    MaxTradeTier = 102,
+    TradeExperience = 103,
+    SkinID = 104,
+    SpawningFrames = 105,
+    CommandBlockTickDelay = 106,
+    CommandBlockExecuteOnFirstTick = 107,
+    AmbientSoundInterval = 108,
+    AmbientSoundIntervalRange = 109,
+    AmbientSoundEventName = 110,
+    FallDamageMultiplier = 111,
+    /// String
+    NameRawText = 112,
+    CanRideTarget = 113,
+    LowTierCuredTradeDiscount = 114,
+    HighTierCuredTradeDiscount = 115,
+    NearbyCuredTradeDiscount = 116,
+    NearbyCuredDiscountTimeStamp = 117,
+    /// CompoundTag
+    HitBox = 118,
+    IsBuoyant = 119,
+    FreezingEffectStrength = 120,
+    BuoyancyData = 121,
+    GoatHornCount = 122,
+    BaseRuntimeID = 123,
+    MovementSoundDistanceOffset = 124,
+    HeartbeatIntervalTicks = 125,
+    HeartbeatSoundEvent = 126,
+    PlayerLastDeathPosition = 127,
+    PlayerLastDeathDimension = 128,
+    PlayerHasDied = 129,
+    /// Vec3
+    CollisionBox = 130,
+    VisibleMobEffects = 131,
+}
+
+impl TryFrom<u32> for ActorDataKey {
+    type Error = ProtoCodecError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => ActorDataKey::Flags,
+            1 => ActorDataKey::StructuralIntegrity,
+            2 => ActorDataKey::Variant,
+            3 => ActorDataKey::ColorIndex,
+            4 => ActorDataKey::Name,
+            5 => ActorDataKey::Owner,
+            6 => ActorDataKey::Target,
+            7 => ActorDataKey::AirSupply,
+            8 => ActorDataKey::EffectColor,
+            9 => ActorDataKey::EffectAmbience,
+            10 => ActorDataKey::JumpDuration,
+            11 => ActorDataKey::Hurt,
+            12 => ActorDataKey::HurtDirection,
+            13 => ActorDataKey::RowTimeLeft,
+            14 => ActorDataKey::RowTimeRight,
+            15 => ActorDataKey::Value,
+            16 => ActorDataKey::DisplayTileRuntimeID,
+            17 => ActorDataKey::DisplayOffset,
+            18 => ActorDataKey::CustomDisplay,
+            19 => ActorDataKey::Swell,
+            20 => ActorDataKey::OldSwell,
+            21 => ActorDataKey::SwellDirection,
+            22 => ActorDataKey::ChargeAmount,
+            23 => ActorDataKey::CarryBlockRuntimeID,
+            24 => ActorDataKey::ClientEvent,
+            25 => ActorDataKey::UsingItem,
+            26 => ActorDataKey::PlayerFlags,
+            27 => ActorDataKey::PlayerIndex,
+            28 => ActorDataKey::BedPosition,
+            29 => ActorDataKey::PowerX,
+            30 => ActorDataKey::PowerY,
+            31 => ActorDataKey::PowerZ,
+            32 => ActorDataKey::AuxPower,
+            33 => ActorDataKey::FishX,
+            34 => ActorDataKey::FishZ,
+            35 => ActorDataKey::FishAngle,
+            36 => ActorDataKey::AuxValueData,
+            37 => ActorDataKey::LeashHolder,
+            38 => ActorDataKey::Scale,
+            39 => ActorDataKey::HasNpc,
+            40 => ActorDataKey::NpcData,
+            41 => ActorDataKey::Actions,
+            42 => ActorDataKey::AirSupplyMax,
+            43 => ActorDataKey::MarkVariant,
+            44 => ActorDataKey::ContainerType,
+            45 => ActorDataKey::ContainerSize,
+            46 => ActorDataKey::ContainerStrengthModifier,
+            47 => ActorDataKey::BlockTarget,
+            48 => ActorDataKey::Inventory,
+            49 => ActorDataKey::TargetA,
+            50 => ActorDataKey::TargetB,
+            51 => ActorDataKey::TargetC,
+            52 => ActorDataKey::AerialAttack,
+            53 => ActorDataKey::Width,
+            54 => ActorDataKey::Height,
+            55 => ActorDataKey::FuseTime,
+            56 => ActorDataKey::SeatOffset,
+            57 => ActorDataKey::SeatLockPassengerRotation,
+            58 => ActorDataKey::SeatLockPassengerRotationDegrees,
+            59 => ActorDataKey::SeatRotationOffset,
+            60 => ActorDataKey::SeatRotationOffsetDegrees,
+            61 => ActorDataKey::DataRadius,
+            62 => ActorDataKey::DataWaiting,
+            63 => ActorDataKey::DataParticle,
+            64 => ActorDataKey::PeekID,
+            65 => ActorDataKey::AttachFace,
+            66 => ActorDataKey::Attached,
+            67 => ActorDataKey::AttachedPosition,
+            68 => ActorDataKey::TradeTarget,
+            69 => ActorDataKey::Career,
+            70 => ActorDataKey::HasCommandBlock,
+            71 => ActorDataKey::CommandName,
+            72 => ActorDataKey::LastCommandOutput,
+            73 => ActorDataKey::TrackCommandOutput,
+            74 => ActorDataKey::ControllingSeatIndex,
+            75 => ActorDataKey::Strength,
+            76 => ActorDataKey::StrengthMax,
+            77 => ActorDataKey::DataSpellCastingColor,
+            78 => ActorDataKey::DataLifetimeTicks,
+            79 => ActorDataKey::PoseIndex,
+            80 => ActorDataKey::DataTickOffset,
+            81 => ActorDataKey::AlwaysShowNameTag,
+            82 => ActorDataKey::ColorTwoIndex,
+            83 => ActorDataKey::NameAuthor,
+            84 => ActorDataKey::Score,
+            85 => ActorDataKey::BalloonAnchor,
+            86 => ActorDataKey::PuffedState,
+            87 => ActorDataKey::BubbleTime,
+            88 => ActorDataKey::Agent,
+            89 => ActorDataKey::SittingAmount,
+            90 => ActorDataKey::SittingAmountPrevious,
+            91 => ActorDataKey::EatingCounter,
+            92 => ActorDataKey::FlagsTwo,
+            93 => ActorDataKey::LayingAmount,
+            94 => ActorDataKey::LayingAmountPrevious,
+            95 => ActorDataKey::DataDuration,
+            96 => ActorDataKey::DataSpawnTime,
+            97 => ActorDataKey::DataChangeRate,
+            98 => ActorDataKey::DataChangeOnPickup,
+            99 => ActorDataKey::DataPickupCount,
+            100 => ActorDataKey::InteractText,
+            101 => ActorDataKey::TradeTier,
+            102 => ActorDataKey::MaxTradeTier,
+            103 => ActorDataKey::TradeExperience,
+            104 => ActorDataKey::SkinID,
+            105 => ActorDataKey::SpawningFrames,
+            106 => ActorDataKey::CommandBlockTickDelay,
+            107 => ActorDataKey::CommandBlockExecuteOnFirstTick,
+            108 => ActorDataKey::AmbientSoundInterval,
+            109 => ActorDataKey::AmbientSoundIntervalRange,
+            110 => ActorDataKey::AmbientSoundEventName,
+            111 => ActorDataKey::FallDamageMultiplier,
+            112 => ActorDataKey::NameRawText,
+            113 => ActorDataKey::CanRideTarget,
+            114 => ActorDataKey::LowTierCuredTradeDiscount,
+            115 => ActorDataKey::HighTierCuredTradeDiscount,
+            116 => ActorDataKey::NearbyCuredTradeDiscount,
+            117 => ActorDataKey::NearbyCuredDiscountTimeStamp,
+            118 => ActorDataKey::HitBox,
+            119 => ActorDataKey::IsBuoyant,
+            120 => ActorDataKey::FreezingEffectStrength,
+            121 => ActorDataKey::BuoyancyData,
+            122 => ActorDataKey::GoatHornCount,
+            123 => ActorDataKey::BaseRuntimeID,
+            124 => ActorDataKey::MovementSoundDistanceOffset,
+            125 => ActorDataKey::HeartbeatIntervalTicks,
+            126 => ActorDataKey::HeartbeatSoundEvent,
+            127 => ActorDataKey::PlayerLastDeathPosition,
+            128 => ActorDataKey::PlayerLastDeathDimension,
+            129 => ActorDataKey::PlayerHasDied,
+            130 => ActorDataKey::CollisionBox,
+            131 => ActorDataKey::VisibleMobEffects,
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("ActorDataKey"),
+                ))
+            }
+        })
+    }
+}
+
+impl From<ActorDataKey> for VAR<u32> {
+    fn from(value: ActorDataKey) -> Self {
+        VAR::new(value as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_round_trip() {
+        for id in 0..=131 {
+            let key = ActorDataKey::try_from(id).unwrap();
+            assert_eq!(VAR::<u32>::from(key).into_inner(), id);
+        }
+
+        assert!(ActorDataKey::try_from(132).is_err());
+    }
+}
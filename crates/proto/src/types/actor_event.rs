@@ -0,0 +1,97 @@
+use bedrockrs_core::int::LE;
+use bedrockrs_proto_derive::ProtoCodec;
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
+pub enum ActorEvent {
+    Jump = 1,
+    Hurt = 2,
+    Death = 3,
+    ArmSwing = 4,
+    StopAttack = 5,
+    TameFail = 6,
+    TameSucceed = 7,
+    ShakeWetness = 8,
+    UseItem = 9,
+    EatGrass = 10,
+    FishHookBubble = 11,
+    FishHookFishPosition = 12,
+    FishHookHookTime = 13,
+    FishHookTease = 14,
+    SquidFleeing = 15,
+    ZombieConverting = 16,
+    PlayAmbient = 17,
+    SpawnAlive = 18,
+    StartOfferFlower = 19,
+    StopOfferFlower = 20,
+    LoveHearts = 21,
+    VillagerAngry = 22,
+    VillagerHappy = 23,
+    WitchHatMagic = 24,
+    FireworksExplode = 25,
+    InLoveHearts = 26,
+    SilverfishMergeAnimation = 27,
+    GuardianAttackSound = 28,
+    DrinkPotion = 29,
+    ThrowPotion = 30,
+    PrimeTntCart = 31,
+    PrimeCreeper = 32,
+    AirSupply = 33,
+    AddPlayerLevels = 34,
+    GuardianMiningFatigue = 35,
+    AgentSwingArm = 36,
+    DragonStartDeathAnim = 37,
+    GroundDust = 38,
+    Shake = 39,
+    Feed = 57,
+    BabyAge = 60,
+    InstantDeath = 61,
+    NotifyTrade = 62,
+    LeashDestroyed = 63,
+    CaravanUpdated = 64,
+    TalismanActivate = 65,
+    UpdateStructureFeature = 66,
+    PlayerSpawnedMob = 67,
+    Puke = 68,
+    UpdateStackSize = 69,
+    StartSwimming = 70,
+    BalloonPop = 71,
+    TreasureHunt = 72,
+    SummonAgent = 73,
+    FinishedChargingItem = 74,
+    Fall = 75,
+    ActorGrowUp = 76,
+    VibrationDetected = 77,
+    DrinkMilk = 78,
+    ShakeWetnessStop = 79,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use bedrockrs_proto_core::ProtoCodec;
+
+    use super::ActorEvent;
+
+    #[test]
+    fn known_ids_round_trip() {
+        for (id, event) in [
+            (1, ActorEvent::Jump),
+            (9, ActorEvent::UseItem),
+            (10, ActorEvent::EatGrass),
+            (39, ActorEvent::Shake),
+            (57, ActorEvent::Feed),
+            (75, ActorEvent::Fall),
+            (76, ActorEvent::ActorGrowUp),
+            (79, ActorEvent::ShakeWetnessStop),
+        ] {
+            let mut stream = vec![];
+            event.proto_serialize(&mut stream).unwrap();
+            assert_eq!(stream, [id]);
+
+            let decoded = ActorEvent::proto_deserialize(&mut Cursor::new(&[id][..])).unwrap();
+            assert_eq!(decoded, event);
+        }
+    }
+}
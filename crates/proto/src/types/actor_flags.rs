@@ -0,0 +1,146 @@
+use bitflags::bitflags;
+
+bitflags! {
+    /// The flags of an actor, they are sent as two 64 bit halves with the
+    /// [`Flags`](crate::types::actor_data_key::ActorDataKey::Flags) and
+    /// [`FlagsTwo`](crate::types::actor_data_key::ActorDataKey::FlagsTwo) data keys.
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+    pub struct ActorFlags: u128 {
+        const ON_FIRE = 1 << 0;
+        const SNEAKING = 1 << 1;
+        const RIDING = 1 << 2;
+        const SPRINTING = 1 << 3;
+        const USING_ITEM = 1 << 4;
+        const INVISIBLE = 1 << 5;
+        const TEMPTED = 1 << 6;
+        const IN_LOVE = 1 << 7;
+        const SADDLED = 1 << 8;
+        const POWERED = 1 << 9;
+        const IGNITED = 1 << 10;
+        const BABY = 1 << 11;
+        const CONVERTING = 1 << 12;
+        const CRITICAL = 1 << 13;
+        const SHOW_NAME = 1 << 14;
+        const ALWAYS_SHOW_NAME = 1 << 15;
+        const NO_AI = 1 << 16;
+        const SILENT = 1 << 17;
+        const WALL_CLIMBING = 1 << 18;
+        const CLIMB = 1 << 19;
+        const SWIM = 1 << 20;
+        const FLY = 1 << 21;
+        const WALK = 1 << 22;
+        const RESTING = 1 << 23;
+        const SITTING = 1 << 24;
+        const ANGRY = 1 << 25;
+        const INTERESTED = 1 << 26;
+        const CHARGED = 1 << 27;
+        const TAMED = 1 << 28;
+        const ORPHANED = 1 << 29;
+        const LEASHED = 1 << 30;
+        const SHEARED = 1 << 31;
+        const GLIDING = 1 << 32;
+        const ELDER = 1 << 33;
+        const MOVING = 1 << 34;
+        const BREATHING = 1 << 35;
+        const CHESTED = 1 << 36;
+        const STACKABLE = 1 << 37;
+        const SHOW_BOTTOM = 1 << 38;
+        const STANDING = 1 << 39;
+        const SHAKING = 1 << 40;
+        const IDLING = 1 << 41;
+        const CASTING = 1 << 42;
+        const CHARGING = 1 << 43;
+        const KEYBOARD_CONTROLLED = 1 << 44;
+        const POWER_JUMP = 1 << 45;
+        const DASH = 1 << 46;
+        const LINGERING = 1 << 47;
+        const HAS_COLLISION = 1 << 48;
+        const HAS_GRAVITY = 1 << 49;
+        const FIRE_IMMUNE = 1 << 50;
+        const DANCING = 1 << 51;
+        const ENCHANTED = 1 << 52;
+        const RETURN_TRIDENT = 1 << 53;
+        const CONTAINER_PRIVATE = 1 << 54;
+        const TRANSFORMING = 1 << 55;
+        const DAMAGE_NEARBY_MOBS = 1 << 56;
+        const SWIMMING = 1 << 57;
+        const BRIBED = 1 << 58;
+        const PREGNANT = 1 << 59;
+        const LAYING_EGG = 1 << 60;
+        const PASSENGER_CAN_PICK = 1 << 61;
+        const TRANSITION_SITTING = 1 << 62;
+        const EATING = 1 << 63;
+        const LAYING_DOWN = 1 << 64;
+        const SNEEZING = 1 << 65;
+        const TRUSTING = 1 << 66;
+        const ROLLING = 1 << 67;
+        const SCARED = 1 << 68;
+        const IN_SCAFFOLDING = 1 << 69;
+        const OVER_SCAFFOLDING = 1 << 70;
+        const DESCEND_THROUGH_BLOCK = 1 << 71;
+        const BLOCKING = 1 << 72;
+        const TRANSITION_BLOCKING = 1 << 73;
+        const BLOCKED_USING_SHIELD = 1 << 74;
+        const BLOCKED_USING_DAMAGED_SHIELD = 1 << 75;
+        const SLEEPING = 1 << 76;
+        const WANTS_TO_WAKE = 1 << 77;
+        const TRADE_INTEREST = 1 << 78;
+        const DOOR_BREAKER = 1 << 79;
+        const BREAKING_OBSTRUCTION = 1 << 80;
+        const DOOR_OPENER = 1 << 81;
+        const CAPTAIN = 1 << 82;
+        const STUNNED = 1 << 83;
+        const ROARING = 1 << 84;
+        const DELAYED_ATTACK = 1 << 85;
+        const AVOIDING_MOBS = 1 << 86;
+        const AVOIDING_BLOCK = 1 << 87;
+        const FACING_TARGET_TO_RANGE_ATTACK = 1 << 88;
+        const HIDDEN_WHEN_INVISIBLE = 1 << 89;
+        const IN_UI = 1 << 90;
+        const STALKING = 1 << 91;
+        const EMOTING = 1 << 92;
+        const CELEBRATING = 1 << 93;
+        const ADMIRING = 1 << 94;
+        const CELEBRATING_SPECIAL = 1 << 95;
+        const OUT_OF_CONTROL = 1 << 96;
+        const RAM_ATTACK = 1 << 97;
+        const PLAYING_DEAD = 1 << 98;
+        const IN_ASCENDING_BLOCK = 1 << 99;
+        const OVER_DESCENDING_BLOCK = 1 << 100;
+        const CROAKING = 1 << 101;
+        const DIGEST_MOB = 1 << 102;
+        const JUMP_GOAL = 1 << 103;
+        const EMERGING = 1 << 104;
+        const SNIFFING = 1 << 105;
+        const DIGGING = 1 << 106;
+        const SONIC_BOOM = 1 << 107;
+        const HAS_DASH_TIMEOUT = 1 << 108;
+        const PUSH_TOWARDS_CLOSEST_SPACE = 1 << 109;
+        const SCENTING = 1 << 110;
+        const RISING = 1 << 111;
+        const FEELING_HAPPY = 1 << 112;
+        const SEARCHING = 1 << 113;
+        const CRAWLING = 1 << 114;
+        const TIMER_FLAG1 = 1 << 115;
+        const TIMER_FLAG2 = 1 << 116;
+        const TIMER_FLAG3 = 1 << 117;
+        const BODY_ROTATION_BLOCKED = 1 << 118;
+    }
+}
+
+impl ActorFlags {
+    /// The first 64 flags, sent with the `Flags` data key.
+    pub fn lower(&self) -> i64 {
+        self.bits() as u64 as i64
+    }
+
+    /// The second 64 flags, sent with the `FlagsTwo` data key.
+    pub fn upper(&self) -> i64 {
+        (self.bits() >> 64) as u64 as i64
+    }
+
+    /// Puts the flags back together from their two halves, unknown flags are kept.
+    pub fn from_halves(lower: i64, upper: i64) -> Self {
+        Self::from_bits_retain(((upper as u64 as u128) << 64) | lower as u64 as u128)
+    }
+}
@@ -1,4 +1,4 @@
-use bedrockrs_core::int::VAR;
+use bedrockrs_core::int::LE;
 use bedrockrs_proto_derive::ProtoCodec;
 use bedrockrs_shared::actor_unique_id::ActorUniqueID;
 
@@ -6,9 +6,13 @@ use super::actor_link_type::ActorLinkType;
 
 #[derive(ProtoCodec, Debug, Clone)]
 pub struct ActorLink {
-    actor_unique_id_a: ActorUniqueID,
-    actor_unique_id_b: ActorUniqueID,
-    link_type: ActorLinkType,
-    immediate: bool,
-    passenger_seat_id: bool,
+    /// The actor that is ridden
+    pub actor_unique_id_a: ActorUniqueID,
+    /// The actor that is riding
+    pub actor_unique_id_b: ActorUniqueID,
+    pub link_type: ActorLinkType,
+    pub immediate: bool,
+    /// Whether the rider initiated the link
+    pub rider_initiated: bool,
+    pub vehicle_angular_velocity: LE<f32>,
 }
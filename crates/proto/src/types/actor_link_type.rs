@@ -1,8 +1,8 @@
-use bedrockrs_core::int::VAR;
+use bedrockrs_core::int::LE;
 use bedrockrs_proto_derive::ProtoCodec;
 
-#[derive(ProtoCodec, Debug, Clone)]
-#[enum_repr(VAR::<i32>)]
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
 pub enum ActorLinkType {
     None = 0,
     Riding = 1,
@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter};
+
 use bedrockrs_proto_core::ProtoCodec;
 use bitflags::bitflags;
 bitflags! {
@@ -312,163 +314,174 @@ pub enum ActorType {
 }
 
 impl ActorType {
-    pub fn to_string(self) -> String {
+    /// The identifier of the actor like `minecraft:zombie`,
+    /// `None` for families and types without an identifier.
+    pub fn identifier(&self) -> Option<&'static str> {
         match self {
-            ActorType::Undefined => unimplemented!(),
-            ActorType::TypeMask => unimplemented!(),
-            ActorType::Mob => unimplemented!(),
-            ActorType::PathfinderMob => unimplemented!(),
-            ActorType::Monster => unimplemented!(),
-            ActorType::Animal => unimplemented!(),
-            ActorType::TamableAnimal => unimplemented!(),
-            ActorType::Ambient => unimplemented!(),
-            ActorType::UndeadMob => unimplemented!(),
-            ActorType::ZombieMonster => unimplemented!(),
-            ActorType::Arthropod => unimplemented!(),
-            ActorType::Minecart => String::from("minecraft:minecart"),
-            ActorType::SkeletonMonster => unimplemented!(),
-            ActorType::EquineAnimal => unimplemented!(),
-            ActorType::Projectile => unimplemented!(),
-            ActorType::AbstractArrow => unimplemented!(),
-            ActorType::WaterAnimal => unimplemented!(),
-            ActorType::VillagerBase => unimplemented!(),
-            ActorType::Chicken => String::from("minecraft:chicken"),
-            ActorType::Cow => String::from("minecraft:cow"),
-            ActorType::Pig => String::from("minecraft:pig"),
-            ActorType::Sheep => String::from("minecraft:sheep"),
-            ActorType::Wolf => String::from("minecraft:wolf"),
-            ActorType::Villager => String::from("minecraft:villager"),
-            ActorType::MushroomCow => unimplemented!(),
-            ActorType::Squid => String::from("minecraft:squid"),
-            ActorType::Rabbit => String::from("minecraft:rabbit"),
-            ActorType::Bat => String::from("minecraft:bat"),
-            ActorType::IronGolem => String::from("minecraft:iron_golem"),
-            ActorType::SnowGolem => String::from("minecraft:snow_golem"),
-            ActorType::Ocelot => String::from("minecraft:ocelot"),
-            ActorType::Horse => String::from("minecraft:zombie_horse"),
-            ActorType::PolarBear => String::from("minecraft:polar_bear"),
-            ActorType::Llama => String::from("minecraft:llama_spit"),
-            ActorType::Parrot => String::from("minecraft:parrot"),
-            ActorType::Dolphin => String::from("minecraft:dolphin"),
-            ActorType::Donkey => String::from("minecraft:donkey"),
-            ActorType::Mule => String::from("minecraft:mule"),
-            ActorType::SkeletonHorse => String::from("minecraft:skeleton_horse"),
-            ActorType::ZombieHorse => String::from("minecraft:zombie_horse"),
-            ActorType::Zombie => String::from("minecraft:zombie"),
-            ActorType::Creeper => String::from("minecraft:creeper"),
-            ActorType::Skeleton => String::from("minecraft:skeleton"),
-            ActorType::Spider => String::from("minecraft:spider"),
-            ActorType::PigZombie => unimplemented!(),
-            ActorType::Slime => String::from("minecraft:slime"),
-            ActorType::EnderMan => String::from("minecraft:enderman"),
-            ActorType::Silverfish => String::from("minecraft:silverfish"),
-            ActorType::CaveSpider => String::from("minecraft:cave_spider"),
-            ActorType::Ghast => String::from("minecraft:ghast"),
-            ActorType::LavaSlime => String::from("minecraft:magma_cube"),
-            ActorType::Blaze => String::from("minecraft:blaze"),
-            ActorType::ZombieVillager => String::from("minecraft:zombie_villager"),
-            ActorType::Witch => String::from("minecraft:witch"),
-            ActorType::Stray => String::from("minecraft:stray"),
-            ActorType::Husk => String::from("minecraft:husk"),
-            ActorType::WitherSkeleton => String::from("minecraft:wither_skeleton"),
-            ActorType::Guardian => String::from("minecraft:guardian"),
-            ActorType::ElderGuardian => String::from("minecraft:elder_guardian"),
-            ActorType::Npc => String::from("minecraft:npc"),
-            ActorType::WitherBoss => unimplemented!(),
-            ActorType::Dragon => unimplemented!(),
-            ActorType::Shulker => String::from("minecraft:shulker"),
-            ActorType::Endermite => String::from("minecraft:endermite"),
-            ActorType::Agent => String::from("minecraft:agent"),
-            ActorType::Vindicator => String::from("minecraft:vindicator"),
-            ActorType::Phantom => String::from("minecraft:phantom"),
-            ActorType::IllagerBeast => unimplemented!(),
-            ActorType::ArmorStand => String::from("minecraft:armor_stand"),
-            ActorType::TripodCamera => unimplemented!(),
-            ActorType::Player => String::from("minecraft:player"),
-            ActorType::ItemEntity => unimplemented!(),
-            ActorType::PrimedTnt => String::from("minecraft:tnt"),
-            ActorType::FallingBlock => String::from("minecraft:falling_block"),
-            ActorType::MovingBlock => unimplemented!(),
-            ActorType::ExperiencePotion => unimplemented!(),
-            ActorType::Experience => unimplemented!(),
-            ActorType::EyeOfEnder => unimplemented!(),
-            ActorType::EnderCrystal => unimplemented!(),
-            ActorType::FireworksRocket => unimplemented!(),
-            ActorType::Trident => unimplemented!(),
-            ActorType::Turtle => String::from("minecraft:turtle"),
-            ActorType::Cat => String::from("minecraft:cat"),
-            ActorType::ShulkerBullet => String::from("minecraft:shulker_bullet"),
-            ActorType::FishingHook => String::from("minecraft:fishing_hook"),
-            ActorType::Chalkboard => unimplemented!(),
-            ActorType::DragonFireball => unimplemented!(),
-            ActorType::Arrow => String::from("minecraft:arrow"),
-            ActorType::Snowball => String::from("minecraft:snowball"),
-            ActorType::ThrownEgg => unimplemented!(),
-            ActorType::Painting => String::from("minecraft:painting"),
-            ActorType::LargeFireball => unimplemented!(),
-            ActorType::ThrownPotion => unimplemented!(),
-            ActorType::Enderpearl => String::from("minecraft:ender_pearl"),
-            ActorType::LeashKnot => unimplemented!(),
-            ActorType::WitherSkull => String::from("minecraft:wither_skull"),
-            ActorType::BoatRideable => unimplemented!(),
-            ActorType::WitherSkullDangerous => unimplemented!(),
-            ActorType::LightningBolt => unimplemented!(),
-            ActorType::SmallFireball => String::from("minecraft:small_fireball"),
-            ActorType::AreaEffectCloud => String::from("minecraft:area_effect_cloud"),
-            ActorType::LingeringPotion => unimplemented!(),
-            ActorType::LlamaSpit => String::from("minecraft:llama_spit"),
-            ActorType::EvocationFang => String::from("minecraft:evocation_fang"),
-            ActorType::EvocationIllager => unimplemented!(),
-            ActorType::Vex => String::from("minecraft:vex"),
-            ActorType::MinecartRideable => unimplemented!(),
-            ActorType::MinecartHopper => String::from("minecraft:hopper_minecart"),
-            ActorType::MinecartTNT => String::from("minecraft:tnt_minecart"),
-            ActorType::MinecartChest => String::from("minecraft:chest_minecart"),
-            ActorType::MinecartFurnace => unimplemented!(),
-            ActorType::MinecartCommandBlock => unimplemented!(),
-            ActorType::IceBomb => unimplemented!(),
-            ActorType::Balloon => String::from("minecraft:balloon"),
-            ActorType::Pufferfish => String::from("minecraft:pufferfish"),
-            ActorType::Salmon => String::from("minecraft:salmon"),
-            ActorType::Drowned => String::from("minecraft:drowned"),
-            ActorType::Tropicalfish => String::from("minecraft:tropicalfish"),
-            ActorType::Fish => unimplemented!(),
-            ActorType::Panda => String::from("minecraft:panda"),
-            ActorType::Pillager => String::from("minecraft:pillager"),
-            ActorType::VillagerV2 => String::from("minecraft:villager_v2"),
-            ActorType::ZombieVillagerV2 => String::from("minecraft:zombie_villager_v2"),
-            ActorType::Shield => unimplemented!(),
-            ActorType::WanderingTrader => String::from("minecraft:wandering_trader"),
-            ActorType::Lectern => unimplemented!(),
-            ActorType::ElderGuardianGhost => unimplemented!(),
-            ActorType::Fox => String::from("minecraft:fox"),
-            ActorType::Bee => String::from("minecraft:bee"),
-            ActorType::Piglin => String::from("minecraft:piglin"),
-            ActorType::Hoglin => String::from("minecraft:hoglin"),
-            ActorType::Strider => String::from("minecraft:strider"),
-            ActorType::Zoglin => String::from("minecraft:zoglin"),
-            ActorType::PiglinBrute => String::from("minecraft:piglin_brute"),
-            ActorType::Goat => String::from("minecraft:goat"),
-            ActorType::GlowSquid => String::from("minecraft:glow_squid"),
-            ActorType::Axolotl => String::from("minecraft:axolotl"),
-            ActorType::Warden => String::from("minecraft:warden"),
-            ActorType::Frog => String::from("minecraft:frog"),
-            ActorType::Tadpole => String::from("minecraft:tadpole"),
-            ActorType::Allay => String::from("minecraft:allay"),
-            ActorType::ChestBoatRideable => unimplemented!(),
-            ActorType::TraderLlama => unimplemented!(),
-            ActorType::Camel => String::from("minecraft:camel"),
-            ActorType::Sniffer => unimplemented!(),
-            ActorType::Breeze => unimplemented!(),
-            ActorType::BreezeWindChargeProjectile => unimplemented!(),
-            ActorType::Armadillo => unimplemented!(),
-            ActorType::WindChargeProjectile => unimplemented!(),
-            ActorType::Bogged => unimplemented!(),
-            ActorType::OminousItemSpawner => unimplemented!(),
+            ActorType::Undefined => None,
+            ActorType::TypeMask => None,
+            ActorType::Mob => None,
+            ActorType::PathfinderMob => None,
+            ActorType::Monster => None,
+            ActorType::Animal => None,
+            ActorType::TamableAnimal => None,
+            ActorType::Ambient => None,
+            ActorType::UndeadMob => None,
+            ActorType::ZombieMonster => None,
+            ActorType::Arthropod => None,
+            ActorType::Minecart => Some("minecraft:minecart"),
+            ActorType::SkeletonMonster => None,
+            ActorType::EquineAnimal => None,
+            ActorType::Projectile => None,
+            ActorType::AbstractArrow => None,
+            ActorType::WaterAnimal => None,
+            ActorType::VillagerBase => None,
+            ActorType::Chicken => Some("minecraft:chicken"),
+            ActorType::Cow => Some("minecraft:cow"),
+            ActorType::Pig => Some("minecraft:pig"),
+            ActorType::Sheep => Some("minecraft:sheep"),
+            ActorType::Wolf => Some("minecraft:wolf"),
+            ActorType::Villager => Some("minecraft:villager"),
+            ActorType::MushroomCow => Some("minecraft:mooshroom"),
+            ActorType::Squid => Some("minecraft:squid"),
+            ActorType::Rabbit => Some("minecraft:rabbit"),
+            ActorType::Bat => Some("minecraft:bat"),
+            ActorType::IronGolem => Some("minecraft:iron_golem"),
+            ActorType::SnowGolem => Some("minecraft:snow_golem"),
+            ActorType::Ocelot => Some("minecraft:ocelot"),
+            ActorType::Horse => Some("minecraft:horse"),
+            ActorType::PolarBear => Some("minecraft:polar_bear"),
+            ActorType::Llama => Some("minecraft:llama"),
+            ActorType::Parrot => Some("minecraft:parrot"),
+            ActorType::Dolphin => Some("minecraft:dolphin"),
+            ActorType::Donkey => Some("minecraft:donkey"),
+            ActorType::Mule => Some("minecraft:mule"),
+            ActorType::SkeletonHorse => Some("minecraft:skeleton_horse"),
+            ActorType::ZombieHorse => Some("minecraft:zombie_horse"),
+            ActorType::Zombie => Some("minecraft:zombie"),
+            ActorType::Creeper => Some("minecraft:creeper"),
+            ActorType::Skeleton => Some("minecraft:skeleton"),
+            ActorType::Spider => Some("minecraft:spider"),
+            ActorType::PigZombie => Some("minecraft:zombie_pigman"),
+            ActorType::Slime => Some("minecraft:slime"),
+            ActorType::EnderMan => Some("minecraft:enderman"),
+            ActorType::Silverfish => Some("minecraft:silverfish"),
+            ActorType::CaveSpider => Some("minecraft:cave_spider"),
+            ActorType::Ghast => Some("minecraft:ghast"),
+            ActorType::LavaSlime => Some("minecraft:magma_cube"),
+            ActorType::Blaze => Some("minecraft:blaze"),
+            ActorType::ZombieVillager => Some("minecraft:zombie_villager"),
+            ActorType::Witch => Some("minecraft:witch"),
+            ActorType::Stray => Some("minecraft:stray"),
+            ActorType::Husk => Some("minecraft:husk"),
+            ActorType::WitherSkeleton => Some("minecraft:wither_skeleton"),
+            ActorType::Guardian => Some("minecraft:guardian"),
+            ActorType::ElderGuardian => Some("minecraft:elder_guardian"),
+            ActorType::Npc => Some("minecraft:npc"),
+            ActorType::WitherBoss => Some("minecraft:wither"),
+            ActorType::Dragon => Some("minecraft:ender_dragon"),
+            ActorType::Shulker => Some("minecraft:shulker"),
+            ActorType::Endermite => Some("minecraft:endermite"),
+            ActorType::Agent => Some("minecraft:agent"),
+            ActorType::Vindicator => Some("minecraft:vindicator"),
+            ActorType::Phantom => Some("minecraft:phantom"),
+            ActorType::IllagerBeast => Some("minecraft:ravager"),
+            ActorType::ArmorStand => Some("minecraft:armor_stand"),
+            ActorType::TripodCamera => Some("minecraft:tripod_camera"),
+            ActorType::Player => Some("minecraft:player"),
+            ActorType::ItemEntity => Some("minecraft:item"),
+            ActorType::PrimedTnt => Some("minecraft:tnt"),
+            ActorType::FallingBlock => Some("minecraft:falling_block"),
+            ActorType::MovingBlock => Some("minecraft:moving_block"),
+            ActorType::ExperiencePotion => Some("minecraft:xp_bottle"),
+            ActorType::Experience => Some("minecraft:xp_orb"),
+            ActorType::EyeOfEnder => Some("minecraft:eye_of_ender_signal"),
+            ActorType::EnderCrystal => Some("minecraft:ender_crystal"),
+            ActorType::FireworksRocket => Some("minecraft:fireworks_rocket"),
+            ActorType::Trident => Some("minecraft:thrown_trident"),
+            ActorType::Turtle => Some("minecraft:turtle"),
+            ActorType::Cat => Some("minecraft:cat"),
+            ActorType::ShulkerBullet => Some("minecraft:shulker_bullet"),
+            ActorType::FishingHook => Some("minecraft:fishing_hook"),
+            ActorType::Chalkboard => None,
+            ActorType::DragonFireball => Some("minecraft:dragon_fireball"),
+            ActorType::Arrow => Some("minecraft:arrow"),
+            ActorType::Snowball => Some("minecraft:snowball"),
+            ActorType::ThrownEgg => Some("minecraft:egg"),
+            ActorType::Painting => Some("minecraft:painting"),
+            ActorType::LargeFireball => Some("minecraft:fireball"),
+            ActorType::ThrownPotion => Some("minecraft:splash_potion"),
+            ActorType::Enderpearl => Some("minecraft:ender_pearl"),
+            ActorType::LeashKnot => Some("minecraft:leash_knot"),
+            ActorType::WitherSkull => Some("minecraft:wither_skull"),
+            ActorType::BoatRideable => Some("minecraft:boat"),
+            ActorType::WitherSkullDangerous => Some("minecraft:wither_skull_dangerous"),
+            ActorType::LightningBolt => Some("minecraft:lightning_bolt"),
+            ActorType::SmallFireball => Some("minecraft:small_fireball"),
+            ActorType::AreaEffectCloud => Some("minecraft:area_effect_cloud"),
+            ActorType::LingeringPotion => Some("minecraft:lingering_potion"),
+            ActorType::LlamaSpit => Some("minecraft:llama_spit"),
+            ActorType::EvocationFang => Some("minecraft:evocation_fang"),
+            ActorType::EvocationIllager => Some("minecraft:evocation_illager"),
+            ActorType::Vex => Some("minecraft:vex"),
+            ActorType::MinecartRideable => Some("minecraft:minecart"),
+            ActorType::MinecartHopper => Some("minecraft:hopper_minecart"),
+            ActorType::MinecartTNT => Some("minecraft:tnt_minecart"),
+            ActorType::MinecartChest => Some("minecraft:chest_minecart"),
+            ActorType::MinecartFurnace => None,
+            ActorType::MinecartCommandBlock => Some("minecraft:command_block_minecart"),
+            ActorType::IceBomb => Some("minecraft:ice_bomb"),
+            ActorType::Balloon => Some("minecraft:balloon"),
+            ActorType::Pufferfish => Some("minecraft:pufferfish"),
+            ActorType::Salmon => Some("minecraft:salmon"),
+            ActorType::Drowned => Some("minecraft:drowned"),
+            ActorType::Tropicalfish => Some("minecraft:tropicalfish"),
+            ActorType::Fish => None,
+            ActorType::Panda => Some("minecraft:panda"),
+            ActorType::Pillager => Some("minecraft:pillager"),
+            ActorType::VillagerV2 => Some("minecraft:villager_v2"),
+            ActorType::ZombieVillagerV2 => Some("minecraft:zombie_villager_v2"),
+            ActorType::Shield => None,
+            ActorType::WanderingTrader => Some("minecraft:wandering_trader"),
+            ActorType::Lectern => None,
+            ActorType::ElderGuardianGhost => Some("minecraft:elder_guardian_ghost"),
+            ActorType::Fox => Some("minecraft:fox"),
+            ActorType::Bee => Some("minecraft:bee"),
+            ActorType::Piglin => Some("minecraft:piglin"),
+            ActorType::Hoglin => Some("minecraft:hoglin"),
+            ActorType::Strider => Some("minecraft:strider"),
+            ActorType::Zoglin => Some("minecraft:zoglin"),
+            ActorType::PiglinBrute => Some("minecraft:piglin_brute"),
+            ActorType::Goat => Some("minecraft:goat"),
+            ActorType::GlowSquid => Some("minecraft:glow_squid"),
+            ActorType::Axolotl => Some("minecraft:axolotl"),
+            ActorType::Warden => Some("minecraft:warden"),
+            ActorType::Frog => Some("minecraft:frog"),
+            ActorType::Tadpole => Some("minecraft:tadpole"),
+            ActorType::Allay => Some("minecraft:allay"),
+            ActorType::ChestBoatRideable => Some("minecraft:chest_boat"),
+            ActorType::TraderLlama => Some("minecraft:trader_llama"),
+            ActorType::Camel => Some("minecraft:camel"),
+            ActorType::Sniffer => Some("minecraft:sniffer"),
+            ActorType::Breeze => Some("minecraft:breeze"),
+            ActorType::BreezeWindChargeProjectile => {
+                Some("minecraft:breeze_wind_charge_projectile")
+            }
+            ActorType::Armadillo => Some("minecraft:armadillo"),
+            ActorType::WindChargeProjectile => Some("minecraft:wind_charge_projectile"),
+            ActorType::Bogged => Some("minecraft:bogged"),
+            ActorType::OminousItemSpawner => Some("minecraft:ominous_item_spawner"),
         }
     }
 }
 
+/// Writes the identifier of the actor, nothing for types without one.
+impl Display for ActorType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.identifier().unwrap_or_default())
+    }
+}
+
 impl ProtoCodec for ActorType {
     fn proto_serialize(
         &self,
@@ -483,3 +496,14 @@ impl ProtoCodec for ActorType {
         unimplemented!()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_the_identifier() {
+        assert_eq!(ActorType::Zombie.to_string(), "minecraft:zombie");
+        assert_eq!(ActorType::Undefined.to_string(), "");
+    }
+}
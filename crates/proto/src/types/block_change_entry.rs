@@ -0,0 +1,30 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::block_update_flags::BlockUpdateFlags;
+use crate::types::network_block_pos::NetworkBlockPos;
+
+/// A single block change in an `UpdateSubChunkBlocksPacket`.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct BlockChangeEntry {
+    /// The absolute position of the block
+    pub position: NetworkBlockPos,
+    pub block_runtime_id: VAR<u32>,
+    pub flags: BlockUpdateFlags,
+    /// The unique id of the actor linked to the change, 0 for none
+    pub synced_update_actor_unique_id: VAR<u64>,
+    /// An [`ActorBlockSyncMessage`](crate::types::actor_block_sync_message::ActorBlockSyncMessage) id
+    pub synced_update_type: VAR<u32>,
+}
+
+impl BlockChangeEntry {
+    pub fn new(position: NetworkBlockPos, block_runtime_id: u32, flags: BlockUpdateFlags) -> Self {
+        Self {
+            position,
+            block_runtime_id: VAR::new(block_runtime_id),
+            flags,
+            synced_update_actor_unique_id: VAR::new(0),
+            synced_update_type: VAR::new(0),
+        }
+    }
+}
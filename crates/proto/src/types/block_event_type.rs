@@ -0,0 +1,11 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(VAR::<i32>)]
+pub enum BlockEventType {
+    None = 0,
+    /// Opens (data 1) or closes (data 0) chests and shulker boxes,
+    /// or moves pistons and rings bells
+    ChangeState = 1,
+}
@@ -0,0 +1,10 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// The layers of a block position, the liquid layer holds the water of waterlogged blocks.
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[enum_repr(VAR::<u32>)]
+pub enum BlockLayer {
+    Standard = 0,
+    Liquid = 1,
+}
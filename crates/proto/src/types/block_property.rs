@@ -0,0 +1,11 @@
+use bedrockrs_nbt as nbt;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// A custom block sent to the client in the [`StartGamePacket`](crate::packets::start_game::StartGamePacket).
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct BlockProperty {
+    /// The identifier of the block, e.g. `custom:block`.
+    pub name: String,
+    /// The block definition as a network NBT compound.
+    pub definition: nbt::Value,
+}
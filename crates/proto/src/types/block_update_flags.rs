@@ -0,0 +1,32 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bitflags::bitflags;
+
+bitflags! {
+    /// How the client applies a block change.
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+    pub struct BlockUpdateFlags: u32 {
+        /// Updates the neighbours of the block
+        const NEIGHBORS = 0x1;
+        const NETWORK = 0x2;
+        /// Changes the block without rerendering it
+        const NO_GRAPHICS = 0x4;
+        /// Rerenders the block with priority
+        const PRIORITY = 0x8;
+    }
+}
+
+impl ProtoCodec for BlockUpdateFlags {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        VAR::<u32>::new(self.bits()).proto_serialize(stream)
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(Self::from_bits_retain(
+            VAR::<u32>::proto_deserialize(stream)?.into_inner(),
+        ))
+    }
+}
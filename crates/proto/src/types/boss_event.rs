@@ -0,0 +1,154 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+
+use crate::types::boss_bar_color::{BossBarColor, BossBarOverlay};
+
+#[derive(Debug, Clone)]
+pub enum BossEvent {
+    /// Shows the boss bar, the boss actor has to be known by the client
+    Add {
+        name: String,
+        /// The progress of the bar from 0 to 1
+        health_percent: LE<f32>,
+        darken_screen: LE<u16>,
+        color: BossBarColor,
+        overlay: BossBarOverlay,
+    },
+    /// Sent by the client to register itself as viewer of the boss bar
+    PlayerAdded {
+        player_unique_id: ActorUniqueID,
+    },
+    /// Hides the boss bar
+    Remove,
+    /// Sent by the client to unregister itself as viewer of the boss bar
+    PlayerRemoved {
+        player_unique_id: ActorUniqueID,
+    },
+    UpdatePercent {
+        health_percent: LE<f32>,
+    },
+    UpdateName {
+        name: String,
+    },
+    UpdateProperties {
+        darken_screen: LE<u16>,
+        color: BossBarColor,
+        overlay: BossBarOverlay,
+    },
+    UpdateStyle {
+        color: BossBarColor,
+        overlay: BossBarOverlay,
+    },
+    /// Sent by the client to request the boss bar
+    Query {
+        player_unique_id: ActorUniqueID,
+    },
+}
+
+impl ProtoCodec for BossEvent {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match self {
+            BossEvent::Add {
+                name,
+                health_percent,
+                darken_screen,
+                color,
+                overlay,
+            } => {
+                VAR::<u32>::new(0).proto_serialize(stream)?;
+                name.proto_serialize(stream)?;
+                health_percent.proto_serialize(stream)?;
+                darken_screen.proto_serialize(stream)?;
+                color.proto_serialize(stream)?;
+                overlay.proto_serialize(stream)?;
+            }
+            BossEvent::PlayerAdded { player_unique_id } => {
+                VAR::<u32>::new(1).proto_serialize(stream)?;
+                player_unique_id.proto_serialize(stream)?;
+            }
+            BossEvent::Remove => {
+                VAR::<u32>::new(2).proto_serialize(stream)?;
+            }
+            BossEvent::PlayerRemoved { player_unique_id } => {
+                VAR::<u32>::new(3).proto_serialize(stream)?;
+                player_unique_id.proto_serialize(stream)?;
+            }
+            BossEvent::UpdatePercent { health_percent } => {
+                VAR::<u32>::new(4).proto_serialize(stream)?;
+                health_percent.proto_serialize(stream)?;
+            }
+            BossEvent::UpdateName { name } => {
+                VAR::<u32>::new(5).proto_serialize(stream)?;
+                name.proto_serialize(stream)?;
+            }
+            BossEvent::UpdateProperties {
+                darken_screen,
+                color,
+                overlay,
+            } => {
+                VAR::<u32>::new(6).proto_serialize(stream)?;
+                darken_screen.proto_serialize(stream)?;
+                color.proto_serialize(stream)?;
+                overlay.proto_serialize(stream)?;
+            }
+            BossEvent::UpdateStyle { color, overlay } => {
+                VAR::<u32>::new(7).proto_serialize(stream)?;
+                color.proto_serialize(stream)?;
+                overlay.proto_serialize(stream)?;
+            }
+            BossEvent::Query { player_unique_id } => {
+                VAR::<u32>::new(8).proto_serialize(stream)?;
+                player_unique_id.proto_serialize(stream)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(match VAR::<u32>::proto_deserialize(stream)?.into_inner() {
+            0 => BossEvent::Add {
+                name: String::proto_deserialize(stream)?,
+                health_percent: LE::<f32>::proto_deserialize(stream)?,
+                darken_screen: LE::<u16>::proto_deserialize(stream)?,
+                color: BossBarColor::proto_deserialize(stream)?,
+                overlay: BossBarOverlay::proto_deserialize(stream)?,
+            },
+            1 => BossEvent::PlayerAdded {
+                player_unique_id: ActorUniqueID::proto_deserialize(stream)?,
+            },
+            2 => BossEvent::Remove,
+            3 => BossEvent::PlayerRemoved {
+                player_unique_id: ActorUniqueID::proto_deserialize(stream)?,
+            },
+            4 => BossEvent::UpdatePercent {
+                health_percent: LE::<f32>::proto_deserialize(stream)?,
+            },
+            5 => BossEvent::UpdateName {
+                name: String::proto_deserialize(stream)?,
+            },
+            6 => BossEvent::UpdateProperties {
+                darken_screen: LE::<u16>::proto_deserialize(stream)?,
+                color: BossBarColor::proto_deserialize(stream)?,
+                overlay: BossBarOverlay::proto_deserialize(stream)?,
+            },
+            7 => BossEvent::UpdateStyle {
+                color: BossBarColor::proto_deserialize(stream)?,
+                overlay: BossBarOverlay::proto_deserialize(stream)?,
+            },
+            8 => BossEvent::Query {
+                player_unique_id: ActorUniqueID::proto_deserialize(stream)?,
+            },
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("BossEvent"),
+                ))
+            }
+        })
+    }
+}
@@ -0,0 +1,10 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// A blob of the client blob cache together with its id, the xxHash64 of the payload.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct CacheBlob {
+    pub id: LE<u64>,
+    #[len_repr(VAR::<u32>)]
+    pub payload: Vec<u8>,
+}
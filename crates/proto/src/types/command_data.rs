@@ -0,0 +1,157 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// A command shown to the client, all names are referenced by their index
+/// into the tables of the [`AvailableCommandsPacket`](crate::packets::available_commands_packet::AvailableCommandsPacket).
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct CommandData {
+    pub name: String,
+    pub description: String,
+    /// A combination of the `FLAG_*` constants
+    pub flags: LE<u16>,
+    pub permission_level: CommandPermissionLevel,
+    /// The index of the enum holding the aliases of the command, `-1` for none
+    pub aliases_enum_index: LE<i32>,
+    /// Indices into the chained subcommands of the packet
+    #[len_repr(VAR::<u32>)]
+    pub chained_subcommand_indices: Vec<LE<u16>>,
+    #[len_repr(VAR::<u32>)]
+    pub overloads: Vec<CommandOverload>,
+}
+
+impl CommandData {
+    pub const FLAG_TEST_USAGE: u16 = 0x1;
+    /// Hides the command from command blocks
+    pub const FLAG_HIDDEN_FROM_COMMAND_BLOCKS: u16 = 0x2;
+    /// Hides the command from players
+    pub const FLAG_HIDDEN_FROM_PLAYERS: u16 = 0x4;
+    pub const FLAG_HIDDEN_FROM_AUTOMATION: u16 = 0x8;
+    pub const FLAG_LOCAL_SYNC: u16 = 0x10;
+    pub const FLAG_EXECUTE_DISALLOWED: u16 = 0x20;
+    pub const FLAG_MESSAGE_TYPE: u16 = 0x40;
+    pub const FLAG_NOT_CHEAT: u16 = 0x80;
+    pub const FLAG_ASYNC: u16 = 0x100;
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[enum_repr(LE::<u8>)]
+pub enum CommandPermissionLevel {
+    Any = 0,
+    GameDirectors = 1,
+    Admin = 2,
+    Host = 3,
+    Owner = 4,
+    Internal = 5,
+}
+
+/// One possible list of parameters of a command.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct CommandOverload {
+    /// If the overload chains into subcommands, like `/execute`
+    pub chaining: bool,
+    #[len_repr(VAR::<u32>)]
+    pub parameters: Vec<CommandParameter>,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct CommandParameter {
+    pub name: String,
+    /// A basic [`CommandArgType`] or an index into the enums, soft enums or suffixes,
+    /// combined with the matching `FLAG_*` constants
+    pub param_type: LE<u32>,
+    pub optional: bool,
+    /// A combination of the `OPTION_*` constants
+    pub options: u8,
+}
+
+impl CommandParameter {
+    pub const FLAG_VALID: u32 = 0x100000;
+    pub const FLAG_ENUM: u32 = 0x200000;
+    pub const FLAG_SUFFIXED: u32 = 0x1000000;
+    pub const FLAG_SOFT_ENUM: u32 = 0x4000000;
+
+    /// Shows the enum as a single parameter instead of listing its values
+    pub const OPTION_COLLAPSE_ENUM: u8 = 0x1;
+    pub const OPTION_HAS_SEMANTIC_CONSTRAINT: u8 = 0x2;
+    pub const OPTION_AS_CHAINED_COMMAND: u8 = 0x4;
+}
+
+/// The basic types of command parameters.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CommandArgType {
+    Int,
+    Float,
+    Value,
+    WildcardInt,
+    Operator,
+    CompareOperator,
+    /// A player name or target selector
+    Target,
+    /// A player name, target selector or `*`
+    WildcardTarget,
+    Filepath,
+    IntegerRange,
+    EquipmentSlots,
+    String,
+    BlockPosition,
+    /// A position, which may be relative using `~` and `^`
+    Position,
+    /// The rest of the command as text
+    Message,
+    RawText,
+    Json,
+    BlockStates,
+    /// The rest of the command as another command
+    Command,
+}
+
+impl CommandArgType {
+    pub fn id(&self) -> u32 {
+        match self {
+            CommandArgType::Int => 1,
+            CommandArgType::Float => 3,
+            CommandArgType::Value => 4,
+            CommandArgType::WildcardInt => 5,
+            CommandArgType::Operator => 6,
+            CommandArgType::CompareOperator => 7,
+            CommandArgType::Target => 8,
+            CommandArgType::WildcardTarget => 10,
+            CommandArgType::Filepath => 17,
+            CommandArgType::IntegerRange => 23,
+            CommandArgType::EquipmentSlots => 47,
+            CommandArgType::String => 56,
+            CommandArgType::BlockPosition => 64,
+            CommandArgType::Position => 65,
+            CommandArgType::Message => 68,
+            CommandArgType::RawText => 70,
+            CommandArgType::Json => 74,
+            CommandArgType::BlockStates => 84,
+            CommandArgType::Command => 87,
+        }
+    }
+
+    pub fn from_id(id: u32) -> Option<Self> {
+        Some(match id {
+            1 => CommandArgType::Int,
+            3 => CommandArgType::Float,
+            4 => CommandArgType::Value,
+            5 => CommandArgType::WildcardInt,
+            6 => CommandArgType::Operator,
+            7 => CommandArgType::CompareOperator,
+            8 => CommandArgType::Target,
+            10 => CommandArgType::WildcardTarget,
+            17 => CommandArgType::Filepath,
+            23 => CommandArgType::IntegerRange,
+            47 => CommandArgType::EquipmentSlots,
+            56 => CommandArgType::String,
+            64 => CommandArgType::BlockPosition,
+            65 => CommandArgType::Position,
+            68 => CommandArgType::Message,
+            70 => CommandArgType::RawText,
+            74 => CommandArgType::Json,
+            84 => CommandArgType::BlockStates,
+            87 => CommandArgType::Command,
+            _ => return None,
+        })
+    }
+}
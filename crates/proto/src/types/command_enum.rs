@@ -0,0 +1,54 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// An enum of fixed values, the values are indices into the enum values of the
+/// [`AvailableCommandsPacket`](crate::packets::available_commands_packet::AvailableCommandsPacket).
+///
+/// The size of the indices depends on the amount of enum values,
+/// so the packet serializes them itself.
+#[derive(Debug, Clone)]
+pub struct CommandEnum {
+    pub name: String,
+    pub value_indices: Vec<u32>,
+}
+
+/// An enum whose values can be changed later with the `UpdateSoftEnum` packet,
+/// for example a list of warps.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct CommandSoftEnum {
+    pub name: String,
+    #[len_repr(VAR::<u32>)]
+    pub values: Vec<String>,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ChainedSubcommand {
+    pub name: String,
+    #[len_repr(VAR::<u32>)]
+    pub values: Vec<ChainedSubcommandValue>,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ChainedSubcommandValue {
+    /// The index into the chained subcommand values of the packet
+    pub index: LE<u16>,
+    /// The type of the value
+    pub value: LE<u16>,
+}
+
+/// Restricts when a value of an enum may be used.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct CommandEnumConstraint {
+    pub enum_value_index: LE<u32>,
+    pub enum_index: LE<u32>,
+    #[len_repr(VAR::<u32>)]
+    pub constraints: Vec<CommandConstraint>,
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
+pub enum CommandConstraint {
+    CheatsEnabled = 0,
+    OperatorPermissions = 1,
+    HostPermissions = 2,
+}
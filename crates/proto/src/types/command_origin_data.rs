@@ -1,7 +1,13 @@
+use std::io::Cursor;
+
 use bedrockrs_core::int::VAR;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
 use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
 use uuid::Uuid;
-#[derive(ProtoCodec, Debug, Clone)]
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
 #[enum_repr(VAR::<u32>)]
 pub enum CommandOriginType {
     Player = 0,
@@ -22,9 +28,51 @@ pub enum CommandOriginType {
     ExecuteContext = 15,
 }
 
-#[derive(ProtoCodec, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub struct CommandOriginData {
     pub command_type: CommandOriginType,
     pub command_uuid: Uuid,
     pub request_id: String,
+    /// Only sent for the dev console and test origins
+    pub player_unique_id: Option<ActorUniqueID>,
+}
+
+impl ProtoCodec for CommandOriginData {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.command_type.proto_serialize(stream)?;
+        self.command_uuid.proto_serialize(stream)?;
+        self.request_id.proto_serialize(stream)?;
+
+        if matches!(
+            self.command_type,
+            CommandOriginType::DevConsole | CommandOriginType::Test
+        ) {
+            self.player_unique_id
+                .clone()
+                .unwrap_or(ActorUniqueID(0))
+                .proto_serialize(stream)?;
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let command_type = CommandOriginType::proto_deserialize(stream)?;
+        let command_uuid = Uuid::proto_deserialize(stream)?;
+        let request_id = String::proto_deserialize(stream)?;
+
+        let player_unique_id = match command_type {
+            CommandOriginType::DevConsole | CommandOriginType::Test => {
+                Some(ActorUniqueID::proto_deserialize(stream)?)
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            command_type,
+            command_uuid,
+            request_id,
+            player_unique_id,
+        })
+    }
 }
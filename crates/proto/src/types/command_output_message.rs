@@ -0,0 +1,22 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_derive::ProtoCodec;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct CommandOutputMessage {
+    pub is_success: bool,
+    /// The message or a translation key like `commands.generic.unknown`
+    pub message_id: String,
+    /// Parameters filled into the translated message
+    #[len_repr(VAR::<u32>)]
+    pub parameters: Vec<String>,
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
+pub enum CommandOutputType {
+    None = 0,
+    LastOutput = 1,
+    Silent = 2,
+    AllOutput = 3,
+    DataSet = 4,
+}
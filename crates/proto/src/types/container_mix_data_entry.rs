@@ -0,0 +1,11 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// Brewing a potion container with a reagent into another container,
+/// for example a potion with gunpowder into a splash potion.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ContainerMixDataEntry {
+    pub input_item_id: VAR<i32>,
+    pub reagent_item_id: VAR<i32>,
+    pub output_item_id: VAR<i32>,
+}
@@ -0,0 +1,75 @@
+use bedrockrs_core::int::LE;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// Identifies a group of slots in item stack requests and responses,
+/// unlike [`ContainerID`](super::container_id::ContainerID) it also distinguishes the
+/// individual parts of a container, for example the input and result slots of a furnace.
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[enum_repr(LE::<u8>)]
+pub enum ContainerName {
+    AnvilInput = 0,
+    AnvilMaterial = 1,
+    AnvilResultPreview = 2,
+    SmithingTableInput = 3,
+    SmithingTableMaterial = 4,
+    SmithingTableResultPreview = 5,
+    Armor = 6,
+    LevelEntity = 7,
+    BeaconPayment = 8,
+    BrewingInput = 9,
+    BrewingResult = 10,
+    BrewingFuel = 11,
+    CombinedHotbarAndInventory = 12,
+    CraftingInput = 13,
+    CraftingOutputPreview = 14,
+    RecipeConstruction = 15,
+    RecipeNature = 16,
+    RecipeItems = 17,
+    RecipeSearch = 18,
+    RecipeSearchBar = 19,
+    RecipeEquipment = 20,
+    RecipeBook = 21,
+    EnchantingInput = 22,
+    EnchantingMaterial = 23,
+    FurnaceFuel = 24,
+    FurnaceIngredient = 25,
+    FurnaceResult = 26,
+    HorseEquip = 27,
+    Hotbar = 28,
+    Inventory = 29,
+    ShulkerBox = 30,
+    TradeIngredient1 = 31,
+    TradeIngredient2 = 32,
+    TradeResultPreview = 33,
+    Offhand = 34,
+    CompoundCreatorInput = 35,
+    CompoundCreatorOutputPreview = 36,
+    ElementConstructorOutputPreview = 37,
+    MaterialReducerInput = 38,
+    MaterialReducerOutput = 39,
+    LabTableInput = 40,
+    LoomInput = 41,
+    LoomDye = 42,
+    LoomMaterial = 43,
+    LoomResultPreview = 44,
+    BlastFurnaceIngredient = 45,
+    SmokerIngredient = 46,
+    Trade2Ingredient1 = 47,
+    Trade2Ingredient2 = 48,
+    Trade2ResultPreview = 49,
+    GrindstoneInput = 50,
+    GrindstoneAdditional = 51,
+    GrindstoneResultPreview = 52,
+    StonecutterInput = 53,
+    StonecutterResultPreview = 54,
+    CartographyInput = 55,
+    CartographyAdditional = 56,
+    CartographyResultPreview = 57,
+    Barrel = 58,
+    /// The item held by the cursor
+    Cursor = 59,
+    CreatedOutput = 60,
+    SmithingTableTemplate = 61,
+    CrafterLevelEntity = 62,
+    DynamicContainer = 63,
+}
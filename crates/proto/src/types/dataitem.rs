@@ -1,17 +1,38 @@
 use std::collections::HashMap;
+use std::io::Cursor;
 
 use bedrockrs_core::{
     int::{LE, VAR},
     Vec3,
 };
 use bedrockrs_nbt as nbt;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
 use bedrockrs_proto_derive::ProtoCodec;
 
+use crate::types::actor_data_key::ActorDataKey;
+use crate::types::actor_flags::ActorFlags;
+
 #[derive(ProtoCodec, Debug, Clone)]
 pub struct DataItem {
     pub id: VAR<u32>,
     pub value: DataItemValue,
 }
+
+impl DataItem {
+    pub fn new(key: ActorDataKey, value: DataItemValue) -> Self {
+        Self {
+            id: key.into(),
+            value,
+        }
+    }
+
+    /// The known key of the data item, `None` for keys this protocol version doesn't know.
+    pub fn key(&self) -> Option<ActorDataKey> {
+        ActorDataKey::try_from(self.id.into_inner()).ok()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum DataItemValue {
     ValByte(u8),
@@ -21,21 +42,327 @@ pub enum DataItemValue {
     ValString(String),
     ValCompoundTag(HashMap<String, nbt::Value>),
     ValPos(Vec3<VAR<i32>>),
+    /// Sent as a var long
     ValInt64(LE<i64>),
     ValVec3(Vec3<LE<f32>>),
 }
-use bedrockrs_proto_core::ProtoCodec;
+
+impl DataItemValue {
+    const BYTE_ID: u32 = 0;
+    const SHORT_ID: u32 = 1;
+    const INT_ID: u32 = 2;
+    const FLOAT_ID: u32 = 3;
+    const STRING_ID: u32 = 4;
+    const COMPOUND_TAG_ID: u32 = 5;
+    const POS_ID: u32 = 6;
+    const INT64_ID: u32 = 7;
+    const VEC3_ID: u32 = 8;
+}
+
 impl ProtoCodec for DataItemValue {
-    fn proto_serialize(
-        &self,
-        stream: &mut Vec<u8>,
-    ) -> Result<(), bedrockrs_proto_core::error::ProtoCodecError> {
-        unimplemented!()
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match self {
+            DataItemValue::ValByte(v) => {
+                VAR::<u32>::new(Self::BYTE_ID).proto_serialize(stream)?;
+                v.proto_serialize(stream)?;
+            }
+            DataItemValue::ValShort(v) => {
+                VAR::<u32>::new(Self::SHORT_ID).proto_serialize(stream)?;
+                LE::<i16>::new(*v).proto_serialize(stream)?;
+            }
+            DataItemValue::ValInt(v) => {
+                VAR::<u32>::new(Self::INT_ID).proto_serialize(stream)?;
+                VAR::<i32>::new(*v).proto_serialize(stream)?;
+            }
+            DataItemValue::ValFloat(v) => {
+                VAR::<u32>::new(Self::FLOAT_ID).proto_serialize(stream)?;
+                LE::<f32>::new(*v).proto_serialize(stream)?;
+            }
+            DataItemValue::ValString(v) => {
+                VAR::<u32>::new(Self::STRING_ID).proto_serialize(stream)?;
+                v.proto_serialize(stream)?;
+            }
+            DataItemValue::ValCompoundTag(v) => {
+                VAR::<u32>::new(Self::COMPOUND_TAG_ID).proto_serialize(stream)?;
+                v.proto_serialize(stream)?;
+            }
+            DataItemValue::ValPos(v) => {
+                VAR::<u32>::new(Self::POS_ID).proto_serialize(stream)?;
+                v.proto_serialize(stream)?;
+            }
+            DataItemValue::ValInt64(v) => {
+                VAR::<u32>::new(Self::INT64_ID).proto_serialize(stream)?;
+                VAR::<i64>::new(v.into_inner()).proto_serialize(stream)?;
+            }
+            DataItemValue::ValVec3(v) => {
+                VAR::<u32>::new(Self::VEC3_ID).proto_serialize(stream)?;
+                v.proto_serialize(stream)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(match VAR::<u32>::proto_deserialize(stream)?.into_inner() {
+            Self::BYTE_ID => DataItemValue::ValByte(u8::proto_deserialize(stream)?),
+            Self::SHORT_ID => {
+                DataItemValue::ValShort(LE::<i16>::proto_deserialize(stream)?.into_inner())
+            }
+            Self::INT_ID => {
+                DataItemValue::ValInt(VAR::<i32>::proto_deserialize(stream)?.into_inner())
+            }
+            Self::FLOAT_ID => {
+                DataItemValue::ValFloat(LE::<f32>::proto_deserialize(stream)?.into_inner())
+            }
+            Self::STRING_ID => DataItemValue::ValString(String::proto_deserialize(stream)?),
+            Self::COMPOUND_TAG_ID => {
+                DataItemValue::ValCompoundTag(HashMap::proto_deserialize(stream)?)
+            }
+            Self::POS_ID => DataItemValue::ValPos(Vec3::<VAR<i32>>::proto_deserialize(stream)?),
+            Self::INT64_ID => DataItemValue::ValInt64(LE::new(
+                VAR::<i64>::proto_deserialize(stream)?.into_inner(),
+            )),
+            Self::VEC3_ID => DataItemValue::ValVec3(Vec3::<LE<f32>>::proto_deserialize(stream)?),
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("DataItemValue"),
+                ))
+            }
+        })
+    }
+}
+
+/// Builds the metadata of an actor, every key is only set once, setting it again overwrites it.
+///
+/// ```ignore
+/// let actor_data = ActorDataBuilder::new()
+///     .flags(ActorFlags::HAS_GRAVITY | ActorFlags::HAS_COLLISION | ActorFlags::ALWAYS_SHOW_NAME)
+///     .name_tag("§aShopkeeper")
+///     .scale(1.5)
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ActorDataBuilder {
+    items: Vec<DataItem>,
+}
+
+impl ActorDataBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets any data item, prefer the typed setters for known keys.
+    pub fn set(mut self, key: ActorDataKey, value: DataItemValue) -> Self {
+        self.items.retain(|item| item.key() != Some(key));
+        self.items.push(DataItem::new(key, value));
+        self
+    }
+
+    /// Sets all 128 flags, flags that aren't contained get unset.
+    pub fn flags(self, flags: ActorFlags) -> Self {
+        self.set(
+            ActorDataKey::Flags,
+            DataItemValue::ValInt64(LE::new(flags.lower())),
+        )
+        .set(
+            ActorDataKey::FlagsTwo,
+            DataItemValue::ValInt64(LE::new(flags.upper())),
+        )
+    }
+
+    pub fn name_tag(self, name_tag: impl Into<String>) -> Self {
+        self.set(
+            ActorDataKey::Name,
+            DataItemValue::ValString(name_tag.into()),
+        )
+    }
+
+    pub fn always_show_name_tag(self, always_show_name_tag: bool) -> Self {
+        self.set(
+            ActorDataKey::AlwaysShowNameTag,
+            DataItemValue::ValByte(always_show_name_tag as u8),
+        )
+    }
+
+    /// The text shown below the name tag.
+    pub fn score_tag(self, score_tag: impl Into<String>) -> Self {
+        self.set(
+            ActorDataKey::Score,
+            DataItemValue::ValString(score_tag.into()),
+        )
+    }
+
+    pub fn scale(self, scale: f32) -> Self {
+        self.set(ActorDataKey::Scale, DataItemValue::ValFloat(scale))
+    }
+
+    pub fn bounding_box(self, width: f32, height: f32) -> Self {
+        self.set(ActorDataKey::Width, DataItemValue::ValFloat(width))
+            .set(ActorDataKey::Height, DataItemValue::ValFloat(height))
+    }
+
+    pub fn variant(self, variant: i32) -> Self {
+        self.set(ActorDataKey::Variant, DataItemValue::ValInt(variant))
+    }
+
+    pub fn mark_variant(self, mark_variant: i32) -> Self {
+        self.set(
+            ActorDataKey::MarkVariant,
+            DataItemValue::ValInt(mark_variant),
+        )
+    }
+
+    pub fn color(self, color: u8) -> Self {
+        self.set(ActorDataKey::ColorIndex, DataItemValue::ValByte(color))
+    }
+
+    pub fn air_supply(self, air_supply: i16, air_supply_max: i16) -> Self {
+        self.set(ActorDataKey::AirSupply, DataItemValue::ValShort(air_supply))
+            .set(
+                ActorDataKey::AirSupplyMax,
+                DataItemValue::ValShort(air_supply_max),
+            )
+    }
+
+    pub fn owner(self, owner: i64) -> Self {
+        self.set(ActorDataKey::Owner, DataItemValue::ValInt64(LE::new(owner)))
     }
 
-    fn proto_deserialize(
-        stream: &mut std::io::Cursor<&[u8]>,
-    ) -> Result<Self, bedrockrs_proto_core::error::ProtoCodecError> {
-        unimplemented!()
+    pub fn target(self, target: i64) -> Self {
+        self.set(
+            ActorDataKey::Target,
+            DataItemValue::ValInt64(LE::new(target)),
+        )
+    }
+
+    pub fn leash_holder(self, leash_holder: i64) -> Self {
+        self.set(
+            ActorDataKey::LeashHolder,
+            DataItemValue::ValInt64(LE::new(leash_holder)),
+        )
+    }
+
+    pub fn build(self) -> Vec<DataItem> {
+        self.items
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(item: &DataItem) -> DataItem {
+        let mut stream = vec![];
+        item.proto_serialize(&mut stream).unwrap();
+
+        let mut cursor = Cursor::new(stream.as_slice());
+        let decoded = DataItem::proto_deserialize(&mut cursor).unwrap();
+        assert_eq!(cursor.position() as usize, stream.len());
+
+        decoded
+    }
+
+    #[test]
+    fn values_round_trip() {
+        let actor_data = ActorDataBuilder::new()
+            .flags(ActorFlags::HAS_GRAVITY | ActorFlags::LAYING_DOWN)
+            .name_tag("Shopkeeper")
+            .always_show_name_tag(true)
+            .scale(1.5)
+            .variant(-3)
+            .air_supply(300, 400)
+            .owner(-1)
+            .set(
+                ActorDataKey::PlayerLastDeathPosition,
+                DataItemValue::ValPos(Vec3 {
+                    x: VAR::new(-12),
+                    y: VAR::new(64),
+                    z: VAR::new(7),
+                }),
+            )
+            .set(
+                ActorDataKey::CollisionBox,
+                DataItemValue::ValVec3(Vec3 {
+                    x: LE::new(0.6),
+                    y: LE::new(1.8),
+                    z: LE::new(0.6),
+                }),
+            )
+            .build();
+
+        for item in &actor_data {
+            let decoded = round_trip(item);
+            assert_eq!(decoded.key(), item.key());
+
+            match (&decoded.value, &item.value) {
+                (DataItemValue::ValByte(a), DataItemValue::ValByte(b)) => assert_eq!(a, b),
+                (DataItemValue::ValShort(a), DataItemValue::ValShort(b)) => assert_eq!(a, b),
+                (DataItemValue::ValInt(a), DataItemValue::ValInt(b)) => assert_eq!(a, b),
+                (DataItemValue::ValFloat(a), DataItemValue::ValFloat(b)) => assert_eq!(a, b),
+                (DataItemValue::ValString(a), DataItemValue::ValString(b)) => assert_eq!(a, b),
+                (DataItemValue::ValInt64(a), DataItemValue::ValInt64(b)) => {
+                    assert_eq!(a.into_inner(), b.into_inner())
+                }
+                (DataItemValue::ValPos(a), DataItemValue::ValPos(b)) => {
+                    assert_eq!(
+                        (a.x.into_inner(), a.y.into_inner(), a.z.into_inner()),
+                        (b.x.into_inner(), b.y.into_inner(), b.z.into_inner())
+                    )
+                }
+                (DataItemValue::ValVec3(a), DataItemValue::ValVec3(b)) => {
+                    assert_eq!(
+                        (a.x.into_inner(), a.y.into_inner(), a.z.into_inner()),
+                        (b.x.into_inner(), b.y.into_inner(), b.z.into_inner())
+                    )
+                }
+                (decoded, value) => panic!("Expected {value:?}, got {decoded:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn flags_are_split_into_halves() {
+        let flags = ActorFlags::HAS_GRAVITY | ActorFlags::LAYING_DOWN | ActorFlags::ON_FIRE;
+        let actor_data = ActorDataBuilder::new().flags(flags).build();
+
+        let half = |key| {
+            let item = actor_data
+                .iter()
+                .find(|item| item.key() == Some(key))
+                .unwrap();
+            match round_trip(item).value {
+                DataItemValue::ValInt64(half) => half.into_inner(),
+                other => panic!("Expected a long, got {other:?}"),
+            }
+        };
+
+        let lower = half(ActorDataKey::Flags);
+        let upper = half(ActorDataKey::FlagsTwo);
+
+        assert_eq!(upper, 1);
+        assert_eq!(ActorFlags::from_halves(lower, upper), flags);
+    }
+
+    #[test]
+    fn setting_a_key_again_overwrites_it() {
+        let actor_data = ActorDataBuilder::new().scale(1.0).scale(2.0).build();
+
+        assert_eq!(actor_data.len(), 1);
+        assert!(matches!(actor_data[0].value, DataItemValue::ValFloat(scale) if scale == 2.0));
+    }
+
+    #[test]
+    fn unknown_keys_are_kept() {
+        let item = DataItem {
+            id: VAR::new(10_000),
+            value: DataItemValue::ValInt(1),
+        };
+
+        let decoded = round_trip(&item);
+
+        assert_eq!(decoded.id.into_inner(), 10_000);
+        assert_eq!(decoded.key(), None);
     }
 }
@@ -7,9 +7,9 @@ use std::sync::Arc;
 
 #[derive(ProtoCodec, Debug, Clone)]
 pub struct GameRule {
-    name: String,
-    editable: bool,
-    value: GameRuleValue,
+    pub name: String,
+    pub editable: bool,
+    pub value: GameRuleValue,
 }
 
 #[derive(Debug, Clone)]
@@ -21,32 +21,32 @@ pub enum GameRuleValue {
 
 impl ProtoCodec for GameRuleValue {
     fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
-        let int = VAR::<i32>::new(match self {
+        // The type of the game rule comes first, followed by its value
+        match self {
             GameRuleValue::ValBool(v) => {
+                VAR::<u32>::new(1).proto_serialize(stream)?;
                 v.proto_serialize(stream)?;
-                1
             }
             GameRuleValue::ValVarU32(v) => {
+                VAR::<u32>::new(2).proto_serialize(stream)?;
                 VAR::<u32>::new(*v)
                     .write(stream)
                     .map_err(|e| ProtoCodecError::IOError(Arc::new(e)))?;
-                2
             }
             GameRuleValue::ValF32(v) => {
+                VAR::<u32>::new(3).proto_serialize(stream)?;
                 LE::<f32>::new(*v)
                     .write(stream)
                     .map_err(|e| ProtoCodecError::IOError(Arc::new(e)))?;
-                3
             }
-        });
+        };
 
-        int.write(stream)
-            .map_err(|e| ProtoCodecError::IOError(Arc::new(e)))
+        Ok(())
     }
 
     fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
         Ok(
-            match VAR::<i32>::read(stream)
+            match VAR::<u32>::read(stream)
                 .map_err(|e| ProtoCodecError::IOError(Arc::new(e)))?
                 .into_inner()
             {
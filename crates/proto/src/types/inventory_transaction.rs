@@ -1,12 +1,210 @@
-use bedrockrs_core::int::LE;
+use std::io::Cursor;
 
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+use crate::types::network_block_pos::NetworkBlockPos;
+use crate::types::network_item_stack_descriptor::NetworkItemStackDescriptor;
+
+/// The slots of a container that were changed by a legacy request.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct LegacySetItemSlot {
+    pub container_id: u8,
+    #[len_repr(VAR::<u32>)]
+    pub slots: Vec<u8>,
+}
+
+/// Where the items of an [`InventoryAction`] come from or go to.
 #[derive(Debug, Clone)]
+pub enum InventorySource {
+    Container {
+        container_id: VAR<i32>,
+    },
+    Global,
+    World {
+        flags: VAR<u32>,
+    },
+    Creative,
+    /// Used for crafting, enchanting and other slots that aren't part of a container yet
+    NonImplementedTodo {
+        container_id: VAR<i32>,
+    },
+}
+
+impl ProtoCodec for InventorySource {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match self {
+            InventorySource::Container { container_id } => {
+                VAR::<u32>::new(0).proto_serialize(stream)?;
+                container_id.proto_serialize(stream)?;
+            }
+            InventorySource::Global => {
+                VAR::<u32>::new(1).proto_serialize(stream)?;
+            }
+            InventorySource::World { flags } => {
+                VAR::<u32>::new(2).proto_serialize(stream)?;
+                flags.proto_serialize(stream)?;
+            }
+            InventorySource::Creative => {
+                VAR::<u32>::new(3).proto_serialize(stream)?;
+            }
+            InventorySource::NonImplementedTodo { container_id } => {
+                VAR::<u32>::new(99999).proto_serialize(stream)?;
+                container_id.proto_serialize(stream)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(match VAR::<u32>::proto_deserialize(stream)?.into_inner() {
+            0 => InventorySource::Container {
+                container_id: VAR::<i32>::proto_deserialize(stream)?,
+            },
+            1 => InventorySource::Global,
+            2 => InventorySource::World {
+                flags: VAR::<u32>::proto_deserialize(stream)?,
+            },
+            3 => InventorySource::Creative,
+            99999 => InventorySource::NonImplementedTodo {
+                container_id: VAR::<i32>::proto_deserialize(stream)?,
+            },
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("InventorySource"),
+                ))
+            }
+        })
+    }
+}
+
+/// A single item change in a slot, the server checks the old item to detect desyncs.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct InventoryAction {
+    pub source: InventorySource,
+    pub slot: VAR<u32>,
+    pub from_item: NetworkItemStackDescriptor,
+    pub to_item: NetworkItemStackDescriptor,
+}
+
+#[derive(Debug, Clone)]
+pub enum InventoryTransaction {
+    /// Moves items between slots, only used for dropping items with server authoritative inventories
+    Normal,
+    /// Sent by the client if it thinks the inventories are out of sync, the server
+    /// should answer by resending the contents of the inventories
+    Mismatch,
+    /// Placing/breaking blocks and using items on blocks or in the air
+    UseItem(UseItemTransactionData),
+    /// Attacking or interacting with an actor
+    UseItemOnEntity(UseItemOnEntityTransactionData),
+    /// Releasing a bow or consuming an item
+    ReleaseItem(ReleaseItemTransactionData),
+}
+
+impl InventoryTransaction {
+    pub(crate) fn type_id(&self) -> u32 {
+        match self {
+            InventoryTransaction::Normal => 0,
+            InventoryTransaction::Mismatch => 1,
+            InventoryTransaction::UseItem(_) => 2,
+            InventoryTransaction::UseItemOnEntity(_) => 3,
+            InventoryTransaction::ReleaseItem(_) => 4,
+        }
+    }
+
+    /// Serializes the data of the transaction, without its type id.
+    pub(crate) fn serialize_data(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match self {
+            InventoryTransaction::Normal | InventoryTransaction::Mismatch => Ok(()),
+            InventoryTransaction::UseItem(data) => data.proto_serialize(stream),
+            InventoryTransaction::UseItemOnEntity(data) => data.proto_serialize(stream),
+            InventoryTransaction::ReleaseItem(data) => data.proto_serialize(stream),
+        }
+    }
+
+    /// Deserializes the data of the transaction with the given type id.
+    pub(crate) fn deserialize_data(
+        type_id: u32,
+        stream: &mut Cursor<&[u8]>,
+    ) -> Result<Self, ProtoCodecError> {
+        Ok(match type_id {
+            0 => InventoryTransaction::Normal,
+            1 => InventoryTransaction::Mismatch,
+            2 => InventoryTransaction::UseItem(UseItemTransactionData::proto_deserialize(stream)?),
+            3 => InventoryTransaction::UseItemOnEntity(
+                UseItemOnEntityTransactionData::proto_deserialize(stream)?,
+            ),
+            4 => InventoryTransaction::ReleaseItem(ReleaseItemTransactionData::proto_deserialize(
+                stream,
+            )?),
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("InventoryTransaction"),
+                ))
+            }
+        })
+    }
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
 pub struct UseItemTransactionData {
-    /// legacy_request_id is an ID that is only non-zero at times when sent by the client. The server should
-    /// always send 0 for this. When this field is not 0, the LegacySetItemSlots slice below will have values
-    /// in it.
-    /// legacy_request_id ties in with the ItemStackResponse packet. If this field is non-0, the server should
-    /// respond with an ItemStackResponse packet. Some inventory actions such as dropping an item out of the
-    /// hotbar are still one using this packet, and the ItemStackResponse packet needs to tie in with it.
-    legacy_request_id: LE<i32>,
+    pub action_type: UseItemAction,
+    pub block_position: NetworkBlockPos,
+    pub block_face: VAR<i32>,
+    pub hotbar_slot: VAR<i32>,
+    pub held_item: NetworkItemStackDescriptor,
+    pub player_position: Vec3<LE<f32>>,
+    /// The position that was clicked relative to the block
+    pub click_position: Vec3<LE<f32>>,
+    /// The runtime id of the clicked block
+    pub block_runtime_id: VAR<u32>,
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(VAR::<u32>)]
+pub enum UseItemAction {
+    ClickBlock = 0,
+    ClickAir = 1,
+    BreakBlock = 2,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct UseItemOnEntityTransactionData {
+    pub target_runtime_id: ActorRuntimeID,
+    pub action_type: UseItemOnEntityAction,
+    pub hotbar_slot: VAR<i32>,
+    pub held_item: NetworkItemStackDescriptor,
+    pub player_position: Vec3<LE<f32>>,
+    /// The position that was clicked relative to the actor
+    pub click_position: Vec3<LE<f32>>,
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(VAR::<u32>)]
+pub enum UseItemOnEntityAction {
+    Interact = 0,
+    Attack = 1,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ReleaseItemTransactionData {
+    pub action_type: ReleaseItemAction,
+    pub hotbar_slot: VAR<i32>,
+    pub held_item: NetworkItemStackDescriptor,
+    pub head_position: Vec3<LE<f32>>,
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(VAR::<u32>)]
+pub enum ReleaseItemAction {
+    Release = 0,
+    Consume = 1,
 }
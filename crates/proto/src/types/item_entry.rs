@@ -0,0 +1,14 @@
+use bedrockrs_core::int::LE;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// An entry of the item registry sent to the client in the
+/// [`StartGamePacket`](crate::packets::start_game::StartGamePacket).
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ItemEntry {
+    /// The identifier of the item, e.g. `minecraft:stone`.
+    pub name: String,
+    /// The runtime ID the item is referred to with in item stacks.
+    pub runtime_id: LE<i16>,
+    /// If the item is defined by components (custom items).
+    pub component_based: bool,
+}
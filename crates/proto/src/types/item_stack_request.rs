@@ -0,0 +1,418 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::container_name::ContainerName;
+use crate::types::network_item_instance_descriptor::NetworkItemInstanceDescriptor;
+use crate::types::recipe_ingredient::RecipeIngredient;
+
+/// A request of the client to change its inventory, all actions of a request
+/// are either accepted or rejected together.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ItemStackRequest {
+    /// Unique id of the request, the response has to contain the same id.
+    /// Client side ids are negative and count down.
+    pub request_id: VAR<i32>,
+    #[len_repr(VAR::<u32>)]
+    pub actions: Vec<ItemStackRequestAction>,
+    /// Strings the client wants to have filtered, for example the name of an item renamed in an anvil
+    #[len_repr(VAR::<u32>)]
+    pub filter_strings: Vec<String>,
+    pub filter_cause: LE<i32>,
+}
+
+/// A slot referenced by an [`ItemStackRequestAction`].
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ItemStackRequestSlotInfo {
+    pub container: ContainerName,
+    pub slot: u8,
+    /// The network id of the stack the client thinks is in the slot,
+    /// negative if it references a stack created earlier in the same request
+    pub stack_network_id: VAR<i32>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ItemStackRequestAction {
+    /// Takes `count` items from the source and puts them into the destination, usually the cursor
+    Take {
+        count: u8,
+        source: ItemStackRequestSlotInfo,
+        destination: ItemStackRequestSlotInfo,
+    },
+    /// Places `count` items from the source, usually the cursor, into the destination
+    Place {
+        count: u8,
+        source: ItemStackRequestSlotInfo,
+        destination: ItemStackRequestSlotInfo,
+    },
+    /// Swaps the whole stacks of both slots
+    Swap {
+        source: ItemStackRequestSlotInfo,
+        destination: ItemStackRequestSlotInfo,
+    },
+    /// Drops `count` items of the source into the world
+    Drop {
+        count: u8,
+        source: ItemStackRequestSlotInfo,
+        randomly: bool,
+    },
+    /// Destroys `count` items of the source, used by creative players to remove items
+    Destroy {
+        count: u8,
+        source: ItemStackRequestSlotInfo,
+    },
+    /// Consumes `count` items of the source as input of a crafting action
+    Consume {
+        count: u8,
+        source: ItemStackRequestSlotInfo,
+    },
+    /// Creates the result with the given index of a previous crafting action in the created output slot
+    Create {
+        results_slot: u8,
+    },
+    PlaceInContainer {
+        count: u8,
+        source: ItemStackRequestSlotInfo,
+        destination: ItemStackRequestSlotInfo,
+    },
+    TakeOutContainer {
+        count: u8,
+        source: ItemStackRequestSlotInfo,
+        destination: ItemStackRequestSlotInfo,
+    },
+    LabTableCombine,
+    /// Pays for the effects of a beacon
+    BeaconPayment {
+        primary_effect: VAR<i32>,
+        secondary_effect: VAR<i32>,
+    },
+    /// Damages the held item by mining a block
+    MineBlock {
+        hotbar_slot: VAR<i32>,
+        predicted_durability: VAR<i32>,
+        stack_network_id: VAR<i32>,
+    },
+    CraftRecipe {
+        recipe_network_id: VAR<u32>,
+        number_of_crafts: u8,
+    },
+    /// Crafting a recipe from the recipe book, the client puts the ingredients into the grid itself
+    CraftRecipeAuto {
+        recipe_network_id: VAR<u32>,
+        number_of_crafts: u8,
+        times_crafted: u8,
+        ingredients: Vec<RecipeIngredient>,
+    },
+    /// Takes an item out of the creative inventory
+    CraftCreative {
+        creative_item_network_id: VAR<u32>,
+        number_of_crafts: u8,
+    },
+    /// Crafting with an additional filtered string, for example renaming in an anvil
+    CraftRecipeOptional {
+        recipe_network_id: VAR<u32>,
+        /// The index into the filter strings of the request
+        filter_string_index: LE<i32>,
+    },
+    CraftGrindstone {
+        recipe_network_id: VAR<u32>,
+        number_of_crafts: u8,
+        cost: VAR<i32>,
+    },
+    CraftLoom {
+        pattern: String,
+        times_crafted: u8,
+    },
+    CraftNonImplemented,
+    /// Sent after crafting actions with the results the client expects, only informative
+    CraftResultsDeprecated {
+        result_items: Vec<NetworkItemInstanceDescriptor>,
+        times_crafted: u8,
+    },
+}
+
+impl ItemStackRequestAction {
+    fn id(&self) -> u8 {
+        match self {
+            ItemStackRequestAction::Take { .. } => 0,
+            ItemStackRequestAction::Place { .. } => 1,
+            ItemStackRequestAction::Swap { .. } => 2,
+            ItemStackRequestAction::Drop { .. } => 3,
+            ItemStackRequestAction::Destroy { .. } => 4,
+            ItemStackRequestAction::Consume { .. } => 5,
+            ItemStackRequestAction::Create { .. } => 6,
+            ItemStackRequestAction::PlaceInContainer { .. } => 7,
+            ItemStackRequestAction::TakeOutContainer { .. } => 8,
+            ItemStackRequestAction::LabTableCombine => 9,
+            ItemStackRequestAction::BeaconPayment { .. } => 10,
+            ItemStackRequestAction::MineBlock { .. } => 11,
+            ItemStackRequestAction::CraftRecipe { .. } => 12,
+            ItemStackRequestAction::CraftRecipeAuto { .. } => 13,
+            ItemStackRequestAction::CraftCreative { .. } => 14,
+            ItemStackRequestAction::CraftRecipeOptional { .. } => 15,
+            ItemStackRequestAction::CraftGrindstone { .. } => 16,
+            ItemStackRequestAction::CraftLoom { .. } => 17,
+            ItemStackRequestAction::CraftNonImplemented => 18,
+            ItemStackRequestAction::CraftResultsDeprecated { .. } => 19,
+        }
+    }
+}
+
+impl ProtoCodec for ItemStackRequestAction {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.id().proto_serialize(stream)?;
+
+        match self {
+            ItemStackRequestAction::Take {
+                count,
+                source,
+                destination,
+            }
+            | ItemStackRequestAction::Place {
+                count,
+                source,
+                destination,
+            }
+            | ItemStackRequestAction::PlaceInContainer {
+                count,
+                source,
+                destination,
+            }
+            | ItemStackRequestAction::TakeOutContainer {
+                count,
+                source,
+                destination,
+            } => {
+                count.proto_serialize(stream)?;
+                source.proto_serialize(stream)?;
+                destination.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::Swap {
+                source,
+                destination,
+            } => {
+                source.proto_serialize(stream)?;
+                destination.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::Drop {
+                count,
+                source,
+                randomly,
+            } => {
+                count.proto_serialize(stream)?;
+                source.proto_serialize(stream)?;
+                randomly.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::Destroy { count, source }
+            | ItemStackRequestAction::Consume { count, source } => {
+                count.proto_serialize(stream)?;
+                source.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::Create { results_slot } => {
+                results_slot.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::LabTableCombine
+            | ItemStackRequestAction::CraftNonImplemented => {}
+            ItemStackRequestAction::BeaconPayment {
+                primary_effect,
+                secondary_effect,
+            } => {
+                primary_effect.proto_serialize(stream)?;
+                secondary_effect.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::MineBlock {
+                hotbar_slot,
+                predicted_durability,
+                stack_network_id,
+            } => {
+                hotbar_slot.proto_serialize(stream)?;
+                predicted_durability.proto_serialize(stream)?;
+                stack_network_id.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::CraftRecipe {
+                recipe_network_id,
+                number_of_crafts,
+            } => {
+                recipe_network_id.proto_serialize(stream)?;
+                number_of_crafts.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::CraftRecipeAuto {
+                recipe_network_id,
+                number_of_crafts,
+                times_crafted,
+                ingredients,
+            } => {
+                recipe_network_id.proto_serialize(stream)?;
+                number_of_crafts.proto_serialize(stream)?;
+                times_crafted.proto_serialize(stream)?;
+
+                let len: u8 = ingredients.len().try_into()?;
+                len.proto_serialize(stream)?;
+
+                for ingredient in ingredients {
+                    ingredient.proto_serialize(stream)?;
+                }
+            }
+            ItemStackRequestAction::CraftCreative {
+                creative_item_network_id,
+                number_of_crafts,
+            } => {
+                creative_item_network_id.proto_serialize(stream)?;
+                number_of_crafts.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::CraftRecipeOptional {
+                recipe_network_id,
+                filter_string_index,
+            } => {
+                recipe_network_id.proto_serialize(stream)?;
+                filter_string_index.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::CraftGrindstone {
+                recipe_network_id,
+                number_of_crafts,
+                cost,
+            } => {
+                recipe_network_id.proto_serialize(stream)?;
+                number_of_crafts.proto_serialize(stream)?;
+                cost.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::CraftLoom {
+                pattern,
+                times_crafted,
+            } => {
+                pattern.proto_serialize(stream)?;
+                times_crafted.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::CraftResultsDeprecated {
+                result_items,
+                times_crafted,
+            } => {
+                VAR::<u32>::new(result_items.len().try_into()?).proto_serialize(stream)?;
+
+                for item in result_items {
+                    item.proto_serialize(stream)?;
+                }
+
+                times_crafted.proto_serialize(stream)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(match u8::proto_deserialize(stream)? {
+            0 => ItemStackRequestAction::Take {
+                count: u8::proto_deserialize(stream)?,
+                source: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+                destination: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+            },
+            1 => ItemStackRequestAction::Place {
+                count: u8::proto_deserialize(stream)?,
+                source: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+                destination: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+            },
+            2 => ItemStackRequestAction::Swap {
+                source: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+                destination: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+            },
+            3 => ItemStackRequestAction::Drop {
+                count: u8::proto_deserialize(stream)?,
+                source: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+                randomly: bool::proto_deserialize(stream)?,
+            },
+            4 => ItemStackRequestAction::Destroy {
+                count: u8::proto_deserialize(stream)?,
+                source: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+            },
+            5 => ItemStackRequestAction::Consume {
+                count: u8::proto_deserialize(stream)?,
+                source: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+            },
+            6 => ItemStackRequestAction::Create {
+                results_slot: u8::proto_deserialize(stream)?,
+            },
+            7 => ItemStackRequestAction::PlaceInContainer {
+                count: u8::proto_deserialize(stream)?,
+                source: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+                destination: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+            },
+            8 => ItemStackRequestAction::TakeOutContainer {
+                count: u8::proto_deserialize(stream)?,
+                source: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+                destination: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+            },
+            9 => ItemStackRequestAction::LabTableCombine,
+            10 => ItemStackRequestAction::BeaconPayment {
+                primary_effect: VAR::<i32>::proto_deserialize(stream)?,
+                secondary_effect: VAR::<i32>::proto_deserialize(stream)?,
+            },
+            11 => ItemStackRequestAction::MineBlock {
+                hotbar_slot: VAR::<i32>::proto_deserialize(stream)?,
+                predicted_durability: VAR::<i32>::proto_deserialize(stream)?,
+                stack_network_id: VAR::<i32>::proto_deserialize(stream)?,
+            },
+            12 => ItemStackRequestAction::CraftRecipe {
+                recipe_network_id: VAR::<u32>::proto_deserialize(stream)?,
+                number_of_crafts: u8::proto_deserialize(stream)?,
+            },
+            13 => {
+                let recipe_network_id = VAR::<u32>::proto_deserialize(stream)?;
+                let number_of_crafts = u8::proto_deserialize(stream)?;
+                let times_crafted = u8::proto_deserialize(stream)?;
+
+                let len = u8::proto_deserialize(stream)?;
+                let mut ingredients = vec![];
+                for _ in 0..len {
+                    ingredients.push(RecipeIngredient::proto_deserialize(stream)?);
+                }
+
+                ItemStackRequestAction::CraftRecipeAuto {
+                    recipe_network_id,
+                    number_of_crafts,
+                    times_crafted,
+                    ingredients,
+                }
+            }
+            14 => ItemStackRequestAction::CraftCreative {
+                creative_item_network_id: VAR::<u32>::proto_deserialize(stream)?,
+                number_of_crafts: u8::proto_deserialize(stream)?,
+            },
+            15 => ItemStackRequestAction::CraftRecipeOptional {
+                recipe_network_id: VAR::<u32>::proto_deserialize(stream)?,
+                filter_string_index: LE::<i32>::proto_deserialize(stream)?,
+            },
+            16 => ItemStackRequestAction::CraftGrindstone {
+                recipe_network_id: VAR::<u32>::proto_deserialize(stream)?,
+                number_of_crafts: u8::proto_deserialize(stream)?,
+                cost: VAR::<i32>::proto_deserialize(stream)?,
+            },
+            17 => ItemStackRequestAction::CraftLoom {
+                pattern: String::proto_deserialize(stream)?,
+                times_crafted: u8::proto_deserialize(stream)?,
+            },
+            18 => ItemStackRequestAction::CraftNonImplemented,
+            19 => {
+                let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+                let mut result_items = vec![];
+                for _ in 0..len {
+                    result_items.push(NetworkItemInstanceDescriptor::proto_deserialize(stream)?);
+                }
+
+                ItemStackRequestAction::CraftResultsDeprecated {
+                    result_items,
+                    times_crafted: u8::proto_deserialize(stream)?,
+                }
+            }
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("ItemStackRequestAction"),
+                ))
+            }
+        })
+    }
+}
@@ -0,0 +1,100 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::container_name::ContainerName;
+
+/// The answer to an [`ItemStackRequest`](super::item_stack_request::ItemStackRequest).
+#[derive(Debug, Clone)]
+pub struct ItemStackResponse {
+    pub result: ItemStackResponseResult,
+    pub request_id: VAR<i32>,
+    /// The new contents of all slots changed by the request, only sent if the request succeeded
+    pub containers: Vec<ItemStackResponseContainerInfo>,
+}
+
+impl ItemStackResponse {
+    /// Accepts the request, the client applies the given slot changes.
+    pub fn ok(request_id: i32, containers: Vec<ItemStackResponseContainerInfo>) -> Self {
+        Self {
+            result: ItemStackResponseResult::Ok,
+            request_id: VAR::new(request_id),
+            containers,
+        }
+    }
+
+    /// Rejects the request, the client reverts all its actions.
+    pub fn error(request_id: i32) -> Self {
+        Self {
+            result: ItemStackResponseResult::Error,
+            request_id: VAR::new(request_id),
+            containers: vec![],
+        }
+    }
+}
+
+impl ProtoCodec for ItemStackResponse {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.result.proto_serialize(stream)?;
+        self.request_id.proto_serialize(stream)?;
+
+        if self.result == ItemStackResponseResult::Ok {
+            VAR::<u32>::new(self.containers.len().try_into()?).proto_serialize(stream)?;
+
+            for container in &self.containers {
+                container.proto_serialize(stream)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let result = ItemStackResponseResult::proto_deserialize(stream)?;
+        let request_id = VAR::<i32>::proto_deserialize(stream)?;
+
+        let mut containers = vec![];
+        if result == ItemStackResponseResult::Ok {
+            let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+
+            for _ in 0..len {
+                containers.push(ItemStackResponseContainerInfo::proto_deserialize(stream)?);
+            }
+        }
+
+        Ok(Self {
+            result,
+            request_id,
+            containers,
+        })
+    }
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
+pub enum ItemStackResponseResult {
+    Ok = 0,
+    Error = 1,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ItemStackResponseContainerInfo {
+    pub container: ContainerName,
+    #[len_repr(VAR::<u32>)]
+    pub slots: Vec<ItemStackResponseSlotInfo>,
+}
+
+/// The final state of a slot after the request was applied.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ItemStackResponseSlotInfo {
+    pub slot: u8,
+    pub hotbar_slot: u8,
+    pub count: u8,
+    pub stack_network_id: VAR<i32>,
+    pub custom_name: String,
+    pub filtered_custom_name: String,
+    pub durability_correction: VAR<i32>,
+}
@@ -0,0 +1,144 @@
+use bedrockrs_core::int::VAR;
+
+id_enum! {
+    /// The events sent with the `LevelEvent` packet, most of them play a sound or show particles.
+    /// Newer events and particles shown by their type id are decoded as [`LevelEvent::Unknown`].
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    #[repr(i32)]
+    pub enum LevelEvent: i32 {
+        /// An event without a variant, like events added by newer versions
+        Unknown,
+        SoundClick = 1000,
+        SoundClickFail = 1001,
+        SoundLaunch = 1002,
+        /// Doors, trapdoors and fence gates opening or closing
+        SoundOpenDoor = 1003,
+        SoundFizz = 1004,
+        SoundFuse = 1005,
+        SoundGhastWarning = 1007,
+        SoundGhastFireball = 1008,
+        SoundBlazeFireball = 1009,
+        /// A zombie banging on a wooden door
+        SoundZombieWoodenDoor = 1010,
+        SoundZombieDoorCrash = 1012,
+        SoundEndermanTeleport = 1018,
+        SoundAnvilBroken = 1020,
+        SoundAnvilUsed = 1021,
+        SoundAnvilLand = 1022,
+        SoundInfinityArrowPickup = 1030,
+        SoundTeleportEnderPearl = 1032,
+        SoundAddItem = 1040,
+        SoundItemFrameBreak = 1041,
+        SoundItemFramePlace = 1042,
+        SoundItemFrameRemoveItem = 1043,
+        SoundItemFrameRotateItem = 1044,
+        SoundExperienceOrbPickup = 1051,
+        SoundTotemUsed = 1052,
+        SoundArmorStandBreak = 1060,
+        SoundArmorStandHit = 1061,
+        SoundArmorStandLand = 1062,
+        SoundArmorStandPlace = 1063,
+        ParticlesShoot = 2000,
+        /// The data is the runtime id of the destroyed block
+        ParticlesDestroyBlock = 2001,
+        ParticlesPotionSplash = 2002,
+        ParticlesEyeOfEnderDeath = 2003,
+        ParticlesMobBlockSpawn = 2004,
+        /// Bone meal particles
+        ParticleCropGrowth = 2005,
+        ParticleSoundGuardianGhost = 2006,
+        ParticleDeathSmoke = 2007,
+        ParticleDenyBlock = 2008,
+        ParticleGenericSpawn = 2009,
+        ParticlesDragonEgg = 2010,
+        ParticlesCropEaten = 2011,
+        ParticlesCritical = 2012,
+        ParticlesTeleport = 2013,
+        /// The data is the runtime id of the block and the face shifted by 24 bits
+        ParticlesCrackBlock = 2014,
+        ParticlesBubble = 2015,
+        ParticlesEvaporate = 2016,
+        ParticlesDestroyArmorStand = 2017,
+        ParticlesBreakingEgg = 2018,
+        ParticleDestroyEgg = 2019,
+        ParticlesEvaporateWater = 2020,
+        ParticlesDestroyBlockNoSound = 2021,
+        ParticlesKnockbackRoar = 2022,
+        ParticlesTeleportTrail = 2023,
+        ParticlesPointCloud = 2024,
+        ParticlesExplosion = 2025,
+        ParticlesBlockExplosion = 2026,
+        ParticlesVibrationSignal = 2027,
+        ParticlesDripstoneDrip = 2028,
+        ParticlesFizzEffect = 2029,
+        /// Honeycomb used on a copper block
+        ParticlesWaxOn = 2030,
+        ParticlesWaxOff = 2031,
+        ParticlesScrape = 2032,
+        ParticlesElectricSpark = 2033,
+        ParticleTurtleEgg = 2034,
+        ParticleSculkShriek = 2035,
+        SculkCatalystBloom = 2036,
+        SculkCharge = 2037,
+        SculkChargePop = 2038,
+        SonicExplosion = 2039,
+        DustPlume = 2040,
+        /// The data is the rain level from 0 to 65535
+        StartRaining = 3001,
+        /// The data is the thunder level from 0 to 65535
+        StartThunderstorm = 3002,
+        StopRaining = 3003,
+        StopThunderstorm = 3004,
+        GlobalPause = 3005,
+        SimTimeStep = 3006,
+        SimTimeScale = 3007,
+        ActivateBlock = 3500,
+        CauldronExplode = 3501,
+        CauldronDyeArmor = 3502,
+        CauldronCleanArmor = 3503,
+        CauldronFillPotion = 3504,
+        CauldronTakePotion = 3505,
+        CauldronFillWater = 3506,
+        CauldronTakeWater = 3507,
+        CauldronAddDye = 3508,
+        CauldronCleanBanner = 3509,
+        /// The data is 65535 divided by the ticks the block takes to break
+        StartBlockCracking = 3600,
+        StopBlockCracking = 3601,
+        UpdateBlockCracking = 3602,
+        AllPlayersSleeping = 9800,
+        SleepingPlayers = 9801,
+        JumpPrevented = 9810,
+    }
+}
+
+impl LevelEvent {
+    /// Added to the id of a particle type to show that particle.
+    pub const ADD_PARTICLE_MASK: i32 = 0x4000;
+}
+
+#[cfg(test)]
+mod tests {
+    use bedrockrs_core::int::VAR;
+
+    use super::LevelEvent;
+
+    #[test]
+    fn ids_round_trip() {
+        for (id, event) in [
+            (1000, LevelEvent::SoundClick),
+            (2026, LevelEvent::ParticlesBlockExplosion),
+            (2030, LevelEvent::ParticlesWaxOn),
+            (2040, LevelEvent::DustPlume),
+            (9810, LevelEvent::JumpPrevented),
+            (1006, LevelEvent::Unknown(1006)),
+            (
+                LevelEvent::ADD_PARTICLE_MASK | 3,
+                LevelEvent::Unknown(0x4003),
+            ),
+        ] {
+            assert_eq!(LevelEvent::from(id), event);
+            assert_eq!(VAR::<i32>::from(event).into_inner(), id);
+        }
+    }
+}
@@ -2,15 +2,18 @@ use bedrockrs_core::int::{LE, VAR};
 use bedrockrs_proto_derive::ProtoCodec;
 
 use bedrockrs_shared::world::difficulty::Difficulty;
+use bedrockrs_shared::world::dimension::Dimension;
 use bedrockrs_shared::world::gamemode::Gamemode;
 use bedrockrs_shared::world::generator_type::GeneratorType;
 
+use crate::info::MINECRAFT_VERSION;
 use crate::types::base_game_version::BaseGameVersion;
 use crate::types::chat_restriction_level::ChatRestrictionLevel;
 use crate::types::edu_shared_uri_resource::EduSharedResourceUri;
 use crate::types::experiments::Experiments;
 use crate::types::gamerule::GameRule;
 use crate::types::network_block_pos::NetworkBlockPos;
+use crate::types::spawn_biome_type::SpawnBiomeType;
 use crate::types::spawn_settings::SpawnSettings;
 use bedrockrs_shared::world::editor_world_type::EditorWorldType;
 
@@ -71,3 +74,75 @@ pub struct LevelSettings {
     pub world_id: String,
     pub scenario_id: String,
 }
+
+impl Default for LevelSettings {
+    fn default() -> Self {
+        Self {
+            seed: LE::new(0),
+            spawn_settings: SpawnSettings {
+                biome_type: SpawnBiomeType::Default,
+                user_defined_biome_name: String::new(),
+                dimension: Dimension::Overworld,
+            },
+            generator_type: GeneratorType::Overworld,
+            game_type: Gamemode::Survival,
+            hardcore: false,
+            difficulty: Difficulty::Normal,
+            default_spawn_block: NetworkBlockPos {
+                x: VAR::new(0),
+                y: VAR::new(64),
+                z: VAR::new(0),
+            },
+            achievements_disabled: true,
+            editor_world_type: EditorWorldType::NotEditor,
+            created_in_editor: false,
+            exported_from_editor: false,
+            day_cycle_stop_time: VAR::new(-1),
+            education_edition_offer: VAR::new(0),
+            education_features: false,
+            education_product_id: String::new(),
+            rain_level: LE::new(0.0),
+            lightning_level: LE::new(0.0),
+            platform_locked_content: false,
+            multiplayer_intended: true,
+            lan_broadcasting_intended: true,
+            broadcasting_settings_xbox_live: VAR::new(2),
+            broadcasting_settings_platform: VAR::new(2),
+            commands_enabled: true,
+            texture_pack_required: false,
+            gamerules: vec![],
+            experiments: Experiments {
+                experiments: vec![],
+                ever_toggled: false,
+            },
+            bonus_chest: false,
+            start_with_map: false,
+            player_permission: VAR::new(1),
+            server_chunk_tick_radius: LE::new(4),
+            locked_behavior_packs: false,
+            locked_resource_packs: false,
+            from_locked_template: false,
+            msa_gamertags_only: false,
+            from_template: false,
+            is_template_locked_settings: false,
+            only_spawn_v1_villagers: false,
+            persona_disabled: false,
+            custom_skins_disabled: false,
+            emote_chat_muted: false,
+            base_game_version: BaseGameVersion(String::from(MINECRAFT_VERSION)),
+            limited_world_width: LE::new(16),
+            limited_world_depth: LE::new(16),
+            new_nether: true,
+            edu_shared_uri_resource: EduSharedResourceUri {
+                button_name: String::new(),
+                link_uri: String::new(),
+            },
+            force_experimental_gameplay: false,
+            chat_restriction_level: ChatRestrictionLevel::None,
+            disable_player_interactions: false,
+            server_id: String::new(),
+            world_id: String::new(),
+            scenario_id: String::new(),
+        }
+    }
+}
@@ -0,0 +1,533 @@
+use bedrockrs_core::int::VAR;
+
+id_enum! {
+    /// The sounds played with the `LevelSoundEvent` packets, the sound of events like
+    /// [`LevelSoundEvent::Step`] or [`LevelSoundEvent::Ambient`] depends on the block or actor
+    /// given in the packet. Newer sounds are decoded as [`LevelSoundEvent::Unknown`] with their id.
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    #[repr(u32)]
+    pub enum LevelSoundEvent: u32 {
+        /// A sound without a variant, like sounds added by newer versions
+        Unknown,
+        ItemUseOn = 0,
+        Hit = 1,
+        Step = 2,
+        Fly = 3,
+        Jump = 4,
+        Break = 5,
+        Place = 6,
+        HeavyStep = 7,
+        Gallop = 8,
+        Fall = 9,
+        Ambient = 10,
+        AmbientBaby = 11,
+        AmbientInWater = 12,
+        Breathe = 13,
+        Death = 14,
+        DeathInWater = 15,
+        DeathToZombie = 16,
+        Hurt = 17,
+        HurtInWater = 18,
+        Mad = 19,
+        Boost = 20,
+        Bow = 21,
+        SquishBig = 22,
+        SquishSmall = 23,
+        FallBig = 24,
+        FallSmall = 25,
+        Splash = 26,
+        Fizz = 27,
+        Flap = 28,
+        Swim = 29,
+        Drink = 30,
+        Eat = 31,
+        Takeoff = 32,
+        Shake = 33,
+        Plop = 34,
+        Land = 35,
+        Saddle = 36,
+        Armor = 37,
+        ArmorStandPlace = 38,
+        AddChest = 39,
+        Throw = 40,
+        Attack = 41,
+        AttackNoDamage = 42,
+        AttackStrong = 43,
+        Warn = 44,
+        Shear = 45,
+        Milk = 46,
+        Thunder = 47,
+        Explode = 48,
+        Fire = 49,
+        Ignite = 50,
+        Fuse = 51,
+        Stare = 52,
+        Spawn = 53,
+        Shoot = 54,
+        BreakBlock = 55,
+        Launch = 56,
+        Blast = 57,
+        LargeBlast = 58,
+        Twinkle = 59,
+        Remedy = 60,
+        Infect = 61,
+        LevelUp = 62,
+        BowHit = 63,
+        BulletHit = 64,
+        ExtinguishFire = 65,
+        ItemFizz = 66,
+        ChestOpen = 67,
+        ChestClosed = 68,
+        ShulkerBoxOpen = 69,
+        ShulkerBoxClosed = 70,
+        EnderChestOpen = 71,
+        EnderChestClosed = 72,
+        PowerOn = 73,
+        PowerOff = 74,
+        Attach = 75,
+        Detach = 76,
+        Deny = 77,
+        Tripod = 78,
+        Pop = 79,
+        DropSlot = 80,
+        Note = 81,
+        Thorns = 82,
+        PistonIn = 83,
+        PistonOut = 84,
+        Portal = 85,
+        Water = 86,
+        LavaPop = 87,
+        Lava = 88,
+        Burp = 89,
+        BucketFillWater = 90,
+        BucketFillLava = 91,
+        BucketEmptyWater = 92,
+        BucketEmptyLava = 93,
+        ArmorEquipChain = 94,
+        ArmorEquipDiamond = 95,
+        ArmorEquipGeneric = 96,
+        ArmorEquipGold = 97,
+        ArmorEquipIron = 98,
+        ArmorEquipLeather = 99,
+        ArmorEquipElytra = 100,
+        Record13 = 101,
+        RecordCat = 102,
+        RecordBlocks = 103,
+        RecordChirp = 104,
+        RecordFar = 105,
+        RecordMall = 106,
+        RecordMellohi = 107,
+        RecordStal = 108,
+        RecordStrad = 109,
+        RecordWard = 110,
+        Record11 = 111,
+        RecordWait = 112,
+        StopRecord = 113,
+        Flop = 114,
+        GuardianCurse = 115,
+        MobWarning = 116,
+        MobWarningBaby = 117,
+        Teleport = 118,
+        ShulkerOpen = 119,
+        ShulkerClose = 120,
+        Haggle = 121,
+        HaggleYes = 122,
+        HaggleNo = 123,
+        HaggleIdle = 124,
+        ChorusGrow = 125,
+        ChorusDeath = 126,
+        Glass = 127,
+        PotionBrewed = 128,
+        CastSpell = 129,
+        PrepareAttackSpell = 130,
+        PrepareSummon = 131,
+        PrepareWololo = 132,
+        Fang = 133,
+        Charge = 134,
+        TakePicture = 135,
+        PlaceLeashKnot = 136,
+        BreakLeashKnot = 137,
+        AmbientGrowl = 138,
+        AmbientWhine = 139,
+        AmbientPant = 140,
+        AmbientPurr = 141,
+        AmbientPurreow = 142,
+        DeathMinVolume = 143,
+        DeathMidVolume = 144,
+        ImitateBlaze = 145,
+        ImitateCaveSpider = 146,
+        ImitateCreeper = 147,
+        ImitateElderGuardian = 148,
+        ImitateEnderDragon = 149,
+        ImitateEnderman = 150,
+        ImitateEndermite = 151,
+        ImitateEvocationIllager = 152,
+        ImitateGhast = 153,
+        ImitateHusk = 154,
+        ImitateIllusionIllager = 155,
+        ImitateMagmaCube = 156,
+        ImitatePolarBear = 157,
+        ImitateShulker = 158,
+        ImitateSilverfish = 159,
+        ImitateSkeleton = 160,
+        ImitateSlime = 161,
+        ImitateSpider = 162,
+        ImitateStray = 163,
+        ImitateVex = 164,
+        ImitateVindicationIllager = 165,
+        ImitateWitch = 166,
+        ImitateWither = 167,
+        ImitateWitherSkeleton = 168,
+        ImitateWolf = 169,
+        ImitateZombie = 170,
+        ImitateZombiePigman = 171,
+        ImitateZombieVillager = 172,
+        EndPortalFrameFill = 173,
+        EndPortalSpawn = 174,
+        AnvilUse = 175,
+        BottleDragonBreath = 176,
+        PortalTravel = 177,
+        TridentHit = 178,
+        TridentReturn = 179,
+        TridentRiptide1 = 180,
+        TridentRiptide2 = 181,
+        TridentRiptide3 = 182,
+        TridentThrow = 183,
+        TridentThunder = 184,
+        TridentHitGround = 185,
+        Default = 186,
+        FletchingTableUse = 187,
+        ElementConstructorOpen = 188,
+        IceBombHit = 189,
+        BalloonPop = 190,
+        LtReactionIceBomb = 191,
+        LtReactionBleach = 192,
+        LtReactionElephantToothpaste = 193,
+        LtReactionElephantToothpaste2 = 194,
+        LtReactionGlowStick = 195,
+        LtReactionGlowStick2 = 196,
+        LtReactionLuminol = 197,
+        LtReactionSalt = 198,
+        LtReactionFertilizer = 199,
+        LtReactionFireball = 200,
+        LtReactionMagnesiumSalt = 201,
+        LtReactionMiscFire = 202,
+        LtReactionFire = 203,
+        LtReactionMiscExplosion = 204,
+        LtReactionMiscMystical = 205,
+        LtReactionMiscMystical2 = 206,
+        LtReactionProduct = 207,
+        SparklerUse = 208,
+        GlowStickUse = 209,
+        SparklerActive = 210,
+        ConvertToDrowned = 211,
+        BucketFillFish = 212,
+        BucketEmptyFish = 213,
+        BubbleColumnUpwards = 214,
+        BubbleColumnDownwards = 215,
+        BubblePop = 216,
+        BubbleUpInside = 217,
+        BubbleDownInside = 218,
+        HurtBaby = 219,
+        DeathBaby = 220,
+        StepBaby = 221,
+        SpawnBaby = 222,
+        Born = 223,
+        TurtleEggBreak = 224,
+        TurtleEggCrack = 225,
+        TurtleEggHatched = 226,
+        LayEgg = 227,
+        TurtleEggAttacked = 228,
+        BeaconActivate = 229,
+        BeaconAmbient = 230,
+        BeaconDeactivate = 231,
+        BeaconPower = 232,
+        ConduitActivate = 233,
+        ConduitAmbient = 234,
+        ConduitAttack = 235,
+        ConduitDeactivate = 236,
+        ConduitShort = 237,
+        Swoop = 238,
+        BambooSaplingPlace = 239,
+        PreSneeze = 240,
+        Sneeze = 241,
+        AmbientTame = 242,
+        Scared = 243,
+        ScaffoldingClimb = 244,
+        CrossbowLoadingStart = 245,
+        CrossbowLoadingMiddle = 246,
+        CrossbowLoadingEnd = 247,
+        CrossbowShoot = 248,
+        CrossbowQuickChargeStart = 249,
+        CrossbowQuickChargeMiddle = 250,
+        CrossbowQuickChargeEnd = 251,
+        AmbientAggressive = 252,
+        AmbientWorried = 253,
+        CantBreed = 254,
+        ShieldBlock = 255,
+        LecternBookPlace = 256,
+        GrindstoneUse = 257,
+        Bell = 258,
+        CampfireCrackle = 259,
+        Roar = 260,
+        Stun = 261,
+        SweetBerryBushHurt = 262,
+        SweetBerryBushPick = 263,
+        CartographyTableUse = 264,
+        StonecutterUse = 265,
+        ComposterEmpty = 266,
+        ComposterFill = 267,
+        ComposterFillLayer = 268,
+        ComposterReady = 269,
+        BarrelOpen = 270,
+        BarrelClose = 271,
+        RaidHorn = 272,
+        LoomUse = 273,
+        AmbientInRaid = 274,
+        UiCartographyTableUse = 275,
+        UiStonecutterUse = 276,
+        UiLoomUse = 277,
+        SmokerUse = 278,
+        BlastFurnaceUse = 279,
+        SmithingTableUse = 280,
+        Screech = 281,
+        Sleep = 282,
+        FurnaceUse = 283,
+        MooshroomConvert = 284,
+        MilkSuspiciously = 285,
+        Celebrate = 286,
+        JumpPrevent = 287,
+        AmbientPollinate = 288,
+        BeehiveDrip = 289,
+        BeehiveEnter = 290,
+        BeehiveExit = 291,
+        BeehiveWork = 292,
+        BeehiveShear = 293,
+        HoneybottleDrink = 294,
+        AmbientCave = 295,
+        Retreat = 296,
+        ConvertToZombified = 297,
+        Admire = 298,
+        StepLava = 299,
+        Tempt = 300,
+        Panic = 301,
+        Angry = 302,
+        AmbientMoodWarpedForest = 303,
+        AmbientMoodSoulsandValley = 304,
+        AmbientMoodNetherWastes = 305,
+        AmbientMoodBasaltDeltas = 306,
+        AmbientMoodCrimsonForest = 307,
+        RespawnAnchorCharge = 308,
+        RespawnAnchorDeplete = 309,
+        RespawnAnchorSetSpawn = 310,
+        RespawnAnchorAmbient = 311,
+        SoulEscapeQuiet = 312,
+        SoulEscapeLoud = 313,
+        RecordPigstep = 314,
+        LinkCompassToLodestone = 315,
+        UseSmithingTable = 316,
+        EquipNetherite = 317,
+        AmbientLoopWarpedForest = 318,
+        AmbientLoopSoulsandValley = 319,
+        AmbientLoopNetherWastes = 320,
+        AmbientLoopBasaltDeltas = 321,
+        AmbientLoopCrimsonForest = 322,
+        AmbientAdditionWarpedForest = 323,
+        AmbientAdditionSoulsandValley = 324,
+        AmbientAdditionNetherWastes = 325,
+        AmbientAdditionBasaltDeltas = 326,
+        AmbientAdditionCrimsonForest = 327,
+        SculkSensorPowerOn = 328,
+        SculkSensorPowerOff = 329,
+        BucketFillPowderSnow = 330,
+        BucketEmptyPowderSnow = 331,
+        PointedDripstoneCauldronDripWater = 332,
+        PointedDripstoneCauldronDripLava = 333,
+        PointedDripstoneDripWater = 334,
+        PointedDripstoneDripLava = 335,
+        CaveVinesPickBerries = 336,
+        BigDripleafTiltDown = 337,
+        BigDripleafTiltUp = 338,
+        CopperWaxOn = 339,
+        CopperWaxOff = 340,
+        Scrape = 341,
+        PlayerHurtDrown = 342,
+        PlayerHurtOnFire = 343,
+        PlayerHurtFreeze = 344,
+        UseSpyglass = 345,
+        StopUsingSpyglass = 346,
+        AmethystBlockChime = 347,
+        AmbientScreamer = 348,
+        HurtScreamer = 349,
+        DeathScreamer = 350,
+        MilkScreamer = 351,
+        JumpToBlock = 352,
+        PreRam = 353,
+        PreRamScreamer = 354,
+        RamImpact = 355,
+        RamImpactScreamer = 356,
+        SquidInkSquirt = 357,
+        GlowSquidInkSquirt = 358,
+        ConvertToStray = 359,
+        CakeAddCandle = 360,
+        ExtinguishCandle = 361,
+        AmbientCandle = 362,
+        BlockClick = 363,
+        BlockClickFail = 364,
+        SculkCatalystBloom = 365,
+        SculkShriekerShriek = 366,
+        WardenNearbyClose = 367,
+        WardenNearbyCloser = 368,
+        WardenNearbyClosest = 369,
+        WardenSlightlyAngry = 370,
+        RecordOtherside = 371,
+        Tongue = 372,
+        CrackIronGolem = 373,
+        RepairIronGolem = 374,
+        Listening = 375,
+        Heartbeat = 376,
+        HornBreak = 377,
+        SculkPlace = 378,
+        SculkSpread = 379,
+        SculkCharge = 380,
+        SculkSensorPlace = 381,
+        SculkShriekerPlace = 382,
+        GoatCall0 = 383,
+        GoatCall1 = 384,
+        GoatCall2 = 385,
+        GoatCall3 = 386,
+        GoatCall4 = 387,
+        GoatCall5 = 388,
+        GoatCall6 = 389,
+        GoatCall7 = 390,
+        ImitateWarden = 391,
+        ListeningAngry = 392,
+        ItemGiven = 393,
+        ItemTaken = 394,
+        Disappeared = 395,
+        Reappeared = 396,
+        DrinkMilk = 397,
+        FrogspawnHatched = 398,
+        LaySpawn = 399,
+        FrogspawnBreak = 400,
+        SonicBoom = 401,
+        SonicCharge = 402,
+        ItemThrown = 403,
+        Record5 = 404,
+        ConvertToFrog = 405,
+        RecordPlaying = 406,
+        EnchantingTableUse = 407,
+        StepSand = 408,
+        DashReady = 409,
+        BundleDropContents = 410,
+        BundleInsert = 411,
+        BundleRemoveOne = 412,
+        PressurePlateClickOff = 413,
+        PressurePlateClickOn = 414,
+        ButtonClickOff = 415,
+        ButtonClickOn = 416,
+        DoorOpen = 417,
+        DoorClose = 418,
+        TrapdoorOpen = 419,
+        TrapdoorClose = 420,
+        FenceGateOpen = 421,
+        FenceGateClose = 422,
+        Insert = 423,
+        Pickup = 424,
+        InsertEnchanted = 425,
+        PickupEnchanted = 426,
+        Brush = 427,
+        BrushCompleted = 428,
+        ShatterDecoratedPot = 429,
+        BreakDecoratedPot = 430,
+        SnifferEggCrack = 431,
+        SnifferEggHatched = 432,
+        WaxedSignInteractFail = 433,
+        RecordRelic = 434,
+        Bump = 435,
+        PumpkinCarve = 436,
+        ConvertHuskToZombie = 437,
+        PigDeath = 438,
+        HoglinZombified = 439,
+        AmbientUnderwaterEnter = 440,
+        AmbientUnderwaterExit = 441,
+        BottleFill = 442,
+        BottleEmpty = 443,
+        CrafterCraft = 444,
+        CrafterFail = 445,
+        DecoratedPotInsert = 446,
+        DecoratedPotInsertFail = 447,
+        CrafterDisableSlot = 448,
+        TrialSpawnerOpenShutter = 449,
+        TrialSpawnerEjectItem = 450,
+        TrialSpawnerDetectPlayer = 451,
+        TrialSpawnerSpawnMob = 452,
+        TrialSpawnerCloseShutter = 453,
+        TrialSpawnerAmbient = 454,
+        CopperBulbTurnOn = 455,
+        CopperBulbTurnOff = 456,
+        AmbientInAir = 457,
+        BreezeWindChargeBurst = 458,
+        ImitateBreeze = 459,
+        ArmadilloBrush = 460,
+        ArmadilloScuteDrop = 461,
+        EquipWolf = 462,
+        UnequipWolf = 463,
+        Reflect = 464,
+        VaultOpenShutter = 465,
+        VaultCloseShutter = 466,
+        VaultEjectItem = 467,
+        VaultInsertItem = 468,
+        VaultInsertItemFail = 469,
+        VaultAmbient = 470,
+        VaultActivate = 471,
+        VaultDeactivate = 472,
+        HurtReduced = 473,
+        WindChargeBurst = 474,
+        ImitateBogged = 475,
+        WolfArmourCrack = 476,
+        WolfArmourBreak = 477,
+        WolfArmourRepair = 478,
+        MaceSmashAir = 479,
+        MaceSmashGround = 480,
+        TrialSpawnerChargeActivate = 481,
+        TrialSpawnerAmbientOminous = 482,
+        OminousItemSpawnerSpawnItem = 483,
+        OminousBottleEndUse = 484,
+        MaceHeavySmashGround = 485,
+        OminousItemSpawnerSpawnItemBegin = 486,
+        ApplyEffectBadOmen = 488,
+        ApplyEffectRaidOmen = 489,
+        ApplyEffectTrialOmen = 490,
+        OminousItemSpawnerAboutToSpawnItem = 491,
+        RecordCreator = 492,
+        RecordCreatorMusicBox = 493,
+        RecordPrecipice = 494,
+        VaultRejectRewardedPlayer = 495,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bedrockrs_core::int::VAR;
+
+    use super::LevelSoundEvent;
+
+    #[test]
+    fn ids_round_trip() {
+        for (id, event) in [
+            (0, LevelSoundEvent::ItemUseOn),
+            (81, LevelSoundEvent::Note),
+            (190, LevelSoundEvent::BalloonPop),
+            (191, LevelSoundEvent::LtReactionIceBomb),
+            (378, LevelSoundEvent::SculkPlace),
+            (488, LevelSoundEvent::ApplyEffectBadOmen),
+            (495, LevelSoundEvent::VaultRejectRewardedPlayer),
+            (487, LevelSoundEvent::Unknown(487)),
+            (530, LevelSoundEvent::Unknown(530)),
+        ] {
+            assert_eq!(LevelSoundEvent::from(id), event);
+            assert_eq!(VAR::<u32>::from(event).into_inner(), id);
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// The outputs of the material reducer of education edition for an input item.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct MaterialReducerDataEntry {
+    /// The runtime id of the item shifted left by 16 bits, combined with its metadata
+    pub input: VAR<i32>,
+    #[len_repr(VAR::<u32>)]
+    pub outputs: Vec<MaterialReducerOutput>,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct MaterialReducerOutput {
+    pub network_id: VAR<i32>,
+    pub count: VAR<i32>,
+}
@@ -1,3 +1,47 @@
+/// Declares an enum of ids together with the conversions from its id and into its
+/// [`VAR`](bedrockrs_core::int::VAR). Ids without a variant are kept in the `Unknown` variant,
+/// which is written first so the macro can tell it apart from the other variants.
+macro_rules! id_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident: $repr:ty {
+            $(#[$unknown_meta:meta])*
+            Unknown,
+            $($(#[$variant_meta:meta])* $variant:ident = $id:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant = $id,)*
+            $(#[$unknown_meta])*
+            Unknown($repr),
+        }
+
+        impl From<$repr> for $name {
+            fn from(value: $repr) -> Self {
+                match value {
+                    $($id => $name::$variant,)*
+                    other => $name::Unknown(other),
+                }
+            }
+        }
+
+        impl From<$name> for VAR<$repr> {
+            fn from(value: $name) -> Self {
+                VAR::new(match value {
+                    $($name::$variant => $id,)*
+                    $name::Unknown(id) => id,
+                })
+            }
+        }
+    };
+}
+
+pub mod ability_data;
+pub mod actor_block_sync_message;
+pub mod actor_data_key;
+pub mod actor_event;
+pub mod actor_flags;
 pub mod actor_link;
 pub mod actor_link_type;
 pub mod actor_type;
@@ -5,11 +49,24 @@ pub mod animate_action;
 pub mod attribute;
 pub mod base_game_version;
 pub mod block_action;
+pub mod block_change_entry;
+pub mod block_event_type;
+pub mod block_layer;
+pub mod block_property;
+pub mod block_update_flags;
+pub mod boss_bar_color;
+pub mod boss_event;
+pub mod cache_blob;
 pub mod chat_restriction_level;
 pub mod chunk_pos;
+pub mod command_data;
+pub mod command_enum;
 pub mod command_origin_data;
+pub mod command_output_message;
 pub mod connection_request;
 pub mod container_id;
+pub mod container_mix_data_entry;
+pub mod container_name;
 pub mod container_type;
 pub mod dataitem;
 pub mod disconnect_reason;
@@ -21,12 +78,20 @@ pub mod input_mode;
 pub mod interact_action;
 pub mod interaction_model;
 pub mod inventory_transaction;
+pub mod item_entry;
 pub mod item_stack_net_id_variant;
+pub mod item_stack_request;
+pub mod item_stack_response;
+pub mod level_event;
 pub mod level_settings;
+pub mod level_sound_event;
+pub mod material_reducer_data_entry;
 pub mod modal_form_cancel_reason;
 pub mod network_block_pos;
+pub mod network_item_instance_descriptor;
 pub mod network_item_stack_descriptor;
 pub mod network_permissions;
+pub mod objective_sort_order;
 pub mod pack_info_behavior;
 pub mod pack_info_resource;
 pub mod pack_url;
@@ -35,11 +100,18 @@ pub mod play_status;
 pub mod player_action_type;
 pub mod player_movement_mode;
 pub mod player_movement_settings;
+pub mod potion_mix_data_entry;
 pub mod property_sync_data;
+pub mod recipe;
+pub mod recipe_ingredient;
+pub mod resource_pack_type;
 pub mod resource_packs_response_status;
 pub mod resource_packs_stack_pack;
+pub mod score_entry;
 pub mod spawn_biome_type;
 pub mod spawn_settings;
+pub mod sub_chunk_entry;
+pub mod sub_chunk_pos;
 pub mod text_message_data;
 pub mod title_type;
 pub mod valid;
@@ -7,3 +7,14 @@ pub struct NetworkBlockPos {
     pub y: VAR<u32>,
     pub z: VAR<i32>,
 }
+
+impl NetworkBlockPos {
+    /// Negative y coordinates are sent wrapped around.
+    pub fn new(x: i32, y: i32, z: i32) -> Self {
+        NetworkBlockPos {
+            x: VAR::new(x),
+            y: VAR::new(y as u32),
+            z: VAR::new(z),
+        }
+    }
+}
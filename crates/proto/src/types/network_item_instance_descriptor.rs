@@ -0,0 +1,75 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+/// An item without a stack network id, used where items aren't part of an inventory,
+/// like recipe results and creative items.
+#[derive(Debug, Clone)]
+pub struct NetworkItemInstanceDescriptor {
+    /// The runtime id of the item, `0` for air in which case nothing else is sent
+    pub id: VAR<i32>,
+    pub stack_size: LE<u16>,
+    pub aux_value: VAR<u32>,
+    pub block_runtime_id: VAR<i32>,
+    /// The serialized NBT, can place on/can destroy lists and blocking tick of the item
+    pub user_data_buffer: Vec<u8>,
+}
+
+impl NetworkItemInstanceDescriptor {
+    pub fn air() -> Self {
+        Self {
+            id: VAR::new(0),
+            stack_size: LE::new(0),
+            aux_value: VAR::new(0),
+            block_runtime_id: VAR::new(0),
+            user_data_buffer: vec![],
+        }
+    }
+}
+
+impl ProtoCodec for NetworkItemInstanceDescriptor {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.id.proto_serialize(stream)?;
+
+        if self.id.into_inner() == 0 {
+            return Ok(());
+        }
+
+        self.stack_size.proto_serialize(stream)?;
+        self.aux_value.proto_serialize(stream)?;
+        self.block_runtime_id.proto_serialize(stream)?;
+
+        VAR::<u32>::new(self.user_data_buffer.len().try_into()?).proto_serialize(stream)?;
+        stream.extend_from_slice(&self.user_data_buffer);
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let id = VAR::<i32>::proto_deserialize(stream)?;
+
+        if id.into_inner() == 0 {
+            return Ok(Self::air());
+        }
+
+        let stack_size = LE::<u16>::proto_deserialize(stream)?;
+        let aux_value = VAR::<u32>::proto_deserialize(stream)?;
+        let block_runtime_id = VAR::<i32>::proto_deserialize(stream)?;
+
+        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        let mut user_data_buffer = vec![];
+        for _ in 0..len {
+            user_data_buffer.push(u8::proto_deserialize(stream)?);
+        }
+
+        Ok(Self {
+            id,
+            stack_size,
+            aux_value,
+            block_runtime_id,
+            user_data_buffer,
+        })
+    }
+}
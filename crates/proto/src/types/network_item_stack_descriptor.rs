@@ -1,4 +1,7 @@
+use std::io::Cursor;
+
 use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
 use bedrockrs_proto_core::ProtoCodec;
 
 use super::{item_stack_net_id_variant::ItemStackNetIdVariant, valid::Valid};
@@ -8,40 +11,109 @@ pub enum NetworkItemStackDescriptor {
     Invalid { id: VAR<i32> },
     Valid { valid: Valid },
 }
+
+impl NetworkItemStackDescriptor {
+    /// An empty slot.
+    pub fn air() -> Self {
+        Self::Invalid { id: VAR::new(0) }
+    }
+}
+
 impl ProtoCodec for NetworkItemStackDescriptor {
-    fn proto_serialize(
-        &self,
-        stream: &mut Vec<u8>,
-    ) -> Result<(), bedrockrs_proto_core::error::ProtoCodecError> {
-        unimplemented!()
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match self {
+            NetworkItemStackDescriptor::Invalid { .. } => {
+                VAR::<i32>::new(0).proto_serialize(stream)
+            }
+            NetworkItemStackDescriptor::Valid { valid } => valid.proto_serialize(stream),
+        }
     }
 
-    fn proto_deserialize(
-        stream: &mut std::io::Cursor<&[u8]>,
-    ) -> Result<Self, bedrockrs_proto_core::error::ProtoCodecError> {
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
         let id = VAR::<i32>::proto_deserialize(stream)?;
         if id.into_inner() == 0 {
             Ok(Self::Invalid { id })
         } else {
-            let stack_size = LE::<u16>::proto_deserialize(stream)?;
-            let aux_value = LE::<u16>::proto_deserialize(stream)?;
-            let include_net_id = bool::proto_deserialize(stream)?;
-            let include_net_id_data = if include_net_id {
-                Some(ItemStackNetIdVariant::proto_deserialize(stream)?)
-            } else {
-                None
-            };
             Ok(Self::Valid {
                 valid: Valid {
                     id,
-                    stack_size,
-                    aux_value,
-                    include_net_id,
-                    include_net_id_data,
+                    stack_size: LE::<u16>::proto_deserialize(stream)?,
+                    aux_value: VAR::<u32>::proto_deserialize(stream)?,
+                    include_net_id_data: Option::<ItemStackNetIdVariant>::proto_deserialize(
+                        stream,
+                    )?,
                     block_runtime_id: VAR::<i32>::proto_deserialize(stream)?,
-                    user_data_buffer: String::proto_deserialize(stream)?,
+                    user_data_buffer: {
+                        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+                        let mut buf = vec![];
+                        for _ in 0..len {
+                            buf.push(u8::proto_deserialize(stream)?);
+                        }
+                        buf
+                    },
                 },
             })
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(descriptor: &NetworkItemStackDescriptor) -> NetworkItemStackDescriptor {
+        let mut stream = vec![];
+        descriptor.proto_serialize(&mut stream).unwrap();
+
+        let mut cursor = Cursor::new(stream.as_slice());
+        let decoded = NetworkItemStackDescriptor::proto_deserialize(&mut cursor).unwrap();
+        assert_eq!(cursor.position() as usize, stream.len());
+
+        decoded
+    }
+
+    #[test]
+    fn items_round_trip() {
+        for stack_network_id in [None, Some(17)] {
+            let decoded = round_trip(&NetworkItemStackDescriptor::Valid {
+                valid: Valid {
+                    id: VAR::new(5),
+                    stack_size: LE::new(64),
+                    aux_value: VAR::new(3),
+                    include_net_id_data: stack_network_id.map(|raw_id| ItemStackNetIdVariant {
+                        raw_id: VAR::new(raw_id),
+                    }),
+                    block_runtime_id: VAR::new(1234),
+                    user_data_buffer: vec![1, 2, 3],
+                },
+            });
+
+            let NetworkItemStackDescriptor::Valid { valid } = decoded else {
+                panic!("expected an item, got {decoded:?}");
+            };
+            assert_eq!(valid.id.into_inner(), 5);
+            assert_eq!(valid.stack_size.into_inner(), 64);
+            assert_eq!(valid.aux_value.into_inner(), 3);
+            assert_eq!(
+                valid.include_net_id_data.map(|id| id.raw_id.into_inner()),
+                stack_network_id
+            );
+            assert_eq!(valid.block_runtime_id.into_inner(), 1234);
+            assert_eq!(valid.user_data_buffer, [1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn air_is_a_single_zero() {
+        let mut stream = vec![];
+        NetworkItemStackDescriptor::air()
+            .proto_serialize(&mut stream)
+            .unwrap();
+        assert_eq!(stream, [0]);
+
+        assert!(matches!(
+            round_trip(&NetworkItemStackDescriptor::air()),
+            NetworkItemStackDescriptor::Invalid { .. }
+        ));
+    }
+}
@@ -0,0 +1,10 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// The order the scores of an objective are listed in.
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(VAR::<i32>)]
+pub enum ObjectiveSortOrder {
+    Ascending = 0,
+    Descending = 1,
+}
@@ -1,13 +1,60 @@
+use bedrockrs_addon::behavior::BehaviorPack;
+use bedrockrs_addon::manifest::AddonManifest;
 use bedrockrs_core::int::LE;
 use bedrockrs_proto_derive::ProtoCodec;
 
 #[derive(ProtoCodec, Debug, Clone)]
 pub struct BehaviorPackInfoType {
-    id: String,
-    version: String,
-    size: LE<u64>,
-    content_key: String,
-    sub_pack_name: String,
-    content_identify: String,
-    has_scripts: bool,
+    pub id: String,
+    pub version: String,
+    pub size: LE<u64>,
+    pub content_key: String,
+    pub sub_pack_name: String,
+    pub content_identify: String,
+    pub has_scripts: bool,
+}
+
+impl BehaviorPackInfoType {
+    /// Describes the pack by its manifest, `size` is the size of the pack's zip file.
+    pub fn from_pack(pack: &BehaviorPack, size: u64) -> Self {
+        let header = &pack.manifest.header;
+
+        Self {
+            id: header.uuid.to_string(),
+            version: header.version.to_string(),
+            size: LE::new(size),
+            content_key: pack.content_key.clone().unwrap_or_default(),
+            sub_pack_name: default_subpack(&pack.manifest),
+            content_identify: content_identity(&pack.manifest, &pack.content_key),
+            has_scripts: has_scripts(pack),
+        }
+    }
+}
+
+/// Whether the pack contains scripts or declares a script module.
+pub(crate) fn has_scripts(pack: &BehaviorPack) -> bool {
+    !pack.scripts.is_empty()
+        || pack
+            .manifest
+            .modules
+            .iter()
+            .any(|module| module.module_type == "script")
+}
+
+/// The sub pack the client uses by default, the first one the manifest declares.
+pub(crate) fn default_subpack(manifest: &AddonManifest) -> String {
+    manifest
+        .subpacks
+        .as_ref()
+        .and_then(|subpacks| subpacks.first())
+        .map(|subpack| subpack.folder_name.clone())
+        .unwrap_or_default()
+}
+
+/// Encrypted packs are identified by their uuid, others don't have a content identity.
+pub(crate) fn content_identity(manifest: &AddonManifest, content_key: &Option<String>) -> String {
+    match content_key {
+        Some(_) => manifest.header.uuid.to_string(),
+        None => String::new(),
+    }
 }
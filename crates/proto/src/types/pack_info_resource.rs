@@ -1,14 +1,43 @@
+use bedrockrs_addon::resource::ResourcePack;
 use bedrockrs_core::int::LE;
 use bedrockrs_proto_derive::ProtoCodec;
 
+use crate::types::pack_info_behavior::{content_identity, default_subpack};
+
 #[derive(ProtoCodec, Debug, Clone)]
 pub struct ResourcePackInfoType {
-    id: String,
-    version: String,
-    size: LE<u64>,
-    content_key: String,
-    sub_pack_name: String,
-    content_identify: String,
-    has_scripts: bool,
-    ray_tracing_capable: bool,
+    pub id: String,
+    pub version: String,
+    pub size: LE<u64>,
+    pub content_key: String,
+    pub sub_pack_name: String,
+    pub content_identify: String,
+    pub has_scripts: bool,
+    pub ray_tracing_capable: bool,
+}
+
+impl ResourcePackInfoType {
+    /// Describes the pack by its manifest, `size` is the size of the pack's zip file.
+    pub fn from_pack(pack: &ResourcePack, size: u64) -> Self {
+        let header = &pack.manifest.header;
+
+        Self {
+            id: header.uuid.to_string(),
+            version: header.version.to_string(),
+            size: LE::new(size),
+            content_key: pack.content_key.clone().unwrap_or_default(),
+            sub_pack_name: default_subpack(&pack.manifest),
+            content_identify: content_identity(&pack.manifest, &pack.content_key),
+            has_scripts: pack
+                .manifest
+                .modules
+                .iter()
+                .any(|module| module.module_type == "script"),
+            ray_tracing_capable: pack
+                .manifest
+                .capabilities
+                .as_ref()
+                .is_some_and(|capabilities| capabilities.iter().any(|c| c == "raytraced")),
+        }
+    }
 }
@@ -3,6 +3,8 @@ use std::io::Cursor;
 use bedrockrs_proto_core::error::ProtoCodecError;
 use bedrockrs_proto_core::ProtoCodec;
 
+use crate::login::provider::packs::PackArchive;
+
 #[derive(Debug, Clone)]
 pub struct PackURL {
     uuid: String,
@@ -10,6 +12,17 @@ pub struct PackURL {
     url: String,
 }
 
+impl PackURL {
+    /// Points the client to `url` to download the given pack from.
+    pub fn new(archive: &PackArchive, url: impl Into<String>) -> Self {
+        Self {
+            uuid: archive.uuid.to_string(),
+            version: archive.version.clone(),
+            url: url.into(),
+        }
+    }
+}
+
 impl ProtoCodec for PackURL {
     fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError>
     where
@@ -0,0 +1,13 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// Brewing a potion with a reagent into another potion.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct PotionMixDataEntry {
+    pub input_potion_id: VAR<i32>,
+    pub input_potion_metadata: VAR<i32>,
+    pub reagent_item_id: VAR<i32>,
+    pub reagent_item_metadata: VAR<i32>,
+    pub output_potion_id: VAR<i32>,
+    pub output_potion_metadata: VAR<i32>,
+}
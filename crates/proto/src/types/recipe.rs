@@ -0,0 +1,415 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_proto_derive::ProtoCodec;
+use uuid::Uuid;
+
+use crate::types::network_item_instance_descriptor::NetworkItemInstanceDescriptor;
+use crate::types::recipe_ingredient::RecipeIngredient;
+
+/// A recipe sent in the [`CraftingDataPacket`](crate::packets::crafting_data_packet::CraftingDataPacket).
+#[derive(Debug, Clone)]
+pub enum Recipe {
+    Shapeless(ShapelessRecipe),
+    Shaped(ShapedRecipe),
+    /// Uses the furnace aux recipe type if the input has a metadata
+    Furnace(FurnaceRecipe),
+    /// A recipe hardcoded in the client, like map cloning or firework crafting
+    Multi(MultiRecipe),
+    /// A shapeless recipe keeping the user data of its input, like dyeing shulker boxes
+    UserDataShapeless(ShapelessRecipe),
+    ShapelessChemistry(ShapelessRecipe),
+    ShapedChemistry(ShapedRecipe),
+    SmithingTransform(SmithingTransformRecipe),
+    SmithingTrim(SmithingTrimRecipe),
+}
+
+impl Recipe {
+    const SHAPELESS_ID: i32 = 0;
+    const SHAPED_ID: i32 = 1;
+    const FURNACE_ID: i32 = 2;
+    const FURNACE_AUX_ID: i32 = 3;
+    const MULTI_ID: i32 = 4;
+    const USER_DATA_SHAPELESS_ID: i32 = 5;
+    const SHAPELESS_CHEMISTRY_ID: i32 = 6;
+    const SHAPED_CHEMISTRY_ID: i32 = 7;
+    const SMITHING_TRANSFORM_ID: i32 = 8;
+    const SMITHING_TRIM_ID: i32 = 9;
+}
+
+impl ProtoCodec for Recipe {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match self {
+            Recipe::Shapeless(recipe) => {
+                VAR::<i32>::new(Self::SHAPELESS_ID).proto_serialize(stream)?;
+                recipe.proto_serialize(stream)?;
+            }
+            Recipe::Shaped(recipe) => {
+                VAR::<i32>::new(Self::SHAPED_ID).proto_serialize(stream)?;
+                recipe.proto_serialize(stream)?;
+            }
+            Recipe::Furnace(recipe) => {
+                match recipe.input_metadata {
+                    None => VAR::<i32>::new(Self::FURNACE_ID).proto_serialize(stream)?,
+                    Some(_) => VAR::<i32>::new(Self::FURNACE_AUX_ID).proto_serialize(stream)?,
+                };
+                recipe.proto_serialize(stream)?;
+            }
+            Recipe::Multi(recipe) => {
+                VAR::<i32>::new(Self::MULTI_ID).proto_serialize(stream)?;
+                recipe.proto_serialize(stream)?;
+            }
+            Recipe::UserDataShapeless(recipe) => {
+                VAR::<i32>::new(Self::USER_DATA_SHAPELESS_ID).proto_serialize(stream)?;
+                recipe.proto_serialize(stream)?;
+            }
+            Recipe::ShapelessChemistry(recipe) => {
+                VAR::<i32>::new(Self::SHAPELESS_CHEMISTRY_ID).proto_serialize(stream)?;
+                recipe.proto_serialize(stream)?;
+            }
+            Recipe::ShapedChemistry(recipe) => {
+                VAR::<i32>::new(Self::SHAPED_CHEMISTRY_ID).proto_serialize(stream)?;
+                recipe.proto_serialize(stream)?;
+            }
+            Recipe::SmithingTransform(recipe) => {
+                VAR::<i32>::new(Self::SMITHING_TRANSFORM_ID).proto_serialize(stream)?;
+                recipe.proto_serialize(stream)?;
+            }
+            Recipe::SmithingTrim(recipe) => {
+                VAR::<i32>::new(Self::SMITHING_TRIM_ID).proto_serialize(stream)?;
+                recipe.proto_serialize(stream)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(match VAR::<i32>::proto_deserialize(stream)?.into_inner() {
+            Self::SHAPELESS_ID => Recipe::Shapeless(ShapelessRecipe::proto_deserialize(stream)?),
+            Self::SHAPED_ID => Recipe::Shaped(ShapedRecipe::proto_deserialize(stream)?),
+            Self::FURNACE_ID => Recipe::Furnace(FurnaceRecipe {
+                input_id: VAR::<i32>::proto_deserialize(stream)?,
+                input_metadata: None,
+                result: NetworkItemInstanceDescriptor::proto_deserialize(stream)?,
+                block: String::proto_deserialize(stream)?,
+            }),
+            Self::FURNACE_AUX_ID => Recipe::Furnace(FurnaceRecipe {
+                input_id: VAR::<i32>::proto_deserialize(stream)?,
+                input_metadata: Some(VAR::<i32>::proto_deserialize(stream)?),
+                result: NetworkItemInstanceDescriptor::proto_deserialize(stream)?,
+                block: String::proto_deserialize(stream)?,
+            }),
+            Self::MULTI_ID => Recipe::Multi(MultiRecipe::proto_deserialize(stream)?),
+            Self::USER_DATA_SHAPELESS_ID => {
+                Recipe::UserDataShapeless(ShapelessRecipe::proto_deserialize(stream)?)
+            }
+            Self::SHAPELESS_CHEMISTRY_ID => {
+                Recipe::ShapelessChemistry(ShapelessRecipe::proto_deserialize(stream)?)
+            }
+            Self::SHAPED_CHEMISTRY_ID => {
+                Recipe::ShapedChemistry(ShapedRecipe::proto_deserialize(stream)?)
+            }
+            Self::SMITHING_TRANSFORM_ID => {
+                Recipe::SmithingTransform(SmithingTransformRecipe::proto_deserialize(stream)?)
+            }
+            Self::SMITHING_TRIM_ID => {
+                Recipe::SmithingTrim(SmithingTrimRecipe::proto_deserialize(stream)?)
+            }
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("Recipe"),
+                ))
+            }
+        })
+    }
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ShapelessRecipe {
+    /// A unique name of the recipe, for example `minecraft:stick`
+    pub recipe_id: String,
+    #[len_repr(VAR::<u32>)]
+    pub ingredients: Vec<RecipeIngredient>,
+    #[len_repr(VAR::<u32>)]
+    pub results: Vec<NetworkItemInstanceDescriptor>,
+    pub uuid: Uuid,
+    /// The block the recipe is crafted in, for example `crafting_table` or `stonecutter`
+    pub block: String,
+    /// Recipes with a higher priority are preferred if multiple recipes match
+    pub priority: VAR<i32>,
+    pub unlocking_requirement: RecipeUnlockingRequirement,
+    /// The id used to reference the recipe in item stack requests
+    pub network_id: VAR<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ShapedRecipe {
+    /// A unique name of the recipe, for example `minecraft:crafting_table`
+    pub recipe_id: String,
+    pub width: VAR<i32>,
+    pub height: VAR<i32>,
+    /// The ingredients row by row, exactly `width * height` entries,
+    /// empty slots use [`RecipeIngredient::empty`]
+    pub ingredients: Vec<RecipeIngredient>,
+    pub results: Vec<NetworkItemInstanceDescriptor>,
+    pub uuid: Uuid,
+    /// The block the recipe is crafted in, for example `crafting_table`
+    pub block: String,
+    /// Recipes with a higher priority are preferred if multiple recipes match
+    pub priority: VAR<i32>,
+    /// If the recipe may be mirrored horizontally
+    pub assume_symmetry: bool,
+    pub unlocking_requirement: RecipeUnlockingRequirement,
+    /// The id used to reference the recipe in item stack requests
+    pub network_id: VAR<u32>,
+}
+
+impl ProtoCodec for ShapedRecipe {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        let size = self.width.into_inner() as i64 * self.height.into_inner() as i64;
+        if size != self.ingredients.len() as i64 {
+            return Err(ProtoCodecError::FormatMismatch(format!(
+                "Shaped recipe {:?} has {} ingredients but a size of {}x{}",
+                self.recipe_id,
+                self.ingredients.len(),
+                self.width.into_inner(),
+                self.height.into_inner(),
+            )));
+        }
+
+        self.recipe_id.proto_serialize(stream)?;
+        self.width.proto_serialize(stream)?;
+        self.height.proto_serialize(stream)?;
+
+        for ingredient in &self.ingredients {
+            ingredient.proto_serialize(stream)?;
+        }
+
+        VAR::<u32>::new(self.results.len().try_into()?).proto_serialize(stream)?;
+        for result in &self.results {
+            result.proto_serialize(stream)?;
+        }
+
+        self.uuid.proto_serialize(stream)?;
+        self.block.proto_serialize(stream)?;
+        self.priority.proto_serialize(stream)?;
+        self.assume_symmetry.proto_serialize(stream)?;
+        self.unlocking_requirement.proto_serialize(stream)?;
+        self.network_id.proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let recipe_id = String::proto_deserialize(stream)?;
+        let width = VAR::<i32>::proto_deserialize(stream)?;
+        let height = VAR::<i32>::proto_deserialize(stream)?;
+
+        let count = width
+            .into_inner()
+            .max(0)
+            .checked_mul(height.into_inner().max(0))
+            .ok_or_else(|| {
+                ProtoCodecError::FormatMismatch(format!(
+                    "Shaped recipe {recipe_id} is too big: {width:?}x{height:?}"
+                ))
+            })? as usize;
+
+        // Every ingredient takes at least one byte
+        let remaining = stream
+            .get_ref()
+            .len()
+            .saturating_sub(stream.position() as usize);
+        if count > remaining {
+            return Err(ProtoCodecError::FormatMismatch(format!(
+                "Shaped recipe {recipe_id} has {count} ingredients, but only {remaining} bytes are left"
+            )));
+        }
+
+        let mut ingredients = vec![];
+        for _ in 0..count {
+            ingredients.push(RecipeIngredient::proto_deserialize(stream)?);
+        }
+
+        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        let mut results = vec![];
+        for _ in 0..len {
+            results.push(NetworkItemInstanceDescriptor::proto_deserialize(stream)?);
+        }
+
+        Ok(Self {
+            recipe_id,
+            width,
+            height,
+            ingredients,
+            results,
+            uuid: Uuid::proto_deserialize(stream)?,
+            block: String::proto_deserialize(stream)?,
+            priority: VAR::<i32>::proto_deserialize(stream)?,
+            assume_symmetry: bool::proto_deserialize(stream)?,
+            unlocking_requirement: RecipeUnlockingRequirement::proto_deserialize(stream)?,
+            network_id: VAR::<u32>::proto_deserialize(stream)?,
+        })
+    }
+}
+
+/// Smelting an item in a furnace, blast furnace, smoker or campfire.
+#[derive(Debug, Clone)]
+pub struct FurnaceRecipe {
+    pub input_id: VAR<i32>,
+    /// The metadata the input has to have, `None` matches any metadata
+    pub input_metadata: Option<VAR<i32>>,
+    pub result: NetworkItemInstanceDescriptor,
+    /// The block the recipe is used in, for example `furnace` or `smoker`
+    pub block: String,
+}
+
+impl ProtoCodec for FurnaceRecipe {
+    /// Serializes the recipe without its type, which depends on the metadata.
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.input_id.proto_serialize(stream)?;
+
+        if let Some(ref input_metadata) = self.input_metadata {
+            input_metadata.proto_serialize(stream)?;
+        }
+
+        self.result.proto_serialize(stream)?;
+        self.block.proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    /// Deserializes a recipe without metadata, [`Recipe`] decides the type.
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(Self {
+            input_id: VAR::<i32>::proto_deserialize(stream)?,
+            input_metadata: None,
+            result: NetworkItemInstanceDescriptor::proto_deserialize(stream)?,
+            block: String::proto_deserialize(stream)?,
+        })
+    }
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct MultiRecipe {
+    pub uuid: Uuid,
+    pub network_id: VAR<u32>,
+}
+
+/// Upgrading an item in a smithing table, like diamond to netherite gear.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct SmithingTransformRecipe {
+    pub recipe_id: String,
+    pub template: RecipeIngredient,
+    pub base: RecipeIngredient,
+    pub addition: RecipeIngredient,
+    pub result: NetworkItemInstanceDescriptor,
+    pub block: String,
+    pub network_id: VAR<u32>,
+}
+
+/// Applying an armor trim in a smithing table.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct SmithingTrimRecipe {
+    pub recipe_id: String,
+    pub template: RecipeIngredient,
+    pub base: RecipeIngredient,
+    pub addition: RecipeIngredient,
+    pub block: String,
+    pub network_id: VAR<u32>,
+}
+
+/// When a recipe gets unlocked in the recipe book.
+#[derive(Debug, Clone)]
+pub enum RecipeUnlockingRequirement {
+    /// Unlocked once the player had any of the ingredients in their inventory
+    Ingredients(Vec<RecipeIngredient>),
+    AlwaysUnlocked,
+    PlayerInWater,
+    PlayerHasManyItems,
+}
+
+impl ProtoCodec for RecipeUnlockingRequirement {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match self {
+            RecipeUnlockingRequirement::Ingredients(ingredients) => {
+                0u8.proto_serialize(stream)?;
+
+                VAR::<u32>::new(ingredients.len().try_into()?).proto_serialize(stream)?;
+                for ingredient in ingredients {
+                    ingredient.proto_serialize(stream)?;
+                }
+            }
+            RecipeUnlockingRequirement::AlwaysUnlocked => 1u8.proto_serialize(stream)?,
+            RecipeUnlockingRequirement::PlayerInWater => 2u8.proto_serialize(stream)?,
+            RecipeUnlockingRequirement::PlayerHasManyItems => 3u8.proto_serialize(stream)?,
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(match u8::proto_deserialize(stream)? {
+            0 => {
+                let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+                let mut ingredients = vec![];
+                for _ in 0..len {
+                    ingredients.push(RecipeIngredient::proto_deserialize(stream)?);
+                }
+
+                RecipeUnlockingRequirement::Ingredients(ingredients)
+            }
+            1 => RecipeUnlockingRequirement::AlwaysUnlocked,
+            2 => RecipeUnlockingRequirement::PlayerInWater,
+            3 => RecipeUnlockingRequirement::PlayerHasManyItems,
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("RecipeUnlockingRequirement"),
+                ))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shaped_recipe_header(width: i32, height: i32) -> Vec<u8> {
+        let mut stream = vec![];
+        String::from("minecraft:test")
+            .proto_serialize(&mut stream)
+            .unwrap();
+        VAR::<i32>::new(width).proto_serialize(&mut stream).unwrap();
+        VAR::<i32>::new(height)
+            .proto_serialize(&mut stream)
+            .unwrap();
+        stream
+    }
+
+    #[test]
+    fn overflowing_shaped_recipe_size_is_rejected() {
+        let stream = shaped_recipe_header(i32::MAX, i32::MAX);
+
+        assert!(matches!(
+            ShapedRecipe::proto_deserialize(&mut Cursor::new(stream.as_slice())),
+            Err(ProtoCodecError::FormatMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn shaped_recipe_ingredients_are_capped_by_the_stream() {
+        let mut stream = shaped_recipe_header(1000, 1000);
+        stream.extend([0; 16]);
+
+        assert!(matches!(
+            ShapedRecipe::proto_deserialize(&mut Cursor::new(stream.as_slice())),
+            Err(ProtoCodecError::FormatMismatch(_))
+        ));
+    }
+}
@@ -0,0 +1,154 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_proto_derive::ProtoCodec;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct RecipeIngredient {
+    pub descriptor: ItemDescriptor,
+    pub count: VAR<i32>,
+}
+
+impl RecipeIngredient {
+    /// Matches `count` items with the runtime id, a metadata of `0x7fff` matches any metadata.
+    pub fn item(network_id: i16, metadata: i16, count: i32) -> Self {
+        Self {
+            descriptor: ItemDescriptor::Default {
+                network_id: LE::new(network_id),
+                metadata: LE::new(metadata),
+            },
+            count: VAR::new(count),
+        }
+    }
+
+    /// Matches `count` items with the tag, for example `minecraft:logs`.
+    pub fn tag(tag: impl Into<String>, count: i32) -> Self {
+        Self {
+            descriptor: ItemDescriptor::ItemTag { tag: tag.into() },
+            count: VAR::new(count),
+        }
+    }
+
+    /// An empty slot in a shaped recipe.
+    pub fn empty() -> Self {
+        Self {
+            descriptor: ItemDescriptor::Invalid,
+            count: VAR::new(0),
+        }
+    }
+}
+
+/// Describes which items match an ingredient.
+#[derive(Debug, Clone)]
+pub enum ItemDescriptor {
+    Invalid,
+    /// Matches an item by its runtime id, a metadata of `0x7fff` matches any metadata
+    Default {
+        network_id: LE<i16>,
+        metadata: LE<i16>,
+    },
+    /// Matches all items the molang expression evaluates to true for
+    MoLang {
+        expression: String,
+        version: u8,
+    },
+    /// Matches all items with the tag, for example `minecraft:planks`
+    ItemTag {
+        tag: String,
+    },
+    /// Matches an item by its identifier
+    Deferred {
+        name: String,
+        metadata: LE<i16>,
+    },
+    ComplexAlias {
+        name: String,
+    },
+}
+
+impl ProtoCodec for ItemDescriptor {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match self {
+            ItemDescriptor::Invalid => {
+                0u8.proto_serialize(stream)?;
+            }
+            ItemDescriptor::Default {
+                network_id,
+                metadata,
+            } => {
+                1u8.proto_serialize(stream)?;
+                network_id.proto_serialize(stream)?;
+
+                // Air has no metadata
+                if network_id.into_inner() != 0 {
+                    metadata.proto_serialize(stream)?;
+                }
+            }
+            ItemDescriptor::MoLang {
+                expression,
+                version,
+            } => {
+                2u8.proto_serialize(stream)?;
+                expression.proto_serialize(stream)?;
+                version.proto_serialize(stream)?;
+            }
+            ItemDescriptor::ItemTag { tag } => {
+                3u8.proto_serialize(stream)?;
+                tag.proto_serialize(stream)?;
+            }
+            ItemDescriptor::Deferred { name, metadata } => {
+                4u8.proto_serialize(stream)?;
+                name.proto_serialize(stream)?;
+                metadata.proto_serialize(stream)?;
+            }
+            ItemDescriptor::ComplexAlias { name } => {
+                5u8.proto_serialize(stream)?;
+                name.proto_serialize(stream)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(match u8::proto_deserialize(stream)? {
+            0 => ItemDescriptor::Invalid,
+            1 => {
+                let network_id = LE::<i16>::proto_deserialize(stream)?;
+
+                let metadata = if network_id.into_inner() != 0 {
+                    LE::<i16>::proto_deserialize(stream)?
+                } else {
+                    LE::new(0)
+                };
+
+                ItemDescriptor::Default {
+                    network_id,
+                    metadata,
+                }
+            }
+            2 => ItemDescriptor::MoLang {
+                expression: String::proto_deserialize(stream)?,
+                version: u8::proto_deserialize(stream)?,
+            },
+            3 => ItemDescriptor::ItemTag {
+                tag: String::proto_deserialize(stream)?,
+            },
+            4 => ItemDescriptor::Deferred {
+                name: String::proto_deserialize(stream)?,
+                metadata: LE::<i16>::proto_deserialize(stream)?,
+            },
+            5 => ItemDescriptor::ComplexAlias {
+                name: String::proto_deserialize(stream)?,
+            },
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("ItemDescriptor"),
+                ))
+            }
+        })
+    }
+}
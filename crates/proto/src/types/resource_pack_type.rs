@@ -0,0 +1,16 @@
+use bedrockrs_core::int::LE;
+use bedrockrs_proto_derive::ProtoCodec;
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
+pub enum ResourcePackType {
+    Invalid = 0,
+    Addon = 1,
+    Cached = 2,
+    CopyProtected = 3,
+    Behavior = 4,
+    PersonaPiece = 5,
+    Resources = 6,
+    Skins = 7,
+    WorldTemplate = 8,
+}
@@ -1,8 +1,21 @@
+use bedrockrs_addon::manifest::AddonManifest;
 use bedrockrs_proto_derive::ProtoCodec;
 
+use crate::types::pack_info_behavior::default_subpack;
+
 #[derive(ProtoCodec, Debug, Clone)]
 pub struct ResourcePacksStackPack {
     pub id: String,
     pub version: String,
     pub sub_pack_name: String,
 }
+
+impl ResourcePacksStackPack {
+    pub fn from_manifest(manifest: &AddonManifest) -> Self {
+        Self {
+            id: manifest.header.uuid.to_string(),
+            version: manifest.header.version.to_string(),
+            sub_pack_name: default_subpack(manifest),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+
+/// Who a score belongs to.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ScoreIdentity {
+    Player(ActorUniqueID),
+    Entity(ActorUniqueID),
+    /// A name that isn't tied to an actor, shown as is on the scoreboard
+    FakePlayer(String),
+}
+
+impl ProtoCodec for ScoreIdentity {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match self {
+            ScoreIdentity::Player(unique_id) => {
+                1u8.proto_serialize(stream)?;
+                unique_id.proto_serialize(stream)?;
+            }
+            ScoreIdentity::Entity(unique_id) => {
+                2u8.proto_serialize(stream)?;
+                unique_id.proto_serialize(stream)?;
+            }
+            ScoreIdentity::FakePlayer(name) => {
+                3u8.proto_serialize(stream)?;
+                name.proto_serialize(stream)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(match u8::proto_deserialize(stream)? {
+            1 => ScoreIdentity::Player(ActorUniqueID::proto_deserialize(stream)?),
+            2 => ScoreIdentity::Entity(ActorUniqueID::proto_deserialize(stream)?),
+            3 => ScoreIdentity::FakePlayer(String::proto_deserialize(stream)?),
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("ScoreIdentity"),
+                ))
+            }
+        })
+    }
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
+pub enum ScoreAction {
+    Change = 0,
+    Remove = 1,
+}
+
+/// A score in a `SetScorePacket`.
+#[derive(Debug, Clone)]
+pub struct ScoreEntry {
+    /// The id the server assigned to the identity, it must be the same for all objectives
+    pub scoreboard_id: VAR<i64>,
+    pub objective_name: String,
+    pub score: LE<i32>,
+    /// Only sent when scores are changed, not when they are removed
+    pub identity: Option<ScoreIdentity>,
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
+pub enum ScoreboardIdentityAction {
+    Register = 0,
+    Clear = 1,
+}
+
+/// A scoreboard id in a `SetScoreboardIdentityPacket`.
+#[derive(Debug, Clone)]
+pub struct ScoreboardIdentityEntry {
+    pub scoreboard_id: VAR<i64>,
+    /// Only sent when the identity is registered, not when it is cleared
+    pub player_unique_id: Option<ActorUniqueID>,
+}
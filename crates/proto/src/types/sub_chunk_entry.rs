@@ -0,0 +1,159 @@
+use std::io::{Cursor, Read};
+use std::sync::Arc;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// The position of a sub chunk relative to the center of a `SubChunkRequestPacket`.
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SubChunkOffset {
+    pub x: i8,
+    pub y: i8,
+    pub z: i8,
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
+pub enum SubChunkRequestResult {
+    Undefined = 0,
+    Success = 1,
+    ChunkNotFound = 2,
+    InvalidDimension = 3,
+    PlayerNotFound = 4,
+    YIndexOutOfBounds = 5,
+    /// The sub chunk only contains air, no payload is sent when the blob cache is enabled
+    SuccessAllAir = 6,
+}
+
+/// Whether a sub chunk comes with a heightmap.
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
+pub enum HeightMapType {
+    NoData = 0,
+    HasData = 1,
+    /// The highest block of every column is above the sub chunk
+    TooHigh = 2,
+    /// The highest block of every column is below the sub chunk
+    TooLow = 3,
+}
+
+/// A single sub chunk in a `SubChunkPacket`.
+#[derive(Debug, Clone)]
+pub struct SubChunkEntry {
+    pub offset: SubChunkOffset,
+    pub result: SubChunkRequestResult,
+    /// The serialized sub chunk followed by the NBT of its block entities
+    pub payload: Vec<u8>,
+    pub heightmap_type: HeightMapType,
+    /// The highest block of every column relative to the sub chunk, indexed by `x + z * 16`.
+    /// Only sent if the heightmap type is [`HeightMapType::HasData`]
+    pub heightmap: Vec<i8>,
+    /// The hash of the payload, only sent if the blob cache is enabled
+    pub blob_id: LE<u64>,
+}
+
+impl SubChunkEntry {
+    /// The number of columns in a heightmap.
+    pub const HEIGHTMAP_SIZE: usize = 256;
+
+    /// An entry without data, for results other than the success ones.
+    pub fn empty(offset: SubChunkOffset, result: SubChunkRequestResult) -> Self {
+        Self {
+            offset,
+            result,
+            payload: vec![],
+            heightmap_type: HeightMapType::NoData,
+            heightmap: vec![],
+            blob_id: LE::new(0),
+        }
+    }
+
+    pub(crate) fn serialize(
+        &self,
+        stream: &mut Vec<u8>,
+        cache_enabled: bool,
+    ) -> Result<(), ProtoCodecError> {
+        self.offset.proto_serialize(stream)?;
+        self.result.proto_serialize(stream)?;
+
+        if !(cache_enabled && self.result == SubChunkRequestResult::SuccessAllAir) {
+            VAR::<u32>::new(self.payload.len().try_into()?).proto_serialize(stream)?;
+            stream.extend_from_slice(&self.payload);
+        }
+
+        self.heightmap_type.proto_serialize(stream)?;
+        if self.heightmap_type == HeightMapType::HasData {
+            if self.heightmap.len() != Self::HEIGHTMAP_SIZE {
+                return Err(ProtoCodecError::FormatMismatch(format!(
+                    "Expected a heightmap with {} entries, got {}",
+                    Self::HEIGHTMAP_SIZE,
+                    self.heightmap.len()
+                )));
+            }
+
+            for height in &self.heightmap {
+                height.proto_serialize(stream)?;
+            }
+        }
+
+        if cache_enabled {
+            self.blob_id.proto_serialize(stream)?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn deserialize(
+        stream: &mut Cursor<&[u8]>,
+        cache_enabled: bool,
+    ) -> Result<Self, ProtoCodecError> {
+        let offset = SubChunkOffset::proto_deserialize(stream)?;
+        let result = SubChunkRequestResult::proto_deserialize(stream)?;
+
+        let mut payload = vec![];
+        if !(cache_enabled && result == SubChunkRequestResult::SuccessAllAir) {
+            let len: usize = VAR::<u32>::proto_deserialize(stream)?
+                .into_inner()
+                .try_into()?;
+
+            let remaining = stream
+                .get_ref()
+                .len()
+                .saturating_sub(stream.position() as usize);
+            if len > remaining {
+                return Err(ProtoCodecError::FormatMismatch(format!(
+                    "Sub chunk payload is {len} bytes long, but only {remaining} bytes are left"
+                )));
+            }
+
+            payload = vec![0; len];
+            stream
+                .read_exact(&mut payload)
+                .map_err(|e| ProtoCodecError::IOError(Arc::new(e)))?;
+        }
+
+        let heightmap_type = HeightMapType::proto_deserialize(stream)?;
+        let mut heightmap = vec![];
+        if heightmap_type == HeightMapType::HasData {
+            for _ in 0..Self::HEIGHTMAP_SIZE {
+                heightmap.push(i8::proto_deserialize(stream)?);
+            }
+        }
+
+        let blob_id = match cache_enabled {
+            true => LE::<u64>::proto_deserialize(stream)?,
+            false => LE::new(0),
+        };
+
+        Ok(Self {
+            offset,
+            result,
+            payload,
+            heightmap_type,
+            heightmap,
+            blob_id,
+        })
+    }
+}
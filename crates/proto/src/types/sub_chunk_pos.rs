@@ -0,0 +1,25 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// The position of a 16x16x16 sub chunk, in sub chunks and not in blocks.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct SubChunkPos {
+    pub x: VAR<i32>,
+    pub y: VAR<i32>,
+    pub z: VAR<i32>,
+}
+
+impl SubChunkPos {
+    pub fn new(x: i32, y: i32, z: i32) -> Self {
+        SubChunkPos {
+            x: VAR::<i32>::new(x),
+            y: VAR::<i32>::new(y),
+            z: VAR::<i32>::new(z),
+        }
+    }
+
+    /// The sub chunk containing the given block.
+    pub fn from_block(x: i32, y: i32, z: i32) -> Self {
+        Self::new(x >> 4, y >> 4, z >> 4)
+    }
+}
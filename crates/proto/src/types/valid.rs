@@ -7,9 +7,11 @@ use super::item_stack_net_id_variant::ItemStackNetIdVariant;
 pub struct Valid {
     pub id: VAR<i32>,
     pub stack_size: LE<u16>,
-    pub aux_value: LE<u16>,
-    pub include_net_id: bool,
+    pub aux_value: VAR<u32>,
+    /// The network id of the stack, only sent for stacks in server authoritative inventories
     pub include_net_id_data: Option<ItemStackNetIdVariant>,
     pub block_runtime_id: VAR<i32>,
-    pub user_data_buffer: String,
+    /// The serialized NBT, can place on/can destroy lists and blocking tick of the item
+    #[len_repr(VAR::<u32>)]
+    pub user_data_buffer: Vec<u8>,
 }
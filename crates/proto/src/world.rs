@@ -0,0 +1,496 @@
+//! Maps worlds loaded with [`bedrockrs_world`] onto the packets sent during login,
+//! and decodes the chunks sent by a server into [`bedrockrs_world`] sub chunks.
+
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use std::sync::Arc;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_core::{Vec2, Vec3};
+use bedrockrs_nbt as nbt;
+use bedrockrs_paletted_storage::PalettedStorage;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_shared::world::dimension::Dimension;
+use bedrockrs_shared::world::permissions_level::PermissionLevel;
+use bedrockrs_world::level_dat::{LevelDat, LevelDatGameRule};
+use bedrockrs_world::world_db::subchunk::SubChunk;
+use bedrockrs_world::World;
+
+use crate::packets::level_chunk::LevelChunkPacket;
+use crate::packets::start_game::StartGamePacket;
+use crate::types::experiments::{Experiment, Experiments};
+use crate::types::gamerule::{GameRule, GameRuleValue};
+use crate::types::level_settings::LevelSettings;
+use crate::types::network_block_pos::NetworkBlockPos;
+
+/// Experiment entries in the `level.dat` that are flags about experiments and not experiments themselves.
+const EXPERIMENT_FLAGS: &[&str] = &["experiments_ever_used", "saved_with_toggled_experiments"];
+
+/// The `SpawnY` of worlds whose spawn height wasn't resolved yet, the game
+/// looks for the highest block at the spawn once the world is loaded.
+const UNSET_SPAWN_Y: i32 = 32767;
+/// The height used instead of an unresolved spawn height, just above the sea level.
+const FALLBACK_SPAWN_Y: i32 = 64;
+
+/// The header of a biome storage that has the same biomes as the sub chunk below.
+const COPY_PREVIOUS_STORAGE: u8 = 0xFF;
+
+impl LevelSettings {
+    /// Builds the [`LevelSettings`] of a world from its [`LevelDat`],
+    /// every setting not stored in the `level.dat` keeps its [`Default`] value.
+    pub fn from_level_dat(level_dat: &LevelDat) -> Self {
+        let mut settings = LevelSettings::default();
+
+        settings.seed = LE::new(level_dat.seed as u64);
+        settings.spawn_settings.dimension =
+            level_dat.dimension.clone().unwrap_or(Dimension::Overworld);
+        settings.game_type = level_dat.game_type.clone();
+        settings.difficulty = level_dat.difficulty.clone();
+        settings.default_spawn_block =
+            NetworkBlockPos::new(level_dat.spawn_x, spawn_y(level_dat), level_dat.spawn_z);
+        settings.bonus_chest = level_dat.bonus_chest_enabled;
+        settings.commands_enabled = level_dat.cheats;
+        settings.player_permission = VAR::new(player_permission(level_dat));
+        settings.experiments = experiments(&level_dat.experiments);
+        settings.gamerules = game_rules(&level_dat.game_rules);
+
+        settings
+    }
+}
+
+impl StartGamePacket {
+    /// Builds a [`StartGamePacket`] for the given [`World`], the player spawns at the
+    /// world spawn and every value not stored in the world keeps its [`Default`] value.
+    pub fn from_world(world: &World) -> Self {
+        let level_dat = &world.level_dat;
+
+        Self {
+            actor_game_type: level_dat.game_type.clone(),
+            position: Vec3 {
+                // Center the player on the spawn block
+                x: LE::new(level_dat.spawn_x as f32 + 0.5),
+                y: LE::new(spawn_y(level_dat) as f32),
+                z: LE::new(level_dat.spawn_z as f32 + 0.5),
+            },
+            rotation: Vec2 {
+                x: LE::new(0.0),
+                y: LE::new(0.0),
+            },
+            settings: LevelSettings::from_level_dat(level_dat),
+            level_name: level_dat.level_name.clone(),
+            current_level_time: LE::new(level_dat.current_tick as u64),
+            ..Self::default()
+        }
+    }
+}
+
+/// The height of the world spawn, servers that know the terrain at the spawn can
+/// override the position in [`LoginProviderServer::on_start_game_pk`](crate::login::provider::LoginProviderServer::on_start_game_pk).
+fn spawn_y(level_dat: &LevelDat) -> i32 {
+    match level_dat.spawn_y {
+        UNSET_SPAWN_Y => FALLBACK_SPAWN_Y,
+        spawn_y => spawn_y,
+    }
+}
+
+/// Maps the `playerPermissionsLevel` of the `level.dat` onto the player permission of the
+/// [`LevelSettings`], where `0` is visitor, `1` is member, `2` is operator and `3` is custom.
+///
+/// The world crate reads the raw values into [`PermissionLevel`]s, which are mapped back
+/// one-to-one. Worlds without the entry fall back to the other abilities.
+fn player_permission(level_dat: &LevelDat) -> i32 {
+    let abilities = &level_dat.abilities;
+
+    match abilities.permissions_level {
+        Some(PermissionLevel::Default) => 0,
+        Some(PermissionLevel::Operator) => 1,
+        Some(PermissionLevel::Admin) => 2,
+        Some(PermissionLevel::Host) => 3,
+        // Isn't a player permission, operator comes closest
+        Some(PermissionLevel::Owner) => 2,
+        None => {
+            if abilities.op {
+                2
+            } else if abilities.build || abilities.mine {
+                1
+            } else {
+                0
+            }
+        }
+    }
+}
+
+fn experiments(experiments: &HashMap<String, bool>) -> Experiments {
+    Experiments {
+        experiments: experiments
+            .iter()
+            .filter(|(name, _)| !EXPERIMENT_FLAGS.contains(&name.as_str()))
+            .map(|(name, enabled)| Experiment {
+                name: name.clone(),
+                enabled: *enabled,
+            })
+            .collect(),
+        ever_toggled: experiments
+            .get("experiments_ever_used")
+            .copied()
+            .unwrap_or(false),
+    }
+}
+
+/// Int game rules are sent unsigned, rules with negative values
+/// are left out so the client keeps their default value.
+fn game_rules(game_rules: &HashMap<String, LevelDatGameRule>) -> Vec<GameRule> {
+    game_rules
+        .iter()
+        .filter_map(|(name, value)| {
+            let value = match value {
+                LevelDatGameRule::Bool(v) => GameRuleValue::ValBool(*v),
+                LevelDatGameRule::Int(v) => GameRuleValue::ValVarU32(u32::try_from(*v).ok()?),
+            };
+
+            Some(GameRule {
+                name: name.clone(),
+                editable: true,
+                value,
+            })
+        })
+        .collect()
+}
+
+/// The blocks and biomes of a chunk decoded from the serialized data of a [`LevelChunkPacket`].
+///
+/// The network format sends runtime ids instead of block states, so every palette entry
+/// is a [`nbt::Value::Int`] holding the runtime id of a block or the id of a biome.
+#[derive(Debug, Clone)]
+pub struct ChunkData {
+    /// The sub chunks from the bottom of the chunk upwards,
+    /// empty if they are sent as blobs or requested by the client
+    pub sub_chunks: Vec<SubChunk>,
+    /// The biomes of every sub chunk from the bottom of the dimension upwards,
+    /// empty if they are sent as a blob
+    pub biomes: Vec<PalettedStorage>,
+    pub border_blocks: Vec<u8>,
+    pub block_entities: Vec<HashMap<String, nbt::Value>>,
+}
+
+impl LevelChunkPacket {
+    /// Decodes the sub chunks, biomes and block entities of the serialized chunk data.
+    ///
+    /// If the blob cache is enabled the sub chunks and biomes are sent as blobs,
+    /// which can be decoded with [`decode_sub_chunk`] and [`decode_biomes`].
+    pub fn chunk_data(&self) -> Result<ChunkData, ProtoCodecError> {
+        let data = self.serialized_chunk_data.as_slice();
+        let mut stream = Cursor::new(data);
+
+        let mut sub_chunks = vec![];
+        let mut biomes = vec![];
+        if !self.cache_enabled {
+            for _ in 0..self.sub_chunk_count.into_inner() {
+                sub_chunks.push(read_sub_chunk(&mut stream)?);
+            }
+
+            biomes = read_biomes(&mut stream, self.dimension_id.into_inner())?;
+        }
+
+        let len = u8::proto_deserialize(&mut stream)?;
+        let mut border_blocks = vec![0; len as usize];
+        stream
+            .read_exact(&mut border_blocks)
+            .map_err(|e| ProtoCodecError::IOError(Arc::new(e)))?;
+
+        let mut block_entities = vec![];
+        while (stream.position() as usize) < data.len() {
+            block_entities.push(HashMap::proto_deserialize(&mut stream)?);
+        }
+
+        Ok(ChunkData {
+            sub_chunks,
+            biomes,
+            border_blocks,
+            block_entities,
+        })
+    }
+}
+
+/// Decodes a sub chunk in the network format, like the sub chunk blobs
+/// of the blob cache and the sub chunks of a `SubChunkPacket`.
+pub fn decode_sub_chunk(bytes: &[u8]) -> Result<SubChunk, ProtoCodecError> {
+    read_sub_chunk(&mut Cursor::new(bytes))
+}
+
+/// Decodes the biomes of a chunk in the dimension with the given id in the network format,
+/// like the biome blob of the blob cache.
+pub fn decode_biomes(
+    bytes: &[u8],
+    dimension_id: i32,
+) -> Result<Vec<PalettedStorage>, ProtoCodecError> {
+    read_biomes(&mut Cursor::new(bytes), dimension_id)
+}
+
+fn read_sub_chunk(stream: &mut Cursor<&[u8]>) -> Result<SubChunk, ProtoCodecError> {
+    let layers = match u8::proto_deserialize(stream)? {
+        1 => 1,
+        version @ (8 | 9) => {
+            let layers = u8::proto_deserialize(stream)?;
+
+            if version == 9 {
+                // The y index, which is known from the position of the sub chunk
+                i8::proto_deserialize(stream)?;
+            }
+
+            layers
+        }
+        other => {
+            return Err(ProtoCodecError::InvalidEnumID(
+                format!("{other:?}"),
+                String::from("SubChunkVersion"),
+            ))
+        }
+    };
+
+    let mut paletted_storage = vec![];
+    for _ in 0..layers {
+        match read_paletted_storage(stream)? {
+            Some(storage) => paletted_storage.push(storage),
+            None => {
+                return Err(ProtoCodecError::FormatMismatch(String::from(
+                    "Block storage refers to a previous storage",
+                )))
+            }
+        }
+    }
+
+    Ok(SubChunk { paletted_storage })
+}
+
+fn read_biomes(
+    stream: &mut Cursor<&[u8]>,
+    dimension_id: i32,
+) -> Result<Vec<PalettedStorage>, ProtoCodecError> {
+    let mut biomes: Vec<PalettedStorage> = vec![];
+
+    for _ in 0..biome_section_count(dimension_id) {
+        let storage = match read_paletted_storage(stream)? {
+            Some(storage) => storage,
+            None => biomes.last().cloned().ok_or_else(|| {
+                ProtoCodecError::FormatMismatch(String::from(
+                    "First biome storage refers to a previous storage",
+                ))
+            })?,
+        };
+
+        biomes.push(storage);
+    }
+
+    Ok(biomes)
+}
+
+/// Reads a paletted storage in the network format, returns `None` if the
+/// storage refers to the previous one, which is only done for biomes.
+fn read_paletted_storage(
+    stream: &mut Cursor<&[u8]>,
+) -> Result<Option<PalettedStorage>, ProtoCodecError> {
+    let header = u8::proto_deserialize(stream)?;
+    if header == COPY_PREVIOUS_STORAGE {
+        return Ok(None);
+    }
+
+    if header & 1 == 0 {
+        return Err(ProtoCodecError::FormatMismatch(String::from(
+            "Paletted storage uses the persistent format instead of the network format",
+        )));
+    }
+
+    let mut storage = PalettedStorage {
+        blocks: [0; 4096],
+        palette: vec![],
+    };
+
+    let bits_per_block = (header >> 1) as u32;
+    if bits_per_block == 0 {
+        // All blocks are the same, the only palette entry follows without a length
+        let entry = VAR::<i32>::proto_deserialize(stream)?.into_inner();
+        storage.palette.push(nbt::Value::Int(entry));
+
+        return Ok(Some(storage));
+    }
+
+    if !matches!(bits_per_block, 1..=6 | 8 | 16) {
+        return Err(ProtoCodecError::FormatMismatch(format!(
+            "Paletted storage uses {bits_per_block} bits per block"
+        )));
+    }
+
+    let blocks_per_word = (32 / bits_per_block) as usize;
+    let mask = (1 << bits_per_block) - 1;
+
+    let mut index = 0;
+    for _ in 0..storage.blocks.len().div_ceil(blocks_per_word) {
+        let word = LE::<u32>::proto_deserialize(stream)?.into_inner();
+
+        for i in 0..blocks_per_word {
+            // The last word may be padded
+            if index == storage.blocks.len() {
+                break;
+            }
+
+            storage.blocks[index] = (word >> (i as u32 * bits_per_block)) & mask;
+            index += 1;
+        }
+    }
+
+    let len = VAR::<i32>::proto_deserialize(stream)?.into_inner();
+    for _ in 0..len {
+        let entry = VAR::<i32>::proto_deserialize(stream)?.into_inner();
+        storage.palette.push(nbt::Value::Int(entry));
+    }
+
+    if storage
+        .blocks
+        .iter()
+        .any(|&block| block as usize >= storage.palette.len())
+    {
+        return Err(ProtoCodecError::FormatMismatch(String::from(
+            "Paletted storage refers to a missing palette entry",
+        )));
+    }
+
+    Ok(Some(storage))
+}
+
+/// The number of biome storages sent for a chunk, one for
+/// every sub chunk from the bottom to the top of the dimension.
+fn biome_section_count(dimension_id: i32) -> usize {
+    match dimension_id {
+        // The nether goes from y 0 to 127
+        1 => 8,
+        // The end goes from y 0 to 255
+        2 => 16,
+        // The overworld goes from y -64 to 319
+        _ => 24,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::chunk_pos::ChunkPos;
+
+    fn palette(storage: &PalettedStorage) -> Vec<i32> {
+        storage
+            .palette
+            .iter()
+            .map(|entry| match entry {
+                nbt::Value::Int(id) => *id,
+                other => panic!("Palette entry {other:?} is not a runtime id"),
+            })
+            .collect()
+    }
+
+    fn write_single_value_storage(stream: &mut Vec<u8>, value: i32) {
+        stream.push(1);
+        VAR::new(value).proto_serialize(stream).unwrap();
+    }
+
+    /// Writes a paletted storage with the given bits per block, the padding
+    /// of the last word is filled with ones so reading it would be noticed.
+    fn write_paletted_storage(
+        stream: &mut Vec<u8>,
+        bits_per_block: u32,
+        blocks: &[u32; 4096],
+        palette: &[i32],
+    ) {
+        stream.push((bits_per_block << 1) as u8 | 1);
+
+        let blocks_per_word = (32 / bits_per_block) as usize;
+        for chunk in blocks.chunks(blocks_per_word) {
+            let mut word = u32::MAX;
+            for (i, block) in chunk.iter().enumerate() {
+                let shift = i as u32 * bits_per_block;
+                word &= !(((1 << bits_per_block) - 1) << shift);
+                word |= block << shift;
+            }
+
+            LE::new(word).proto_serialize(stream).unwrap();
+        }
+
+        VAR::new(palette.len() as i32)
+            .proto_serialize(stream)
+            .unwrap();
+        for entry in palette {
+            VAR::new(*entry).proto_serialize(stream).unwrap();
+        }
+    }
+
+    #[test]
+    fn padded_paletted_storage_is_decoded() {
+        // 3 and 5 bits per block don't fill the words, and 4096 blocks don't fill the last word
+        for (bits_per_block, palette_len) in [(3, 5), (5, 20)] {
+            let mut blocks = [0; 4096];
+            for (i, block) in blocks.iter_mut().enumerate() {
+                *block = (i % palette_len) as u32;
+            }
+            let entries: Vec<i32> = (0..palette_len as i32).map(|id| id * 10).collect();
+
+            let mut stream = vec![8, 1];
+            write_paletted_storage(&mut stream, bits_per_block, &blocks, &entries);
+
+            let sub_chunk = decode_sub_chunk(&stream).unwrap();
+
+            assert_eq!(sub_chunk.paletted_storage.len(), 1);
+            assert_eq!(sub_chunk.paletted_storage[0].blocks, blocks);
+            assert_eq!(palette(&sub_chunk.paletted_storage[0]), entries);
+        }
+    }
+
+    #[test]
+    fn biome_storages_copy_the_previous_storage() {
+        let mut stream = vec![];
+        write_single_value_storage(&mut stream, 7);
+        stream.extend([COPY_PREVIOUS_STORAGE; 7]);
+
+        let biomes = decode_biomes(&stream, 1).unwrap();
+
+        assert_eq!(biomes.len(), 8);
+        for storage in &biomes {
+            assert_eq!(palette(storage), [7]);
+        }
+
+        // There is no previous storage to copy for the first one
+        let stream = [COPY_PREVIOUS_STORAGE; 8];
+        assert!(decode_biomes(&stream, 1).is_err());
+    }
+
+    #[test]
+    fn chunk_data_is_decoded() {
+        let mut blocks = [0; 4096];
+        blocks[4095] = 1;
+
+        let mut data = vec![8, 1];
+        write_paletted_storage(&mut data, 1, &blocks, &[0, 42]);
+        write_single_value_storage(&mut data, 1);
+        data.extend([COPY_PREVIOUS_STORAGE; 23]);
+        // No border blocks and no block entities
+        data.push(0);
+
+        let packet = LevelChunkPacket {
+            chunk_position: ChunkPos::new(3, -7),
+            dimension_id: VAR::new(0),
+            sub_chunk_count: VAR::new(1),
+            cache_enabled: false,
+            blob_ids: vec![],
+            serialized_chunk_data: data,
+            client_needs_to_request_subchunks: false,
+            client_request_subchunk_limit: VAR::new(-1),
+        };
+
+        let chunk_data = packet.chunk_data().unwrap();
+
+        assert_eq!(chunk_data.sub_chunks.len(), 1);
+        assert_eq!(chunk_data.sub_chunks[0].paletted_storage[0].blocks, blocks);
+        assert_eq!(chunk_data.biomes.len(), 24);
+        assert!(chunk_data.border_blocks.is_empty());
+        assert!(chunk_data.block_entities.is_empty());
+    }
+}
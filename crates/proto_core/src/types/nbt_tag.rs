@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::io::Cursor;
 
 use bedrockrs_nbt as nbt;
@@ -11,7 +12,33 @@ impl ProtoCodec for nbt::Value {
         Ok(())
     }
 
-    fn proto_deserialize(cursor: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
-        todo!("Deserialize to concrete type instead of NbtTag");
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let start = stream.position() as usize;
+        let mut buf = stream.get_ref().get(start..).unwrap_or_default();
+        let len = buf.len();
+
+        let (tag, _) = nbt::from_var_bytes(&mut buf)?;
+
+        // Skip the bytes the nbt reader consumed
+        stream.set_position((start + len - buf.len()) as u64);
+
+        Ok(tag)
+    }
+}
+
+/// A compound tag, like the NBT of block entities.
+impl ProtoCodec for HashMap<String, nbt::Value> {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        nbt::to_var_bytes_in(stream, self)?;
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        match nbt::Value::proto_deserialize(stream)? {
+            nbt::Value::Compound(compound) => Ok(compound),
+            other => Err(ProtoCodecError::FormatMismatch(format!(
+                "Expected a compound tag, got {other:?}"
+            ))),
+        }
     }
 }
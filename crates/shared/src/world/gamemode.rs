@@ -1,7 +1,7 @@
 use bedrockrs_core::int::VAR;
 use bedrockrs_proto_derive::ProtoCodec;
 
-#[derive(ProtoCodec, Debug, Clone)]
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
 #[enum_repr(VAR::<i32>)]
 pub enum Gamemode {
     Survival = 0,
@@ -9,6 +9,7 @@ use byteorder::{LittleEndian, ReadBytesExt};
 
 use bedrockrs_shared::world::difficulty::Difficulty;
 use bedrockrs_shared::world::dimension::Dimension;
+use bedrockrs_shared::world::gamemode::Gamemode;
 
 use crate::error::WorldError;
 use crate::level_dat::abilities::LevelDatAbilities;
@@ -67,8 +68,65 @@ pub struct LevelDat {
 
     /// If cheats are on. (NBT entry: `commandsEnabled`)
     pub cheats: bool,
+
+    /// The default game mode of players joining the world. (NBT entry: `GameType`)
+    pub game_type: Gamemode,
+
+    /// The game rules of the world, keyed by their lowercase name.
+    /// Game rules are stored as top-level entries, see [`GAME_RULES`] for the known ones.
+    pub game_rules: HashMap<String, LevelDatGameRule>,
+}
+
+/// The value of a game rule stored in the `level.dat`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LevelDatGameRule {
+    /// Stored as a Byte.
+    Bool(bool),
+    /// Stored as an Int32.
+    Int(i32),
 }
 
+/// The names of all game rules that are known to be stored in the `level.dat`.
+pub const GAME_RULES: &[&str] = &[
+    "commandblockoutput",
+    "commandblocksenabled",
+    "dodaylightcycle",
+    "doentitydrops",
+    "dofiretick",
+    "doimmediaterespawn",
+    "doinsomnia",
+    "dolimitedcrafting",
+    "domobloot",
+    "domobspawning",
+    "dotiledrops",
+    "doweathercycle",
+    "drowningdamage",
+    "falldamage",
+    "firedamage",
+    "freezedamage",
+    "functioncommandlimit",
+    "keepinventory",
+    "maxcommandchainlength",
+    "mobgriefing",
+    "naturalregeneration",
+    "playerssleepingpercentage",
+    "projectilescanbreakblocks",
+    "pvp",
+    "randomtickspeed",
+    "recipesunlock",
+    "respawnblocksexplode",
+    "sendcommandfeedback",
+    "showbordereffect",
+    "showcoordinates",
+    "showdaysplayed",
+    "showdeathmessages",
+    "showrecipemessages",
+    "showtags",
+    "spawnradius",
+    "tntexplodes",
+    "tntexplosiondropdecay",
+];
+
 impl LevelDat {
     /// Opens the `level.dat` file from a given Minecraft Bedrock world directory.
     ///
@@ -277,6 +335,40 @@ impl LevelDat {
                 spawn_y: get_int32(&mut map, "SpawnY")?,
                 spawn_z: get_int32(&mut map, "SpawnZ")?,
                 cheats: get_byte_as_bool(&mut map, "commandsEnabled")?,
+                game_type: match get_int32_option(&mut map, "GameType")? {
+                    Some(0) | None => Gamemode::Survival,
+                    Some(1) => Gamemode::Creative,
+                    Some(2) => Gamemode::Adventure,
+                    Some(3) => Gamemode::SurvivalSpectator,
+                    Some(4) => Gamemode::CreativeSpectator,
+                    Some(5) => Gamemode::Default,
+                    Some(6) => Gamemode::Spectator,
+                    Some(other) => Err(WorldError::FormatError(format!(
+                        "Value for `GameType` is out of bounds, got {:?}",
+                        other
+                    )))?,
+                },
+                game_rules: {
+                    let mut game_rules = HashMap::new();
+
+                    for name in GAME_RULES {
+                        match map.remove(*name) {
+                            Some(NbtTag::Byte(v)) => {
+                                game_rules.insert(name.to_string(), LevelDatGameRule::Bool(v != 0));
+                            }
+                            Some(NbtTag::Int32(v)) => {
+                                game_rules.insert(name.to_string(), LevelDatGameRule::Int(v));
+                            }
+                            Some(other) => Err(WorldError::FormatError(format!(
+                                "Expected game rule `{}` in LevelDat to be of type Byte or Int32, got {:?}",
+                                name, other
+                            )))?,
+                            None => {}
+                        }
+                    }
+
+                    game_rules
+                },
             }),
             other => Err(WorldError::FormatError(format!(
                 "Expected root tag in LevelDat to be of type Compound, got {:?}",